@@ -1,8 +1,34 @@
-use soroban_sdk::{Address, Env, Symbol, Vec, vec};
+use soroban_sdk::{Address, Env, IntoVal, Map, String, Symbol, Topics, Val, Vec, vec};
+
+use crate::storage::event_seq_key;
+use crate::types::{EmergencyAction, Status};
+
+// Version of the event payload layout. Bump whenever the topics or data of
+// any emitted event change shape so indexers can branch on it.
+pub const EVENT_SCHEMA_VERSION: u32 = 4;
 
 pub struct Events;
 
 impl Events {
+    // Next value of the contract-wide event counter, starting at 1. Indexers
+    // use it to spot gaps or reordering.
+    pub fn next_seq(env: &Env) -> u64 {
+        let seq: u64 = env.storage().instance().get(&event_seq_key()).unwrap_or(0) + 1;
+        env.storage().instance().set(&event_seq_key(), &seq);
+        seq
+    }
+
+    // Every event's data is published as `(seq, data)`.
+    fn publish<T, D>(env: &Env, topics: T, data: D)
+    where
+        T: Topics,
+        D: IntoVal<Env, Val>,
+    {
+        let seq = Self::next_seq(env);
+        let data: Val = data.into_val(env);
+        env.events().publish(topics, (seq, data));
+    }
+
     fn bounty_created_event(env: &Env) -> Symbol {
         Symbol::new(env, "bounty_created")
     }
@@ -63,6 +89,10 @@ impl Events {
         Symbol::new(env, "project_completed")
     }
 
+    fn project_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "project_updated")
+    }
+
     fn hackathon_created_event(env: &Env) -> Symbol {
         Symbol::new(env, "hackathon_created")
     }
@@ -79,102 +109,569 @@ impl Events {
         Symbol::new(env, "hackathon_prizes_distributed")
     }
 
+    fn fee_cap_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "fee_cap_updated")
+    }
+
+    fn submission_cooldown_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "submission_cooldown_updated")
+    }
+
+    fn fee_refund_window_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "fee_refund_window_updated")
+    }
+
+    fn fee_refunded_event(env: &Env) -> Symbol {
+        Symbol::new(env, "fee_refunded")
+    }
+
+    fn max_open_per_owner_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "max_open_per_owner_updated")
+    }
+
+    fn min_judging_gap_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "min_judging_gap_updated")
+    }
+
+    fn keeper_incentive_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "keeper_incentive_updated")
+    }
+
+    fn keeper_rewarded_event(env: &Env) -> Symbol {
+        Symbol::new(env, "keeper_rewarded")
+    }
+
+    fn bounty_archived_event(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_archived")
+    }
+
+    fn milestone_release_fee_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "milestone_release_fee_updated")
+    }
+
+    fn admin_proposed_event(env: &Env) -> Symbol {
+        Symbol::new(env, "admin_proposed")
+    }
+
+    fn admin_proposal_cancelled_event(env: &Env) -> Symbol {
+        Symbol::new(env, "admin_proposal_cancelled")
+    }
+
+    fn reward_claimed_event(env: &Env) -> Symbol {
+        Symbol::new(env, "reward_claimed")
+    }
+
+    fn token_decimals_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "token_decimals_updated")
+    }
+
+    fn bounty_featured_event(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_featured")
+    }
+
+    fn bounty_status_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_status_updated")
+    }
+
+    fn project_settled_event(env: &Env) -> Symbol {
+        Symbol::new(env, "project_settled")
+    }
+
+    fn alt_pool_funded_event(env: &Env) -> Symbol {
+        Symbol::new(env, "alt_pool_funded")
+    }
+
+    fn winner_accepted_event(env: &Env) -> Symbol {
+        Symbol::new(env, "winner_accepted")
+    }
+
+    fn unaccepted_reclaimed_event(env: &Env) -> Symbol {
+        Symbol::new(env, "unaccepted_reclaimed")
+    }
+
+    fn fee_account_proposed_event(env: &Env) -> Symbol {
+        Symbol::new(env, "fee_account_proposed")
+    }
+
+    fn project_purged_event(env: &Env) -> Symbol {
+        Symbol::new(env, "project_purged")
+    }
+
+    fn block_owner_apply_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "block_owner_apply_updated")
+    }
+
+    fn bounty_status_forced_event(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_status_forced")
+    }
+
+    fn fee_accrued_event(env: &Env) -> Symbol {
+        Symbol::new(env, "fee_accrued")
+    }
+
+    fn milestone_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "milestone_updated")
+    }
+
+    fn emergency_delay_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "emergency_delay_updated")
+    }
+
+    fn emergency_action_requested_event(env: &Env) -> Symbol {
+        Symbol::new(env, "emergency_action_requested")
+    }
+
+    fn emergency_action_executed_event(env: &Env) -> Symbol {
+        Symbol::new(env, "emergency_action_executed")
+    }
+
+    fn emergency_action_cancelled_event(env: &Env) -> Symbol {
+        Symbol::new(env, "emergency_action_cancelled")
+    }
+
+    fn submission_withdrawn_event(env: &Env) -> Symbol {
+        Symbol::new(env, "submission_withdrawn")
+    }
+
+    fn crowdfund_contributed_event(env: &Env) -> Symbol {
+        Symbol::new(env, "crowdfund_contributed")
+    }
+
+    fn contributors_refunded_event(env: &Env) -> Symbol {
+        Symbol::new(env, "contributors_refunded")
+    }
+
+    fn series_created_event(env: &Env) -> Symbol {
+        Symbol::new(env, "series_created")
+    }
+
+    fn series_ended_event(env: &Env) -> Symbol {
+        Symbol::new(env, "series_ended")
+    }
+
+    fn insurance_bps_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "insurance_bps_updated")
+    }
+
+    fn owner_create_cooldown_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "owner_create_cooldown_updated")
+    }
+
+    fn project_ownership_transferred_event(env: &Env) -> Symbol {
+        Symbol::new(env, "project_ownership_transferred")
+    }
+
+    fn fee_recipient_approval_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "fee_recipient_approval_updated")
+    }
+
+    fn bounty_state_event(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_state")
+    }
+
+    fn default_token_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "default_token_updated")
+    }
+
+    fn applicants_not_selected_event(env: &Env) -> Symbol {
+        Symbol::new(env, "applicants_not_selected")
+    }
+
+    fn bounty_paused_event(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_paused")
+    }
+
+    fn bounty_judging_started_event(env: &Env) -> Symbol {
+        Symbol::new(env, "bounty_judging_started")
+    }
+
+    fn claim_window_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "claim_window_updated")
+    }
+
+    fn unclaimed_reclaimed_event(env: &Env) -> Symbol {
+        Symbol::new(env, "unclaimed_reclaimed")
+    }
+
+    fn milestone_payee_updated_event(env: &Env) -> Symbol {
+        Symbol::new(env, "milestone_payee_updated")
+    }
+
+    fn schema_version_event(env: &Env) -> Symbol {
+        Symbol::new(env, "schema_version")
+    }
+
     pub fn emit_bounty_created(env: &Env, bounty_id: u64) {
-        env.events()
-            .publish((Self::bounty_created_event(env),), bounty_id);
+        Self::publish(env, (Self::bounty_created_event(env),), bounty_id);
     }
 
     pub fn emit_bounty_updated(env: &Env, bounty_id: u64, updated_fields: Vec<Symbol>) {
-        env.events().publish(
+        Self::publish(
+            env,
             (Self::bounty_updated_event(env),),
             vec![env, (bounty_id, updated_fields)],
         );
     }
 
     pub fn emit_bounty_deleted(env: &Env, bounty_id: u64) {
-        env.events()
-            .publish((Self::bounty_deleted_event(env),), bounty_id);
+        Self::publish(env, (Self::bounty_deleted_event(env),), bounty_id);
     }
 
     pub fn emit_submission_added(env: &Env, bounty_id: u64, applicant: Address) {
-        env.events()
-            .publish((Self::submission_added_event(env),), (bounty_id, applicant));
+        Self::publish(env, (Self::submission_added_event(env),), (bounty_id, applicant));
     }
 
     pub fn emit_submission_updated(env: &Env, bounty_id: u64, applicant: Address) {
-        env.events()
-            .publish((Self::submission_updated_event(env),), (bounty_id, applicant));
+        Self::publish(env, (Self::submission_updated_event(env),), (bounty_id, applicant));
     }
 
-    pub fn emit_winners_selected(env: &Env, bounty_id: u64, winners: Vec<Address>) {
-        env.events()
-            .publish((Self::winners_selected_event(env),), (bounty_id, winners));
+    pub fn emit_winners_selected(
+        env: &Env,
+        bounty_id: u64,
+        winners: Vec<Address>,
+        note: Option<String>,
+    ) {
+        Self::publish(env, (Self::winners_selected_event(env),), (bounty_id, winners, note));
     }
 
-    pub fn emit_auto_distributed(env: &Env, bounty_id: u64) {
-        env.events()
-            .publish((Self::auto_distributed_event(env),), bounty_id);
+    // `recipients` lists each applicant and the primary-token share they
+    // received; it is empty when the reward went back to the owner.
+    pub fn emit_auto_distributed(env: &Env, bounty_id: u64, recipients: Vec<(Address, i128)>) {
+        Self::publish(env, (Self::auto_distributed_event(env),), (bounty_id, recipients));
     }
 
     pub fn emit_admin_updated(env: &Env, new_admin: Address) {
-        env.events()
-            .publish((Self::admin_updated_event(env),), new_admin);
+        Self::publish(env, (Self::admin_updated_event(env),), new_admin);
     }
 
     pub fn emit_fee_account_updated(env: &Env, new_fee_account: Address) {
-        env.events()
-            .publish((Self::fee_account_updated_event(env),), new_fee_account);
+        Self::publish(env, (Self::fee_account_updated_event(env),), new_fee_account);
     }
 
     pub fn emit_bounty_closed(env: &Env, bounty_id: u64) {
-        env.events()
-            .publish((Self::bounty_closed_event(env),), bounty_id);
+        Self::publish(env, (Self::bounty_closed_event(env),), bounty_id);
     }
 
     pub fn emit_project_gig_created(env: &Env, project_id: u64, total_reward: i128) {
-        env.events()
-            .publish((Self::project_gig_created_event(env),), (project_id, total_reward));
+        Self::publish(env, (Self::project_gig_created_event(env),), (project_id, total_reward));
     }
 
     pub fn emit_project_job_created(env: &Env, project_id: u64) {
-        env.events()
-            .publish((Self::project_job_created_event(env),), project_id);
+        Self::publish(env, (Self::project_job_created_event(env),), project_id);
     }
 
     pub fn emit_milestone_paid(env: &Env, project_id: u64, milestone_order: u32, contributor: Address, amount: i128) {
-        env.events()
-            .publish((Self::milestone_paid_event(env),), (project_id, milestone_order, contributor, amount));
+        Self::publish(
+            env,
+            (Self::milestone_paid_event(env),),
+            (project_id, milestone_order, contributor, amount),
+        );
     }
 
     pub fn emit_project_cancelled(env: &Env, project_id: u64, refunded_amount: i128) {
-        env.events()
-            .publish((Self::project_cancelled_event(env),), (project_id, refunded_amount));
+        Self::publish(env, (Self::project_cancelled_event(env),), (project_id, refunded_amount));
     }
 
     pub fn emit_project_completed(env: &Env, project_id: u64) {
-        env.events()
-            .publish((Self::project_completed_event(env),), project_id);
+        Self::publish(env, (Self::project_completed_event(env),), project_id);
     }
 
     pub fn emit_hackathon_created(env: &Env, hackathon_id: u64, total_budget: i128) {
-        env.events()
-            .publish((Self::hackathon_created_event(env),), (hackathon_id, total_budget));
+        Self::publish(env, (Self::hackathon_created_event(env),), (hackathon_id, total_budget));
     }
 
     pub fn emit_hackathon_updated(env: &Env, hackathon_id: u64, updated_fields: Vec<Symbol>) {
-        env.events().publish(
+        Self::publish(
+            env,
             (Self::hackathon_updated_event(env),),
             vec![env, (hackathon_id, updated_fields)],
         );
     }
 
     pub fn emit_hackathon_cancelled(env: &Env, hackathon_id: u64, refunded_amount: i128) {
-        env.events()
-            .publish((Self::hackathon_cancelled_event(env),), (hackathon_id, refunded_amount));
+        Self::publish(
+            env,
+            (Self::hackathon_cancelled_event(env),),
+            (hackathon_id, refunded_amount),
+        );
     }
 
     pub fn emit_hackathon_prizes_distributed(env: &Env, hackathon_id: u64) {
-        env.events()
-            .publish((Self::hackathon_prizes_distributed_event(env),), hackathon_id);
+        Self::publish(env, (Self::hackathon_prizes_distributed_event(env),), hackathon_id);
+    }
+
+    pub fn emit_schema_version(env: &Env) {
+        Self::publish(env, (Self::schema_version_event(env),), EVENT_SCHEMA_VERSION);
+    }
+
+    pub fn emit_fee_cap_updated(env: &Env, token: Address, max_fee: Option<i128>) {
+        Self::publish(env, (Self::fee_cap_updated_event(env),), (token, max_fee));
+    }
+
+    pub fn emit_submission_cooldown_updated(env: &Env, cooldown: u64) {
+        Self::publish(env, (Self::submission_cooldown_updated_event(env),), cooldown);
+    }
+
+    pub fn emit_keeper_incentive_updated(env: &Env, percent: u32) {
+        Self::publish(env, (Self::keeper_incentive_updated_event(env),), percent);
+    }
+
+    pub fn emit_keeper_rewarded(env: &Env, bounty_id: u64, keeper: Address, amount: i128) {
+        Self::publish(env, (Self::keeper_rewarded_event(env), bounty_id), (keeper, amount));
+    }
+
+    pub fn emit_bounty_archived(
+        env: &Env,
+        bounty_id: u64,
+        status: Status,
+        winners: Vec<Address>,
+        payouts: Map<Address, i128>,
+    ) {
+        Self::publish(
+            env,
+            (Self::bounty_archived_event(env), bounty_id),
+            (status, winners, payouts),
+        );
+    }
+
+    pub fn emit_milestone_release_fee_updated(env: &Env, percent: u32) {
+        Self::publish(env, (Self::milestone_release_fee_updated_event(env),), percent);
+    }
+
+    pub fn emit_admin_proposed(env: &Env, pending_admin: Address) {
+        Self::publish(env, (Self::admin_proposed_event(env),), pending_admin);
+    }
+
+    pub fn emit_admin_proposal_cancelled(env: &Env, pending_admin: Address) {
+        Self::publish(env, (Self::admin_proposal_cancelled_event(env),), pending_admin);
+    }
+
+    pub fn emit_reward_claimed(env: &Env, bounty_id: u64, winner: Address) {
+        Self::publish(env, (Self::reward_claimed_event(env), bounty_id), winner);
+    }
+
+    pub fn emit_token_decimals_updated(env: &Env, token: Address, decimals: Option<u32>) {
+        Self::publish(env, (Self::token_decimals_updated_event(env),), (token, decimals));
+    }
+
+    pub fn emit_project_updated(env: &Env, project_id: u64, deadline: u64) {
+        Self::publish(env, (Self::project_updated_event(env), project_id), deadline);
+    }
+
+    pub fn emit_bounty_featured(env: &Env, bounty_id: u64, featured: bool) {
+        Self::publish(env, (Self::bounty_featured_event(env), bounty_id), featured);
+    }
+
+    pub fn emit_bounty_status_updated(env: &Env, bounty_id: u64, status: Status) {
+        Self::publish(env, (Self::bounty_status_updated_event(env), bounty_id), status);
+    }
+
+    pub fn emit_fee_refund_window_updated(env: &Env, window: u64) {
+        Self::publish(env, (Self::fee_refund_window_updated_event(env),), window);
+    }
+
+    pub fn emit_fee_refunded(env: &Env, bounty_id: u64, owner: Address, amount: i128) {
+        Self::publish(env, (Self::fee_refunded_event(env), bounty_id), (owner, amount));
+    }
+
+    pub fn emit_max_open_per_owner_updated(env: &Env, limit: u32) {
+        Self::publish(env, (Self::max_open_per_owner_updated_event(env),), limit);
+    }
+
+    pub fn emit_project_settled(env: &Env, project_id: u64, contributor: Address, amount: i128) {
+        Self::publish(
+            env,
+            (Self::project_settled_event(env), project_id),
+            (contributor, amount),
+        );
+    }
+
+    pub fn emit_min_judging_gap_updated(env: &Env, gap: u64) {
+        Self::publish(env, (Self::min_judging_gap_updated_event(env),), gap);
+    }
+
+    pub fn emit_alt_pool_funded(env: &Env, token: Address, amount: i128) {
+        Self::publish(env, (Self::alt_pool_funded_event(env), token), amount);
+    }
+
+    pub fn emit_winner_accepted(env: &Env, bounty_id: u64, winner: Address) {
+        Self::publish(env, (Self::winner_accepted_event(env), bounty_id), winner);
+    }
+
+    pub fn emit_unaccepted_reclaimed(env: &Env, bounty_id: u64, winners: Vec<Address>) {
+        Self::publish(env, (Self::unaccepted_reclaimed_event(env), bounty_id), winners);
+    }
+
+    pub fn emit_fee_account_proposed(env: &Env, fee_account: Address, effective_at: u64) {
+        Self::publish(env, (Self::fee_account_proposed_event(env),), (fee_account, effective_at));
+    }
+
+    pub fn emit_project_purged(env: &Env, project_id: u64) {
+        Self::publish(env, (Self::project_purged_event(env),), project_id);
+    }
+
+    pub fn emit_block_owner_apply_updated(env: &Env, blocked: bool) {
+        Self::publish(env, (Self::block_owner_apply_updated_event(env),), blocked);
+    }
+
+    pub fn emit_bounty_status_forced(env: &Env, bounty_id: u64, from: Status, to: Status) {
+        Self::publish(env, (Self::bounty_status_forced_event(env), bounty_id), (from, to));
+    }
+
+    pub fn emit_fee_accrued(env: &Env, token: Address, amount: i128) {
+        Self::publish(env, (Self::fee_accrued_event(env), token), amount);
+    }
+
+    pub fn emit_milestone_updated(
+        env: &Env,
+        project_id: u64,
+        milestone_order: u32,
+        amount: i128,
+        funding_delta: i128,
+    ) {
+        Self::publish(
+            env,
+            (Self::milestone_updated_event(env), project_id),
+            (milestone_order, amount, funding_delta),
+        );
+    }
+
+    pub fn emit_emergency_delay_updated(env: &Env, delay: u64) {
+        Self::publish(env, (Self::emergency_delay_updated_event(env),), delay);
+    }
+
+    pub fn emit_emergency_action_requested(env: &Env, action: EmergencyAction, executable_at: u64) {
+        Self::publish(env, (Self::emergency_action_requested_event(env),), (action, executable_at));
+    }
+
+    pub fn emit_emergency_action_executed(env: &Env, action: EmergencyAction) {
+        Self::publish(env, (Self::emergency_action_executed_event(env),), action);
+    }
+
+    pub fn emit_emergency_action_cancelled(env: &Env, action: EmergencyAction) {
+        Self::publish(env, (Self::emergency_action_cancelled_event(env),), action);
+    }
+
+    pub fn emit_submission_withdrawn(env: &Env, bounty_id: u64, applicant: Address) {
+        Self::publish(env, (Self::submission_withdrawn_event(env),), (bounty_id, applicant));
+    }
+
+    pub fn emit_crowdfund_contributed(env: &Env, bounty_id: u64, sponsor: Address, amount: i128) {
+        Self::publish(env, (Self::crowdfund_contributed_event(env), bounty_id), (sponsor, amount));
+    }
+
+    pub fn emit_contributors_refunded(env: &Env, bounty_id: u64, amount: i128) {
+        Self::publish(env, (Self::contributors_refunded_event(env), bounty_id), amount);
+    }
+
+    pub fn emit_series_created(env: &Env, series_id: u64, first_bounty_id: u64) {
+        Self::publish(env, (Self::series_created_event(env), series_id), first_bounty_id);
+    }
+
+    pub fn emit_series_ended(env: &Env, series_id: u64) {
+        Self::publish(env, (Self::series_ended_event(env),), series_id);
+    }
+
+    pub fn emit_insurance_bps_updated(env: &Env, bps: u32) {
+        Self::publish(env, (Self::insurance_bps_updated_event(env),), bps);
+    }
+
+    pub fn emit_owner_create_cooldown_updated(env: &Env, cooldown: u64) {
+        Self::publish(env, (Self::owner_create_cooldown_updated_event(env),), cooldown);
+    }
+
+    pub fn emit_project_ownership_transferred(
+        env: &Env,
+        project_id: u64,
+        previous_owner: Address,
+        new_owner: Address,
+    ) {
+        Self::publish(
+            env,
+            (Self::project_ownership_transferred_event(env), project_id),
+            (previous_owner, new_owner),
+        );
+    }
+
+    pub fn emit_fee_recipient_approval_updated(env: &Env, recipient: Address, approved: bool) {
+        Self::publish(env, (Self::fee_recipient_approval_updated_event(env), recipient), approved);
+    }
+
+    // Counts rather than the applicant and winner lists keep the event small
+    // however large the bounty grows.
+    #[allow(clippy::too_many_arguments)]
+    pub fn emit_bounty_state(
+        env: &Env,
+        bounty_id: u64,
+        owner: Address,
+        token: Address,
+        reward: i128,
+        status: Status,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        applicant_count: u32,
+        winner_count: u32,
+    ) {
+        Self::publish(
+            env,
+            (Self::bounty_state_event(env), bounty_id),
+            (
+                owner,
+                token,
+                reward,
+                status,
+                submission_deadline,
+                judging_deadline,
+                applicant_count,
+                winner_count,
+            ),
+        );
+    }
+
+    pub fn emit_default_token_updated(env: &Env, token: Option<Address>) {
+        Self::publish(env, (Self::default_token_updated_event(env),), token);
+    }
+
+    pub fn emit_applicants_not_selected(
+        env: &Env,
+        bounty_id: u64,
+        page: u32,
+        applicants: Vec<Address>,
+    ) {
+        Self::publish(
+            env,
+            (Self::applicants_not_selected_event(env), bounty_id),
+            (page, applicants),
+        );
+    }
+
+    pub fn emit_bounty_paused(env: &Env, bounty_id: u64, paused: bool) {
+        Self::publish(env, (Self::bounty_paused_event(env), bounty_id), paused);
+    }
+
+    // Carries the owner so frontends can tell them it is time to judge.
+    pub fn emit_bounty_judging_started(env: &Env, bounty_id: u64, owner: Address) {
+        Self::publish(env, (Self::bounty_judging_started_event(env), bounty_id), owner);
+    }
+
+    pub fn emit_claim_window_updated(env: &Env, window: u64) {
+        Self::publish(env, (Self::claim_window_updated_event(env),), window);
+    }
+
+    pub fn emit_unclaimed_reclaimed(env: &Env, bounty_id: u64, winners: Vec<Address>) {
+        Self::publish(env, (Self::unclaimed_reclaimed_event(env), bounty_id), winners);
+    }
+
+    pub fn emit_milestone_payee_updated(
+        env: &Env,
+        project_id: u64,
+        milestone_order: u32,
+        payee: Option<Address>,
+    ) {
+        Self::publish(
+            env,
+            (Self::milestone_payee_updated_event(env), project_id),
+            (milestone_order, payee),
+        );
     }
 }
@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: Boost Software License, Version 1.0.0
 #![no_std]
 
-use soroban_sdk::{Address, Env, Map, String, Symbol, Vec, contract, contractimpl, contractmeta};
+use soroban_sdk::{
+    Address, BytesN, Env, Map, String, Symbol, Vec, contract, contractimpl, contractmeta,
+    panic_with_error,
+};
 
 mod events;
 mod storage;
@@ -10,11 +13,22 @@ mod utils;
 
 use crate::types::*;
 use crate::utils::{
-    adjust_for_decimals, calculate_fee, get_token_client, get_token_decimals, is_zero_address,
-    validate_distribution_sum, FeeType,
+    adjust_for_decimals, assert_unique_addresses, calculate_fee, checked_adjust_for_decimals, convert_from_token_amount, even_distribution, get_token_client, is_zero_address, try_get_token_decimals,
+    validate_deadlines, validate_distribution_sum, FeeType, ACCEPTANCE_WINDOW, MIN_CLAIM_WINDOW, BOUNTY_PLATFORM_FEE_PERCENT, DEFAULT_EMERGENCY_DELAY, GIG_PLATFORM_FEE_PERCENT,
+    HACKATHON_PLATFORM_FEE_PERCENT, JOB_PLATFORM_FEE_PERCENT, MAX_BATCH_SIZE, MAX_DISTRIBUTION_RANKS,
+    MAX_KEEPER_INCENTIVE_PERCENT, MAX_DECIMALS, MAX_FEE_REFUND_WINDOW, MAX_MILESTONE_RELEASE_FEE_PERCENT, MAX_NOTE_LENGTH,
+    MAX_DEADLINE_EXTENSIONS, MAX_DEADLINE_EXTENSION_DAYS, MAX_DESCRIPTION_LENGTH, MAX_PROPOSAL_LENGTH, MAX_INSURANCE_BPS, MAX_SUBMISSION_REVISIONS, MIN_EMERGENCY_DELAY,
+    DAY_IN_LEDGERS, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD,
+};
+use events::{EVENT_SCHEMA_VERSION, Events};
+use storage::{
+    admin_key, approved_fee_recipient_key, bounty_key, bounty_payouts_key, claimable_reward_key, deployment_seq_key, fee_account_key, fee_cap_key,
+    featured_bounties_key, fee_refund_window_key, global_stats_key, max_open_per_owner_key,
+    acceptance_deadline_key, accrued_fees_key, allowlist_key, applicant_count_key, crowdfund_key, deadline_extensions_key, bounty_series_key, next_series_id_key, series_key, sub_bounties_key, alt_pool_key, block_owner_apply_key, contributor_earnings_key, emergency_delay_key, insurance_bps_key, insurance_pool_key, pending_emergency_key, project_contributors_key, project_earnings_key, min_judging_gap_key, open_bounty_count_key, used_tokens_key,
+    idempotency_record_key, last_submission_key, submission_history_key, default_token_key, bounty_paused_key, claim_window_key, claims_opened_at_key, fee_refundable_key, next_id_key, next_project_id_key, project_key,
+    keeper_incentive_key, milestone_release_fee_key, pending_admin_key, pending_fee_account_key, submission_cooldown_key, owner_create_cooldown_key, last_creation_key,
+    lifetime_funded_key, lifetime_paid_key, operational_counters_key, owner_counts_key, proposal_key, token_decimals_key,
 };
-use events::Events;
-use storage::{admin_key, bounty_key, deployment_seq_key, fee_account_key, next_id_key, next_project_id_key, project_key};
 
 contractmeta!(key = "Version", val = "0.1.0");
 contractmeta!(
@@ -49,6 +63,7 @@ impl StallionContract {
         storage.set(&deployment_seq_key(), &env.ledger().sequence());
         Events::emit_admin_updated(&env, admin);
         Events::emit_fee_account_updated(&env, fee_account);
+        Events::emit_schema_version(&env);
     }
 
     // ========================================
@@ -63,285 +78,3991 @@ impl StallionContract {
         env.storage().persistent().get(&fee_account_key()).unwrap()
     }
 
-    // Returns the high-order base for IDs in this deployment.
-    // Upper 32 bits encode the ledger sequence at deployment time so that IDs
-    // from different contract deployments never collide.
-    fn id_base(env: &Env) -> u64 {
-        let seq: u32 = env.storage().persistent().get(&deployment_seq_key()).unwrap_or(0);
-        (seq as u64) << 32
+    // Decimals used to adjust amounts of `token`: the admin override when one
+    // is set, otherwise the value the token reports.
+    fn try_token_decimals(env: &Env, token: &Address) -> Result<u32, Error> {
+        let decimals: Option<u32> = env
+            .storage()
+            .persistent()
+            .get(&token_decimals_key(token.clone()));
+        match decimals {
+            Some(decimals) => Ok(decimals),
+            None => try_get_token_decimals(env, token),
+        }
     }
 
-    // ========================================
-    // ADMIN FUNCTIONS
-    // ========================================
-
-    pub fn update_admin(env: Env, new_admin: Address) -> Result<(), Error> {
-        let admin = Self::get_admin(&env);
-        admin.require_auth();
+    fn token_decimals(env: &Env, token: &Address) -> u32 {
+        Self::try_token_decimals(env, token).unwrap_or_else(|e| panic_with_error!(env, e))
+    }
 
-        if is_zero_address(&env, &new_admin) {
-            return Err(Error::AdminCannotBeZero);
+    // Rejects a submission made within the configured cooldown of the
+    // applicant's previous one on this bounty, then records the new time.
+    fn enforce_submission_cooldown(
+        env: &Env,
+        bounty_id: u64,
+        applicant: &Address,
+    ) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        let now = env.ledger().timestamp();
+        let cooldown: u64 = storage.get(&submission_cooldown_key()).unwrap_or(0);
+        let last: Option<u64> = storage.get(&last_submission_key(bounty_id, applicant.clone()));
+        if let Some(last) = last
+            && now < last.saturating_add(cooldown)
+        {
+            return Err(Error::SubmissionCooldown);
         }
-
-        env.storage().persistent().set(&admin_key(), &new_admin);
-        Events::emit_admin_updated(&env, new_admin.clone());
+        storage.set(&last_submission_key(bounty_id, applicant.clone()), &now);
         Ok(())
     }
 
-    pub fn update_fee_account(env: Env, new_fee_account: Address) -> Result<(), Error> {
-        let admin = Self::get_admin(&env);
-        admin.require_auth();
-
-        if is_zero_address(&env, &new_fee_account) {
-            return Err(Error::FeeAccountCannotBeZero);
+    // Rejects a bounty creation by `owner` within the configured cooldown of
+    // their previous one, then records this one.
+    fn enforce_create_cooldown(env: &Env, owner: &Address) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        let now = env.ledger().timestamp();
+        let cooldown: u64 = storage.get(&owner_create_cooldown_key()).unwrap_or(0);
+        let last: Option<u64> = storage.get(&last_creation_key(owner.clone()));
+        if let Some(last) = last
+            && now < last.saturating_add(cooldown)
+        {
+            return Err(Error::CreateCooldown);
         }
+        storage.set(&last_creation_key(owner.clone()), &now);
+        Ok(())
+    }
 
-        // Check if the new fee account is the same as the current fee account
-        let current_fee_account = Self::get_fee_account(&env);
-        if current_fee_account == new_fee_account {
-            return Err(Error::SameFeeAccount);
+    // Percentage fee for the given (decimal-adjusted) amount, bounded by the
+    // absolute cap configured for the token, if any.
+    fn charged_fee(
+        env: &Env,
+        token: &Address,
+        adjusted_amount: i128,
+        fee_type: FeeType,
+        decimals: u32,
+    ) -> i128 {
+        let fee = calculate_fee(adjusted_amount, fee_type);
+        let cap: Option<i128> = env.storage().persistent().get(&fee_cap_key(token.clone()));
+        match cap {
+            Some(cap) => fee.min(adjust_for_decimals(cap, decimals)),
+            None => fee,
         }
-
-        env.storage()
-            .persistent()
-            .set(&fee_account_key(), &new_fee_account);
-        Events::emit_fee_account_updated(&env, new_fee_account.clone());
-        Ok(())
     }
 
-    // ========================================
-    // BOUNTY QUERY FUNCTIONS
-    // ========================================
+    // Per-winner payouts of `total_reward` (already adjusted for token
+    // decimals) for the ranks that can be filled, in rank order.
+    fn allocate_rewards(
+        env: &Env,
+        bounty: &Bounty,
+        winners: &Vec<Address>,
+        total_reward: i128,
+    ) -> Vec<(Address, i128)> {
+        // Calculate how many winners we can actually reward
+        let actual_winners = winners.len().min(bounty.applicants.len());
+        let mut payouts = Vec::new(env);
+        let mut paid_percent: i128 = 0;
+        let mut distributed: i128 = 0;
 
-    pub fn get_bounties(env: Env) -> Vec<u64> {
-        let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut bounties = Vec::new(&env);
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
+        for i in 0..actual_winners {
+            let rank = i + 1;
+            if let Some(pct) = bounty.distribution.get(rank) {
+                let amount = Self::rank_share(total_reward, pct, bounty.rounding)
+                    .min(total_reward - distributed);
+                payouts.push_back((winners.get(i).unwrap(), amount));
+                paid_percent += pct as i128;
+                distributed += amount;
             }
+        }
 
-            bounties.push_back(id);
+        // Hand the rounding dust of the filled ranks to the top winner
+        if bounty.allocation == AllocationMode::LargestRemainder && !payouts.is_empty() {
+            let dust = total_reward * paid_percent / 100 - distributed;
+            let (top_winner, top_amount) = payouts.get(0).unwrap();
+            payouts.set(0, (top_winner, top_amount + dust));
         }
-        bounties
+
+        payouts
     }
 
-    pub fn get_user_bounties(env: Env, user: Address) -> Vec<u64> {
-        let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut bounties = Vec::new(&env);
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
-            }
+    // `total * pct / 100` rounded as the bounty asks.
+    fn rank_share(total: i128, pct: u32, rounding: RoundingMode) -> i128 {
+        let scaled = total * pct as i128;
+        let (share, rest) = (scaled / 100, scaled % 100);
+        let round_up = match rounding {
+            RoundingMode::Down => false,
+            RoundingMode::Up => rest > 0,
+            RoundingMode::Bankers => rest > 50 || (rest == 50 && share % 2 == 1),
+        };
+        if round_up { share + 1 } else { share }
+    }
 
-            let bounty = bounty.unwrap();
-            if bounty.submissions.contains_key(user.clone()) {
-                bounties.push_back(id);
-            }
+    // Primary-token payouts `select_winners` makes for `winners`.
+    fn winner_allocations(
+        env: &Env,
+        bounty: &Bounty,
+        winners: &Vec<Address>,
+    ) -> Vec<(Address, i128)> {
+        // Common case: one winner takes 100% of the reward, so there is no
+        // split to compute and nothing left over
+        let single_winner = bounty.distribution.len() == 1
+            && bounty.distribution.get(1) == Some(100)
+            && winners.len() == 1
+            && !bounty.applicants.is_empty();
+        if single_winner {
+            Vec::from_array(env, [(winners.get(0).unwrap(), bounty.reward)])
+        } else {
+            Self::allocate_rewards(env, bounty, winners, bounty.reward)
         }
-        bounties
     }
 
-    pub fn get_user_bounties_count(env: Env, user: Address) -> u32 {
-        let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut count = 0;
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
-            }
+    // Raises every allocation below the bounty's `min_per_winner` to that
+    // floor. Returns the raised allocations and the total top-up needed.
+    fn apply_winner_floor(
+        env: &Env,
+        bounty: &Bounty,
+        allocations: Vec<(Address, i128)>,
+    ) -> Result<(Vec<(Address, i128)>, i128), Error> {
+        if bounty.min_per_winner == 0 {
+            return Ok((allocations, 0));
+        }
 
-            let bounty = bounty.unwrap();
-            if bounty.submissions.contains_key(user.clone()) {
-                count += 1;
+        let mut raised = Vec::new(env);
+        let mut top_up = 0i128;
+        for (winner, amount) in allocations.iter() {
+            if amount < bounty.min_per_winner {
+                top_up = top_up
+                    .checked_add(bounty.min_per_winner - amount)
+                    .ok_or(Error::ArithmeticError)?;
+                raised.push_back((winner, bounty.min_per_winner));
+            } else {
+                raised.push_back((winner, amount));
             }
         }
-        count
+        Ok((raised, top_up))
     }
 
-    pub fn get_owner_bounties(env: Env, owner: Address) -> Vec<u64> {
-        let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut bounties = Vec::new(&env);
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
+    // Pulls `reward` (user-friendly units) plus the platform fee into the
+    // contract and forwards the fee, to `fee_recipient` when set. Returns the
+    // adjusted (reward, fee, part of the fee kept for the insurance pool).
+    // Fails with InsufficientBalance before moving anything when a payer
+    // cannot cover their part.
+    fn escrow_reward(
+        env: &Env,
+        owner: &Address,
+        fee_payer: &Option<Address>,
+        fee_recipient: &Option<Address>,
+        token: &Address,
+        reward: i128,
+        fee_only: bool,
+    ) -> Result<(i128, i128, i128), Error> {
+        // Get token decimals and adjust reward first to prevent precision loss in fee calculation
+        let token_client = get_token_client(env, token.clone());
+        let decimals = Self::token_decimals(env, token);
+        let adjusted_reward = adjust_for_decimals(reward, decimals);
+        let adjusted_fee =
+            Self::charged_fee(env, token, adjusted_reward, FeeType::Bounty, decimals);
+
+        match fee_payer {
+            // Sponsored: the owner escrows the reward, the fee payer covers the fee
+            Some(payer) => {
+                let owner_share = if fee_only { 0 } else { adjusted_reward };
+                if token_client.balance(owner) < owner_share
+                    || token_client.balance(payer) < adjusted_fee
+                {
+                    return Err(Error::InsufficientBalance);
+                }
+                if !fee_only {
+                    token_client.transfer(owner, &env.current_contract_address(), &adjusted_reward);
+                }
+                token_client.transfer(payer, &env.current_contract_address(), &adjusted_fee);
             }
-
-            let bounty = bounty.unwrap();
-            if bounty.owner == owner {
-                bounties.push_back(id);
+            // Transfer reward + fee from owner to contract
+            None => {
+                let adjusted_total = if fee_only { adjusted_fee } else { adjusted_reward + adjusted_fee };
+                if token_client.balance(owner) < adjusted_total {
+                    return Err(Error::InsufficientBalance);
+                }
+                token_client.transfer(owner, &env.current_contract_address(), &adjusted_total);
             }
         }
-        bounties
+
+        // Immediately transfer fee to fee account
+        let insured_fee = Self::forward_bounty_fee(env, token, adjusted_fee, fee_recipient);
+        Self::record_token(env, token);
+
+        Ok((adjusted_reward, adjusted_fee, insured_fee))
     }
 
-    pub fn get_owner_bounties_count(env: Env, owner: Address) -> u32 {
+    #[allow(clippy::too_many_arguments)]
+    fn create_bounty_internal(
+        env: &Env,
+        owner: Address,
+        rewards: Vec<(Address, i128)>,
+        distribution: Vec<(u32, u32)>,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        fee_payer: Option<Address>,
+        idempotency_key: Option<BytesN<32>>,
+        options: Option<BountyOptions>,
+        funding_deadline: Option<u64>,
+        parent_id: Option<u64>,
+    ) -> Result<u64, Error> {
         let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut count = 0;
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
-            }
+        let options = options.unwrap_or_default();
 
-            let bounty = bounty.unwrap();
-            if bounty.owner == owner {
-                count += 1;
+        if rewards.is_empty() {
+            return Err(Error::InvalidReward);
+        }
+        let external = options.reward_kind != RewardKind::Fungible;
+        if external && rewards.len() > 1 {
+            return Err(Error::InvalidReward);
+        }
+        let mut tokens = Map::new(env);
+        for (token, reward) in rewards.iter() {
+            if (!external && reward <= 0) || tokens.contains_key(token.clone()) {
+                return Err(Error::InvalidReward);
+            }
+            if !external && Self::try_token_decimals(env, &token)? > MAX_DECIMALS {
+                return Err(Error::UnsupportedTokenDecimals);
             }
+            tokens.set(token, true);
         }
-        count
-    }
 
-    pub fn get_bounties_by_token(env: Env, token: Address) -> Vec<u64> {
-        let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut bounties = Vec::new(&env);
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
-            }
+        if distribution.len() > MAX_DISTRIBUTION_RANKS {
+            return Err(Error::TooManyItems);
+        }
+        if !validate_distribution_sum(&distribution) {
+            return Err(Error::DistributionMustSumTo100);
+        }
 
-            let bounty = bounty.unwrap();
-            if bounty.token == token {
-                bounties.push_back(id);
+        validate_deadlines(
+            submission_deadline,
+            judging_deadline,
+            Self::deadline_clock(env, &options.deadline_mode),
+            Self::get_min_judging_gap(env.clone()),
+        )?;
+
+        // Crowdfunding raises a single fungible reward, and funding has to
+        // close before the submission deadline
+        if let Some(funding_deadline) = funding_deadline {
+            if external || rewards.len() > 1 {
+                return Err(Error::InvalidReward);
+            }
+            if funding_deadline <= Self::deadline_clock(env, &options.deadline_mode)
+                || funding_deadline >= submission_deadline
+            {
+                return Err(Error::InvalidDeadlineUpdate);
             }
         }
-        bounties
-    }
 
-    pub fn get_bounties_by_token_count(env: Env, token: Address) -> u32 {
-        let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut count = 0;
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
-            }
+        if let Some(allowed) = &options.allowed_applicants
+            && allowed.len() > MAX_BATCH_SIZE
+        {
+            return Err(Error::TooManyItems);
+        }
+        if options.min_per_winner < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if let Some(description) = &options.description
+            && description.len() > MAX_DESCRIPTION_LENGTH
+        {
+            return Err(Error::TooManyItems);
+        }
+        if let Some(recipient) = &options.fee_recipient
+            && !Self::is_fee_recipient_approved(env.clone(), recipient.clone())
+        {
+            return Err(Error::Unauthorized);
+        }
 
-            let bounty = bounty.unwrap();
-            if bounty.token == token {
-                count += 1;
+        // Only the parent's owner can hang tasks under it
+        let mut siblings = Vec::new(env);
+        if let Some(parent_id) = parent_id {
+            let parent: Bounty =
+                storage.get(&bounty_key(parent_id)).ok_or(Error::BountyNotFound)?;
+            if parent.owner != owner {
+                return Err(Error::OnlyOwner);
+            }
+            siblings = storage.get(&sub_bounties_key(parent_id)).unwrap_or(siblings);
+            if siblings.len() >= MAX_BATCH_SIZE {
+                return Err(Error::TooManyItems);
             }
         }
-        count
-    }
 
-    pub fn get_active_bounties(env: Env) -> Vec<u64> {
-        let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut active = Vec::new(&env);
+        owner.require_auth();
+        if let Some(payer) = &fee_payer {
+            payer.require_auth();
+        }
 
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
+        // A retried request carrying a key this owner already used returns the
+        // bounty created the first time instead of escrowing funds again
+        if let Some(key) = &idempotency_key {
+            let existing: Option<u64> =
+                storage.get(&idempotency_record_key(owner.clone(), key.clone()));
+            if let Some(existing_id) = existing {
+                return Ok(existing_id);
             }
+        }
 
-            let bounty = bounty.unwrap();
-            if bounty.status == Status::Active {
-                active.push_back(id);
-            }
+        let max_open: u32 = storage.get(&max_open_per_owner_key()).unwrap_or(0);
+        let open: u32 = storage.get(&open_bounty_count_key(owner.clone())).unwrap_or(0);
+        if max_open > 0 && open >= max_open {
+            return Err(Error::OwnerBountyLimitReached);
         }
-        active
+        Self::enforce_create_cooldown(env, &owner)?;
+
+        // External prizes are settled off-chain, so nothing is escrowed. A
+        // crowdfunded bounty only pays the fee on its goal up front; sponsors
+        // escrow the reward through `contribute`.
+        let (token, reward) = rewards.get(0).unwrap();
+        let crowdfunded = funding_deadline.is_some();
+        let (adjusted_reward, adjusted_fee, insured_fee) = if external {
+            (0, 0, 0)
+        } else {
+            Self::escrow_reward(
+                env,
+                &owner,
+                &fee_payer,
+                &options.fee_recipient,
+                &token,
+                reward,
+                crowdfunded,
+            )?
+        };
+        if adjusted_reward > 0 && !crowdfunded {
+            Self::record_funding(env, &token, adjusted_reward);
+        }
+
+        let mut extra_rewards = Vec::new(env);
+        let mut extra_fees = Vec::new(env);
+        for (extra_token, extra_reward) in rewards.slice(1..).iter() {
+            let (adjusted_extra, extra_fee, extra_insured) = Self::escrow_reward(
+                env,
+                &owner,
+                &fee_payer,
+                &options.fee_recipient,
+                &extra_token,
+                extra_reward,
+                false,
+            )?;
+            Self::record_funding(env, &extra_token, adjusted_extra);
+            extra_fees.push_back((extra_token.clone(), extra_fee, extra_insured));
+            extra_rewards.push_back((extra_token, adjusted_extra));
+        }
+
+        // Initialize bounty - store the adjusted reward amount
+        let mut distribution_map = Map::new(env);
+        for (rank, percent) in distribution.iter() {
+            distribution_map.set(rank, percent);
+        }
+        let bounty = Bounty {
+            owner: owner.clone(),
+            token: token.clone(),
+            reward: if crowdfunded { 0 } else { adjusted_reward }, // Store the adjusted reward amount
+            distribution: distribution_map,
+            submission_deadline,
+            judging_deadline,
+            title: title.clone(),
+            description: options.description.unwrap_or(String::from_str(env, "")),
+            status: if crowdfunded { Status::Funding } else { Status::Active },
+            applicants: Vec::new(env),
+            submissions: Map::new(env),
+            winners: Vec::new(env),
+            fee: adjusted_fee,
+            allocation: options.allocation,
+            rounding: options.rounding,
+            extra_rewards,
+            extra_fees,
+            payout: options.payout,
+            distribution_completed: false,
+            reward_kind: options.reward_kind,
+            remainder_recipient: options.remainder_recipient,
+            require_winner_acceptance: options.require_winner_acceptance,
+            deadline_mode: options.deadline_mode,
+            min_per_winner: if options.min_per_winner > 0 {
+                checked_adjust_for_decimals(options.min_per_winner, Self::token_decimals(env, &token))
+                    .ok_or(Error::ArithmeticError)?
+            } else {
+                0
+            },
+            created_at: env.ledger().timestamp(),
+            fee_bps_at_creation: if adjusted_reward > 0 {
+                (adjusted_fee * 10_000 / adjusted_reward) as u32
+            } else {
+                0
+            },
+            insured_fee,
+            parent_id,
+            fee_recipient: options.fee_recipient,
+        };
+        let id = Self::insert_bounty(env, &bounty);
+        if let Some(parent_id) = parent_id {
+            siblings.push_back(id);
+            storage.set(&sub_bounties_key(parent_id), &siblings);
+        }
+        if let Some(funding_deadline) = funding_deadline {
+            let crowdfund = Crowdfund {
+                goal: adjusted_reward,
+                funding_deadline,
+                contributions: Map::new(env),
+            };
+            storage.set(&crowdfund_key(id), &crowdfund);
+        }
+        if let Some(allowed) = options.allowed_applicants
+            && !allowed.is_empty()
+        {
+            storage.set(&allowlist_key(id), &allowed);
+        }
+        if let Some(key) = idempotency_key {
+            storage.set(&idempotency_record_key(owner.clone(), key), &id);
+        }
+
+        Ok(id)
     }
 
-    pub fn get_bounties_count(env: Env) -> u32 {
+    // Assigns the next bounty id to `bounty`, stores it and counts it as open.
+    fn insert_bounty(env: &Env, bounty: &Bounty) -> u64 {
         let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-
-        let mut count = 0;
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
-                continue;
-            }
 
-            count += 1;
-        }
+        // Assign new bounty ID — upper 32 bits encode deployment epoch for global uniqueness
+        let base = Self::id_base(env);
+        let id: u64 = storage.get(&next_id_key()).unwrap_or(base + 1);
+        storage.set(&next_id_key(), &(id + 1));
 
-        count
+        storage.set(&bounty_key(id), bounty);
+        Self::update_owner_count(env, &bounty.owner, true);
+        let open: u32 = storage.get(&open_bounty_count_key(bounty.owner.clone())).unwrap_or(0);
+        storage.set(&open_bounty_count_key(bounty.owner.clone()), &(open + 1));
+        Self::update_stats(env, |stats| {
+            stats.total_bounties += 1;
+            stats.active_bounties += 1;
+        });
+        Self::update_counters(env, |c| c.bounties_created += 1);
+        Events::emit_bounty_created(env, id);
+        id
     }
 
-    pub fn get_bounties_by_status(env: Env, status: Status) -> Vec<u64> {
+    // Runs when an occurrence of a recurring series settles. A completed
+    // occurrence spawns the next one, pulling its reward and fee from the
+    // owner's allowance; a closed one, or a failed pull, ends the series.
+    fn advance_series(env: &Env, bounty_id: u64, bounty: &Bounty, status: &Status) {
         let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut bounties = Vec::new(&env);
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
+        let series_id: Option<u64> = storage.get(&bounty_series_key(bounty_id));
+        if series_id.is_none() {
+            return;
+        }
+
+        let series_id = series_id.unwrap();
+        let mut series: Series = storage.get(&series_key(series_id)).unwrap();
+        if series.remaining == 0 {
+            return;
+        }
+
+        let decimals = Self::token_decimals(env, &series.token);
+        let adjusted_reward = adjust_for_decimals(series.reward, decimals);
+        let adjusted_fee =
+            Self::charged_fee(env, &series.token, adjusted_reward, FeeType::Bounty, decimals);
+        let token_client = get_token_client(env, series.token.clone());
+        let pulled = *status == Status::Completed
+            && matches!(
+                token_client.try_transfer_from(
+                    &env.current_contract_address(),
+                    &series.owner,
+                    &env.current_contract_address(),
+                    &(adjusted_reward + adjusted_fee),
+                ),
+                Ok(Ok(()))
+            );
+        if !pulled {
+            series.remaining = 0;
+            storage.set(&series_key(series_id), &series);
+            Events::emit_series_ended(env, series_id);
+            return;
+        }
+
+        let insured_fee =
+            Self::forward_bounty_fee(env, &series.token, adjusted_fee, &bounty.fee_recipient);
+        Self::record_funding(env, &series.token, adjusted_reward);
+
+        // Deadlines move by whole periods, skipping any slot whose submission
+        // window has already closed
+        let now = Self::deadline_clock(env, &bounty.deadline_mode);
+        let mut shift = series.period;
+        if bounty.submission_deadline + shift <= now {
+            let missed = (now - bounty.submission_deadline) / series.period;
+            shift = (missed + 1) * series.period;
+        }
+
+        let mut next = bounty.clone();
+        next.reward = adjusted_reward;
+        next.fee = adjusted_fee;
+        next.insured_fee = insured_fee;
+        next.fee_bps_at_creation = (adjusted_fee * 10_000 / adjusted_reward) as u32;
+        next.submission_deadline = bounty.submission_deadline + shift;
+        next.judging_deadline = bounty.judging_deadline + shift;
+        next.status = Status::Active;
+        next.applicants = Vec::new(env);
+        next.submissions = Map::new(env);
+        next.winners = Vec::new(env);
+        next.extra_rewards = Vec::new(env);
+        next.extra_fees = Vec::new(env);
+        next.distribution_completed = false;
+        next.created_at = env.ledger().timestamp();
+        let next_id = Self::insert_bounty(env, &next);
+
+        let allowed: Option<Vec<Address>> = storage.get(&allowlist_key(bounty_id));
+        if let Some(allowed) = allowed {
+            storage.set(&allowlist_key(next_id), &allowed);
+        }
+        storage.set(&bounty_series_key(next_id), &series_id);
+        series.bounties.push_back(next_id);
+        series.remaining -= 1;
+        storage.set(&series_key(series_id), &series);
+    }
+
+    // Sends an (adjusted) milestone amount out of escrow, splitting off the
+    // project's release fee for the fee account when one applies, and adds
+    // what the contributor received to their earnings history.
+    fn pay_out_milestone(
+        env: &Env,
+        project_id: u64,
+        project: &Project,
+        contributor: &Address,
+        amount: i128,
+    ) {
+        let token_client = get_token_client(env, project.token.clone());
+        let fee = amount * project.release_fee_percent as i128 / 100;
+        if fee > 0 {
+            Self::forward_fee(env, &project.token, fee);
+        }
+        let net = amount - fee;
+        token_client.transfer(&env.current_contract_address(), contributor, &net);
+        Self::record_paid(env, &project.token, net);
+
+        let storage = env.storage().persistent();
+        let project_key = project_earnings_key(project_id, contributor.clone());
+        if !storage.has(&project_key) {
+            // Remembered so `purge_project` can drop the per-project entries
+            let contributors_key = project_contributors_key(project_id);
+            let mut contributors: Vec<Address> =
+                storage.get(&contributors_key).unwrap_or(Vec::new(env));
+            contributors.push_back(contributor.clone());
+            storage.set(&contributors_key, &contributors);
+        }
+        let earned: i128 = storage.get(&project_key).unwrap_or(0);
+        storage.set(&project_key, &(earned + net));
+        let total_key = contributor_earnings_key(contributor.clone(), project.token.clone());
+        let total: i128 = storage.get(&total_key).unwrap_or(0);
+        storage.set(&total_key, &(total + net));
+    }
+
+    // Transfers a winner's share straight away in push mode, or credits it to
+    // the winner's claimable balance for the bounty in claim mode.
+    fn pay_winner(
+        env: &Env,
+        bounty: &Bounty,
+        bounty_id: u64,
+        token: &Address,
+        winner: &Address,
+        amount: i128,
+    ) {
+        match bounty.payout {
+            PayoutMode::Push if !bounty.require_winner_acceptance => {
+                let token_client = get_token_client(env, token.clone());
+                token_client.transfer(&env.current_contract_address(), winner, &amount);
+                Self::record_paid(env, token, amount);
+            }
+            _ => {
+                let storage = env.storage().persistent();
+                let key = claimable_reward_key(bounty_id, winner.clone());
+                let mut claims: Vec<(Address, i128)> =
+                    storage.get(&key).unwrap_or(Vec::new(env));
+                claims.push_back((token.clone(), amount));
+                storage.set(&key, &claims);
+            }
+        }
+    }
+
+    // Appends `link` to the applicant's revision history, dropping the oldest
+    // entry once the history is full.
+    fn record_revision(env: &Env, bounty_id: u64, applicant: &Address, link: String) {
+        let storage = env.storage().persistent();
+        let key = submission_history_key(bounty_id, applicant.clone());
+        let mut history: Vec<String> = storage.get(&key).unwrap_or(Vec::new(env));
+        if history.len() == MAX_SUBMISSION_REVISIONS {
+            history.pop_front();
+        }
+        history.push_back(link);
+        storage.set(&key, &history);
+    }
+
+    // Starts the window in which winners of an acceptance-gated bounty must
+    // accept their shares before the owner can take them back. For a plain
+    // claim-mode bounty, records when claims opened for `reclaim_unclaimed`.
+    fn open_claim_windows(env: &Env, bounty: &Bounty, bounty_id: u64) {
+        let now = env.ledger().timestamp();
+        if bounty.require_winner_acceptance {
+            env.storage()
+                .persistent()
+                .set(&acceptance_deadline_key(bounty_id), &(now + ACCEPTANCE_WINDOW));
+        } else if bounty.payout == PayoutMode::Claim {
+            env.storage().persistent().set(&claims_opened_at_key(bounty_id), &now);
+        }
+    }
+
+    // Sends every share of the bounty's winners that is still unclaimed to
+    // `to` and clears those claims. Returns the winners whose shares moved.
+    fn return_unclaimed(env: &Env, bounty_id: u64, bounty: &Bounty, to: &Address) -> Vec<Address> {
+        let storage = env.storage().persistent();
+        let mut unclaimed = Vec::new(env);
+        for winner in bounty.winners.iter() {
+            let key = claimable_reward_key(bounty_id, winner.clone());
+            let claims: Option<Vec<(Address, i128)>> = storage.get(&key);
+            if claims.is_none() {
                 continue;
             }
+            storage.remove(&key);
+            for (token, amount) in claims.unwrap().iter() {
+                let token_client = get_token_client(env, token);
+                token_client.transfer(&env.current_contract_address(), to, &amount);
+            }
+            unclaimed.push_back(winner);
+        }
+        unclaimed
+    }
 
-            let bounty = bounty.unwrap();
-            if bounty.status == status {
-                bounties.push_back(id);
+    // Transfers everything credited to `winner` on the bounty and clears the
+    // claim. Returns false when there was nothing to claim.
+    fn pay_out_claims(env: &Env, bounty_id: u64, winner: &Address) -> bool {
+        let storage = env.storage().persistent();
+        let key = claimable_reward_key(bounty_id, winner.clone());
+        let claims: Option<Vec<(Address, i128)>> = storage.get(&key);
+        if claims.is_none() {
+            return false;
+        }
+
+        storage.remove(&key);
+        for (token, amount) in claims.unwrap().iter() {
+            let token_client = get_token_client(env, token.clone());
+            token_client.transfer(&env.current_contract_address(), winner, &amount);
+            Self::record_paid(env, &token, amount);
+        }
+        Events::emit_reward_claimed(env, bounty_id, winner.clone());
+        true
+    }
+
+    // Primary-token amounts paid to each recipient of a bounty so far.
+    fn get_payouts(env: &Env, bounty_id: u64) -> Map<Address, i128> {
+        env.storage()
+            .persistent()
+            .get(&bounty_payouts_key(bounty_id))
+            .unwrap_or(Map::new(env))
+    }
+
+    // Adds an escrowed (adjusted) bounty reward to the token's lifetime total.
+    fn record_funding(env: &Env, token: &Address, amount: i128) {
+        let storage = env.storage().persistent();
+        let key = lifetime_funded_key(token.clone());
+        let funded: i128 = storage.get(&key).unwrap_or(0);
+        storage.set(&key, &(funded + amount));
+    }
+
+    // Adds `amount` of `token` actually sent to winners, applicants or
+    // contributors to the lifetime total.
+    fn record_paid(env: &Env, token: &Address, amount: i128) {
+        let storage = env.storage().persistent();
+        let key = lifetime_paid_key(token.clone());
+        let paid: i128 = storage.get(&key).unwrap_or(0);
+        storage.set(&key, &(paid + amount));
+    }
+
+    // Adds `token` to the set of tokens the contract has ever escrowed.
+    fn record_token(env: &Env, token: &Address) {
+        let storage = env.storage().persistent();
+        let mut tokens: Vec<Address> = storage.get(&used_tokens_key()).unwrap_or(Vec::new(env));
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            storage.set(&used_tokens_key(), &tokens);
+        }
+    }
+
+    // Keeps the owner -> live bounty count index behind `get_top_owners` in
+    // step with bounty creation and deletion.
+    fn update_owner_count(env: &Env, owner: &Address, created: bool) {
+        let storage = env.storage().persistent();
+        let mut counts: Map<Address, u32> =
+            storage.get(&owner_counts_key()).unwrap_or(Map::new(env));
+        let count = counts.get(owner.clone()).unwrap_or(0);
+        if created {
+            counts.set(owner.clone(), count + 1);
+        } else if count > 1 {
+            counts.set(owner.clone(), count - 1);
+        } else {
+            counts.remove(owner.clone());
+        }
+        storage.set(&owner_counts_key(), &counts);
+    }
+
+    fn load_stats(env: &Env) -> GlobalStats {
+        env.storage()
+            .persistent()
+            .get(&global_stats_key())
+            .unwrap_or(GlobalStats {
+                total_bounties: 0,
+                active_bounties: 0,
+                total_projects: 0,
+                active_projects: 0,
+                fees_collected: Map::new(env),
+            })
+    }
+
+    fn update_stats(env: &Env, update: impl FnOnce(&mut GlobalStats)) {
+        let mut stats = Self::load_stats(env);
+        update(&mut stats);
+        env.storage().persistent().set(&global_stats_key(), &stats);
+    }
+
+    // Lifecycle counters live in instance storage: small, global and read
+    // by monitoring on every poll.
+    fn update_counters(env: &Env, update: impl FnOnce(&mut Counters)) {
+        let storage = env.storage().instance();
+        let mut counters: Counters = storage.get(&operational_counters_key()).unwrap_or_default();
+        update(&mut counters);
+        storage.set(&operational_counters_key(), &counters);
+    }
+
+    // Sends a collected fee (already adjusted) to the fee account. If the fee
+    // account cannot receive `token` (e.g. its balance is frozen) the fee is
+    // held in the contract instead, so the operation that charged it still
+    // goes through; the fee account pulls it later with `claim_accrued_fees`.
+    fn forward_fee(env: &Env, token: &Address, fee: i128) {
+        let fee_account = Self::get_fee_account(env);
+        let token_client = get_token_client(env, token.clone());
+        let sent = token_client.try_transfer(&env.current_contract_address(), &fee_account, &fee);
+        if !matches!(sent, Ok(Ok(()))) {
+            Self::accrue_fee(env, token, fee);
+        }
+        Self::record_fee(env, token, fee);
+    }
+
+    // Holds a fee the contract could not send until the fee account claims it.
+    fn accrue_fee(env: &Env, token: &Address, fee: i128) {
+        let storage = env.storage().persistent();
+        let key = accrued_fees_key(token.clone());
+        let accrued: i128 = storage.get(&key).unwrap_or(0);
+        storage.set(&key, &(accrued + fee));
+        Events::emit_fee_accrued(env, token.clone(), fee);
+    }
+
+    // Sets the insurance share of a bounty fee aside in the contract and
+    // forwards the rest, to the bounty's own fee recipient if it has one.
+    // Returns the share kept.
+    fn forward_bounty_fee(
+        env: &Env,
+        token: &Address,
+        fee: i128,
+        fee_recipient: &Option<Address>,
+    ) -> i128 {
+        let bps: u32 = env.storage().persistent().get(&insurance_bps_key()).unwrap_or(0);
+        let insured = fee * bps as i128 / 10_000;
+        if insured > 0 {
+            Self::add_to_insurance_pool(env, token, insured);
+        }
+        match fee_recipient {
+            Some(recipient) => {
+                // A recipient that can't receive must not block settlement
+                // (recurring bounties charge the next fee inside it)
+                let token_client = get_token_client(env, token.clone());
+                let sent =
+                    token_client.try_transfer(&env.current_contract_address(), recipient, &(fee - insured));
+                if !matches!(sent, Ok(Ok(()))) {
+                    Self::accrue_fee(env, token, fee - insured);
+                }
+                Self::record_fee(env, token, fee - insured);
             }
+            None => Self::forward_fee(env, token, fee - insured),
         }
-        bounties
+        insured
     }
 
-    pub fn get_bounties_by_status_count(env: Env, status: Status) -> u32 {
+    fn add_to_insurance_pool(env: &Env, token: &Address, amount: i128) {
         let storage = env.storage().persistent();
-        let base = Self::id_base(&env);
-        let start = base + 1;
-        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
-        let mut count = 0;
-        for id in start..next_id {
-            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
-            if bounty.is_none() {
+        let key = insurance_pool_key(token.clone());
+        let pool: i128 = storage.get(&key).unwrap_or(0);
+        storage.set(&key, &(pool + amount));
+    }
+
+    fn record_fee(env: &Env, token: &Address, fee: i128) {
+        if fee > 0 {
+            Self::update_stats(env, |stats| {
+                let collected = stats.fees_collected.get(token.clone()).unwrap_or(0);
+                stats.fees_collected.set(token.clone(), collected + fee);
+            });
+        }
+    }
+
+    // Active and Judging bounties still hold their escrow.
+    fn is_unsettled(status: &Status) -> bool {
+        matches!(status, Status::Active | Status::Judging)
+    }
+
+    // Current point in time in the unit a bounty's deadlines use.
+    fn deadline_clock(env: &Env, mode: &DeadlineMode) -> u64 {
+        match mode {
+            DeadlineMode::Timestamp => env.ledger().timestamp(),
+            DeadlineMode::LedgerSeq => env.ledger().sequence() as u64,
+        }
+    }
+
+    // Status as clients should see it: an Active bounty past its submission
+    // deadline is Judging even before `advance_status` stores that.
+    fn effective_status(env: &Env, bounty: &Bounty) -> Status {
+        if bounty.status == Status::Active
+            && Self::deadline_clock(env, &bounty.deadline_mode) > bounty.submission_deadline
+        {
+            Status::Judging
+        } else {
+            bounty.status.clone()
+        }
+    }
+
+    // Moves a bounty to `status`, keeping the active bounty counter and any
+    // recurring series it belongs to in step.
+    fn set_bounty_status(env: &Env, bounty_id: u64, bounty: &mut Bounty, status: Status) {
+        if Self::is_unsettled(&bounty.status) && !Self::is_unsettled(&status) {
+            Self::bounty_settled(env, &bounty.owner);
+            Self::advance_series(env, bounty_id, bounty, &status);
+            match status {
+                Status::Completed => Self::update_counters(env, |c| c.bounties_completed += 1),
+                Status::Closed => Self::update_counters(env, |c| c.bounties_closed += 1),
+                _ => {}
+            }
+        }
+        bounty.status = status;
+    }
+
+    // Drops a bounty that is being settled or deleted from the open counts.
+    fn bounty_settled(env: &Env, owner: &Address) {
+        Self::update_stats(env, |stats| stats.active_bounties = stats.active_bounties.saturating_sub(1));
+        let storage = env.storage().persistent();
+        let key = open_bounty_count_key(owner.clone());
+        let open: u32 = storage.get(&key).unwrap_or(0);
+        if open > 1 {
+            storage.set(&key, &(open - 1));
+        } else {
+            storage.remove(&key);
+        }
+    }
+
+    // A project deadline can only be pushed back, and never into the past.
+    fn check_project_deadline(env: &Env, project: &Project, deadline: u64) -> Result<(), Error> {
+        if deadline < project.deadline {
+            return Err(Error::InvalidDeadlineUpdate);
+        }
+        if deadline <= env.ledger().timestamp() {
+            return Err(Error::DeadlinePassed);
+        }
+        Ok(())
+    }
+
+    // Moves a project to `status`, keeping the active project counter in step.
+    fn set_project_status(env: &Env, project: &mut Project, status: ProjectStatus) {
+        if project.status == ProjectStatus::Active && status != ProjectStatus::Active {
+            Self::update_stats(env, |stats| stats.active_projects = stats.active_projects.saturating_sub(1));
+        }
+        project.status = status;
+    }
+
+    // Address that receives whatever a settlement leaves undistributed.
+    fn remainder_recipient(env: &Env, bounty: &Bounty) -> Address {
+        match &bounty.remainder_recipient {
+            RemainderRecipient::Owner => bounty.owner.clone(),
+            RemainderRecipient::FeeAccount => Self::get_fee_account(env),
+            RemainderRecipient::Address(recipient) => recipient.clone(),
+        }
+    }
+
+    // Publishes the applicants who were not picked as winners, in pages of
+    // at most MAX_BATCH_SIZE addresses so each event stays small.
+    fn announce_not_selected(env: &Env, bounty_id: u64, bounty: &Bounty) {
+        let mut page = 0u32;
+        let mut batch = Vec::new(env);
+        for applicant in bounty.applicants.iter() {
+            if bounty.winners.contains(&applicant) {
                 continue;
             }
+            batch.push_back(applicant);
+            if batch.len() == MAX_BATCH_SIZE {
+                Events::emit_applicants_not_selected(env, bounty_id, page, batch);
+                batch = Vec::new(env);
+                page += 1;
+            }
+        }
+        if !batch.is_empty() {
+            Events::emit_applicants_not_selected(env, bounty_id, page, batch);
+        }
+    }
+
+    // Returns the escrowed reward of the bounty, in every token, to `to`.
+    fn refund_rewards(env: &Env, bounty: &Bounty, to: &Address) {
+        if bounty.reward > 0 {
+            let token_client = get_token_client(env, bounty.token.clone());
+            token_client.transfer(&env.current_contract_address(), to, &bounty.reward);
+        }
+        Self::refund_extra_rewards(env, bounty, to);
+    }
+
+    // Returns every additional-token reward of the bounty to `to`.
+    fn refund_extra_rewards(env: &Env, bounty: &Bounty, to: &Address) {
+        for (extra_token, extra_reward) in bounty.extra_rewards.iter() {
+            let token_client = get_token_client(env, extra_token);
+            token_client.transfer(&env.current_contract_address(), to, &extra_reward);
+        }
+    }
+
+    // Returns the high-order base for IDs in this deployment.
+    // Upper 32 bits encode the ledger sequence at deployment time so that IDs
+    // from different contract deployments never collide.
+    fn id_base(env: &Env) -> u64 {
+        let seq: u32 = env.storage().persistent().get(&deployment_seq_key()).unwrap_or(0);
+        (seq as u64) << 32
+    }
+
+    // ========================================
+    // ADMIN FUNCTIONS
+    // ========================================
+
+    pub fn update_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if is_zero_address(&env, &new_admin) {
+            return Err(Error::AdminCannotBeZero);
+        }
+
+        env.storage().persistent().set(&admin_key(), &new_admin);
+        Events::emit_admin_updated(&env, new_admin.clone());
+        Ok(())
+    }
+
+    // Two-step handover: the current admin proposes a successor, who must
+    // then call `accept_admin` to take over.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if is_zero_address(&env, &new_admin) {
+            return Err(Error::AdminCannotBeZero);
+        }
+
+        env.storage().persistent().set(&pending_admin_key(), &new_admin);
+        Events::emit_admin_proposed(&env, new_admin);
+        Ok(())
+    }
+
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        let pending: Option<Address> = storage.get(&pending_admin_key());
+        if pending.is_none() {
+            return Err(Error::NotAdmin);
+        }
+
+        let new_admin = pending.unwrap();
+        new_admin.require_auth();
+
+        storage.set(&admin_key(), &new_admin);
+        storage.remove(&pending_admin_key());
+        Events::emit_admin_updated(&env, new_admin);
+        Ok(())
+    }
+
+    pub fn cancel_admin_proposal(env: Env) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let storage = env.storage().persistent();
+        let pending: Option<Address> = storage.get(&pending_admin_key());
+        if pending.is_none() {
+            return Err(Error::NotAdmin);
+        }
+
+        storage.remove(&pending_admin_key());
+        Events::emit_admin_proposal_cancelled(&env, pending.unwrap());
+        Ok(())
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&pending_admin_key())
+    }
+
+    // Instant fee account change, kept for emergencies. Routine rotations
+    // should go through `propose_fee_account`; this drops any pending one.
+    pub fn update_fee_account(env: Env, new_fee_account: Address) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if is_zero_address(&env, &new_fee_account) {
+            return Err(Error::FeeAccountCannotBeZero);
+        }
+
+        // Check if the new fee account is the same as the current fee account
+        let current_fee_account = Self::get_fee_account(&env);
+        if current_fee_account == new_fee_account {
+            return Err(Error::SameFeeAccount);
+        }
+
+        let storage = env.storage().persistent();
+        storage.set(&fee_account_key(), &new_fee_account);
+        storage.remove(&pending_fee_account_key());
+        Events::emit_fee_account_updated(&env, new_fee_account.clone());
+        Ok(())
+    }
+
+    // Schedules a fee account change that anyone can apply through
+    // `apply_fee_account` once `effective_at` has passed, so the rotation is
+    // visible on-chain ahead of time.
+    pub fn propose_fee_account(
+        env: Env,
+        new_fee_account: Address,
+        effective_at: u64,
+    ) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if is_zero_address(&env, &new_fee_account) {
+            return Err(Error::FeeAccountCannotBeZero);
+        }
+        if Self::get_fee_account(&env) == new_fee_account {
+            return Err(Error::SameFeeAccount);
+        }
+        if effective_at <= env.ledger().timestamp() {
+            return Err(Error::DeadlinePassed);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&pending_fee_account_key(), &(new_fee_account.clone(), effective_at));
+        Events::emit_fee_account_proposed(&env, new_fee_account, effective_at);
+        Ok(())
+    }
+
+    pub fn apply_fee_account(env: Env) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        let pending: Option<(Address, u64)> = storage.get(&pending_fee_account_key());
+        if pending.is_none() {
+            return Err(Error::NothingToClaim);
+        }
+
+        let (new_fee_account, effective_at) = pending.unwrap();
+        if env.ledger().timestamp() < effective_at {
+            return Err(Error::TooEarly);
+        }
+
+        storage.set(&fee_account_key(), &new_fee_account);
+        storage.remove(&pending_fee_account_key());
+        Events::emit_fee_account_updated(&env, new_fee_account);
+        Ok(())
+    }
+
+    pub fn get_pending_fee_account(env: Env) -> Option<(Address, u64)> {
+        env.storage().persistent().get(&pending_fee_account_key())
+    }
+
+    // Pays out fees in `token` that were held in the contract because the
+    // fee account could not receive them at the time. Returns the amount.
+    pub fn claim_accrued_fees(env: Env, token: Address) -> Result<i128, Error> {
+        let fee_account = Self::get_fee_account(&env);
+        fee_account.require_auth();
+
+        let storage = env.storage().persistent();
+        let key = accrued_fees_key(token.clone());
+        let accrued: i128 = storage.get(&key).unwrap_or(0);
+        if accrued == 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        storage.remove(&key);
+        let token_client = get_token_client(&env, token);
+        token_client.transfer(&env.current_contract_address(), &fee_account, &accrued);
+        Ok(accrued)
+    }
+
+    pub fn get_accrued_fees(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&accrued_fees_key(token))
+            .unwrap_or(0)
+    }
+
+    // Caps the platform fee charged in `token` to `max_fee` (user-friendly
+    // units). Passing `None` removes the cap.
+    pub fn set_max_fee_absolute(
+        env: Env,
+        token: Address,
+        max_fee: Option<i128>,
+    ) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let storage = env.storage().persistent();
+        match max_fee {
+            Some(cap) => {
+                if cap < 0 {
+                    return Err(Error::InvalidAmount);
+                }
+                storage.set(&fee_cap_key(token.clone()), &cap);
+            }
+            None => storage.remove(&fee_cap_key(token.clone())),
+        }
+
+        Events::emit_fee_cap_updated(&env, token, max_fee);
+        Ok(())
+    }
+
+    pub fn get_max_fee_absolute(env: Env, token: Address) -> Option<i128> {
+        env.storage().persistent().get(&fee_cap_key(token))
+    }
+
+    // Minimum number of seconds an applicant must wait between two
+    // submissions to the same bounty. Zero disables the check.
+    pub fn set_submission_cooldown(env: Env, cooldown: u64) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&submission_cooldown_key(), &cooldown);
+        Events::emit_submission_cooldown_updated(&env, cooldown);
+        Ok(())
+    }
+
+    pub fn get_submission_cooldown(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&submission_cooldown_key())
+            .unwrap_or(0)
+    }
+
+    // Minimum number of seconds between two bounty creations by the same
+    // owner. Zero disables the check.
+    pub fn set_owner_create_cooldown(env: Env, cooldown: u64) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&owner_create_cooldown_key(), &cooldown);
+        Events::emit_owner_create_cooldown_updated(&env, cooldown);
+        Ok(())
+    }
+
+    pub fn get_owner_create_cooldown(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&owner_create_cooldown_key())
+            .unwrap_or(0)
+    }
+
+    // Seconds after creation during which closing a bounty nobody applied to
+    // makes its platform fee refundable through `refund_fee`. Zero disables
+    // refunds.
+    pub fn set_fee_refund_window(env: Env, window: u64) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if window > MAX_FEE_REFUND_WINDOW {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&fee_refund_window_key(), &window);
+        Events::emit_fee_refund_window_updated(&env, window);
+        Ok(())
+    }
+
+    // Seconds winners of a claim-mode bounty have to claim before the owner
+    // may reclaim their shares. Zero (the default) never lets owners reclaim.
+    pub fn set_claim_window(env: Env, window: u64) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if window != 0 && window < MIN_CLAIM_WINDOW {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().persistent().set(&claim_window_key(), &window);
+        Events::emit_claim_window_updated(&env, window);
+        Ok(())
+    }
+
+    pub fn get_claim_window(env: Env) -> u64 {
+        env.storage().persistent().get(&claim_window_key()).unwrap_or(0)
+    }
+
+    pub fn get_fee_refund_window(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&fee_refund_window_key())
+            .unwrap_or(0)
+    }
+
+    // Most unsettled (Active or Judging) bounties a single owner may have at
+    // once. Zero means unlimited.
+    pub fn set_max_open_per_owner(env: Env, limit: u32) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&max_open_per_owner_key(), &limit);
+        Events::emit_max_open_per_owner_updated(&env, limit);
+        Ok(())
+    }
+
+    pub fn get_max_open_per_owner(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&max_open_per_owner_key())
+            .unwrap_or(0)
+    }
+
+    // Minimum number of seconds between a bounty's submission and judging
+    // deadlines, so owners leave real review time. Zero disables the check.
+    pub fn set_min_judging_gap(env: Env, gap: u64) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        env.storage().persistent().set(&min_judging_gap_key(), &gap);
+        Events::emit_min_judging_gap_updated(&env, gap);
+        Ok(())
+    }
+
+    pub fn get_min_judging_gap(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&min_judging_gap_key())
+            .unwrap_or(0)
+    }
+
+    // When set, bounty owners may not apply to their own bounties. Off by
+    // default to keep existing behavior.
+    pub fn set_block_owner_apply(env: Env, blocked: bool) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        env.storage().persistent().set(&block_owner_apply_key(), &blocked);
+        Events::emit_block_owner_apply_updated(&env, blocked);
+        Ok(())
+    }
+
+    pub fn get_block_owner_apply(env: Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&block_owner_apply_key())
+            .unwrap_or(false)
+    }
+
+    // Approves `recipient` as a per-bounty fee recipient (e.g. a co-marketing
+    // campaign account) that bounty owners may name in their options.
+    pub fn set_fee_recipient_approved(
+        env: Env,
+        recipient: Address,
+        approved: bool,
+    ) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let key = approved_fee_recipient_key(recipient.clone());
+        if approved {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Events::emit_fee_recipient_approval_updated(&env, recipient, approved);
+        Ok(())
+    }
+
+    pub fn is_fee_recipient_approved(env: Env, recipient: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&approved_fee_recipient_key(recipient))
+    }
+
+    // Moves `amount` (user-friendly units) of `token` from the admin into the
+    // alternate-token pool used by `select_winners_alt`.
+    pub fn fund_alt_pool(env: Env, token: Address, amount: i128) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let decimals = Self::token_decimals(&env, &token);
+        let adjusted_amount = adjust_for_decimals(amount, decimals);
+        let token_client = get_token_client(&env, token.clone());
+        token_client.transfer(&admin, &env.current_contract_address(), &adjusted_amount);
+
+        let storage = env.storage().persistent();
+        let key = alt_pool_key(token.clone());
+        let balance: i128 = storage.get(&key).unwrap_or(0);
+        storage.set(&key, &(balance + adjusted_amount));
+        Events::emit_alt_pool_funded(&env, token, adjusted_amount);
+        Ok(())
+    }
+
+    // Balance of the alternate-token pool for `token`, in token units.
+    pub fn get_alt_pool(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&alt_pool_key(token))
+            .unwrap_or(0)
+    }
+
+    // Percentage of a bounty's reward paid to the keeper that settles it
+    // through `keeper_check_judging`, capped at MAX_KEEPER_INCENTIVE_PERCENT.
+    pub fn set_keeper_incentive(env: Env, percent: u32) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if percent > MAX_KEEPER_INCENTIVE_PERCENT {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&keeper_incentive_key(), &percent);
+        Events::emit_keeper_incentive_updated(&env, percent);
+        Ok(())
+    }
+
+    pub fn get_keeper_incentive(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&keeper_incentive_key())
+            .unwrap_or(0)
+    }
+
+    // Percentage taken from every milestone release of gigs created while it
+    // is set, forwarded to the fee account. Existing gigs keep their rate.
+    pub fn set_milestone_release_fee(env: Env, percent: u32) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if percent > MAX_MILESTONE_RELEASE_FEE_PERCENT {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&milestone_release_fee_key(), &percent);
+        Events::emit_milestone_release_fee_updated(&env, percent);
+        Ok(())
+    }
+
+    pub fn get_milestone_release_fee(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&milestone_release_fee_key())
+            .unwrap_or(0)
+    }
+
+    // Read-only sanity check of the global state for monitoring. Id counters
+    // are sane while unset or at least one past this deployment's id base.
+    pub fn health_check(env: Env) -> HealthReport {
+        let storage = env.storage().persistent();
+        let first_id = Self::id_base(&env) + 1;
+        let id_ok = |next: Option<u64>| next.is_none_or(|next| next >= first_id);
+
+        let admin_set = storage.has(&admin_key());
+        let fee_account_set = storage.has(&fee_account_key());
+        let bounty_ids_ok = id_ok(storage.get(&next_id_key()));
+        let project_ids_ok = id_ok(storage.get(&next_project_id_key()));
+        let hackathon_ids_ok = id_ok(storage.get(&crate::storage::next_hackathon_id_key()));
+
+        HealthReport {
+            healthy: admin_set
+                && fee_account_set
+                && bounty_ids_ok
+                && project_ids_ok
+                && hackathon_ids_ok,
+            admin_set,
+            fee_account_set,
+            bounty_ids_ok,
+            project_ids_ok,
+            hackathon_ids_ok,
+        }
+    }
+
+    // Compares the contract's balance of `token` with everything it owes in
+    // that token: escrow of open bounties, projects and hackathons, unclaimed
+    // winner shares, and the alt, insurance and accrued-fee pools. Returns
+    // (held, owed, solvent), all adjusted. Scans every entity, so this is
+    // meant for off-chain monitoring.
+    pub fn check_solvency(env: Env, token: Address) -> (i128, i128, bool) {
+        let storage = env.storage().persistent();
+        let start = Self::id_base(&env) + 1;
+        let mut owed: i128 = storage.get(&alt_pool_key(token.clone())).unwrap_or(0);
+        owed += storage.get(&insurance_pool_key(token.clone())).unwrap_or(0);
+        owed += storage.get(&accrued_fees_key(token.clone())).unwrap_or(0);
+
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if Self::is_unsettled(&bounty.status) || bounty.status == Status::Funding {
+                if bounty.token == token {
+                    owed += bounty.reward;
+                }
+                for (extra_token, extra_reward) in bounty.extra_rewards.iter() {
+                    if extra_token == token {
+                        owed += extra_reward;
+                    }
+                }
+            }
+            for winner in bounty.winners.iter() {
+                let claims: Vec<(Address, i128)> = storage
+                    .get(&claimable_reward_key(id, winner))
+                    .unwrap_or(Vec::new(&env));
+                for (claim_token, amount) in claims.iter() {
+                    if claim_token == token {
+                        owed += amount;
+                    }
+                }
+            }
+        }
+
+        let next_id: u64 = storage.get(&next_project_id_key()).unwrap_or(start);
+        for id in start..next_id {
+            let project: Option<Project> = storage.get(&project_key(id));
+            if let Some(project) = project
+                && project.status == ProjectStatus::Active
+                && project.token == token
+            {
+                owed += project.remaining_escrow;
+            }
+        }
+
+        let next_id: u64 = storage
+            .get(&crate::storage::next_hackathon_id_key())
+            .unwrap_or(start);
+        for id in start..next_id {
+            let hackathon: Option<Hackathon> = storage.get(&crate::storage::hackathon_key(id));
+            if let Some(hackathon) = hackathon
+                && hackathon.status == HackathonStatus::Active
+                && hackathon.token == token
+            {
+                owed += hackathon.remaining_escrow;
+            }
+        }
+
+        let held = get_token_client(&env, token).balance(&env.current_contract_address());
+        (held, owed, held >= owed)
+    }
+
+    // Lets frontends hide admin-only actions; false before initialization.
+    pub fn is_admin(env: Env, addr: Address) -> bool {
+        let admin: Option<Address> = env.storage().persistent().get(&admin_key());
+        admin == Some(addr)
+    }
+
+    pub fn get_config(env: Env) -> ConfigView {
+        ConfigView {
+            admin: Self::get_admin(&env),
+            fee_account: Self::get_fee_account(&env),
+            bounty_fee_percent: BOUNTY_PLATFORM_FEE_PERCENT,
+            gig_fee_percent: GIG_PLATFORM_FEE_PERCENT,
+            job_fee_percent: JOB_PLATFORM_FEE_PERCENT,
+            hackathon_fee_percent: HACKATHON_PLATFORM_FEE_PERCENT,
+            submission_cooldown: Self::get_submission_cooldown(env.clone()),
+            owner_create_cooldown: Self::get_owner_create_cooldown(env.clone()),
+            keeper_incentive_percent: Self::get_keeper_incentive(env.clone()),
+            milestone_release_fee_percent: Self::get_milestone_release_fee(env.clone()),
+            fee_refund_window: Self::get_fee_refund_window(env.clone()),
+            max_open_per_owner: Self::get_max_open_per_owner(env.clone()),
+            min_judging_gap: Self::get_min_judging_gap(env.clone()),
+            block_owner_apply: Self::get_block_owner_apply(env.clone()),
+            emergency_delay: Self::get_emergency_delay(env.clone()),
+            insurance_bps: Self::get_insurance_bps(env.clone()),
+            claim_window: Self::get_claim_window(env.clone()),
+            default_token: Self::get_default_token(env.clone()),
+            event_schema_version: EVENT_SCHEMA_VERSION,
+        }
+    }
+
+    // Overrides the decimals used for `token`, for tokens whose `decimals()`
+    // is missing or wrong. Passing `None` reverts to the reported value.
+    pub fn set_token_decimals(
+        env: Env,
+        token: Address,
+        decimals: Option<u32>,
+    ) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if let Some(value) = decimals
+            && value > MAX_DECIMALS
+        {
+            return Err(Error::UnsupportedTokenDecimals);
+        }
+
+        let storage = env.storage().persistent();
+        match decimals {
+            Some(value) => storage.set(&token_decimals_key(token.clone()), &value),
+            None => storage.remove(&token_decimals_key(token.clone())),
+        }
+
+        Events::emit_token_decimals_updated(&env, token, decimals);
+        Ok(())
+    }
+
+    pub fn get_token_decimals_override(env: Env, token: Address) -> Option<u32> {
+        env.storage().persistent().get(&token_decimals_key(token))
+    }
+
+    // Keeper-friendly maintenance: extends the TTL of every bounty and project
+    // entry stored under the given ids, along with the contract instance so the
+    // entries remain reachable. Unknown ids are skipped.
+    pub fn bump_many_ttl(env: Env, ids: Vec<u64>) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::TooManyItems);
+        }
+
+        env.storage()
+            .instance()
+            .extend_ttl(PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        let storage = env.storage().persistent();
+        for id in ids.iter() {
+            if storage.has(&bounty_key(id)) {
+                storage.extend_ttl(
+                    &bounty_key(id),
+                    PERSISTENT_LIFETIME_THRESHOLD,
+                    PERSISTENT_BUMP_AMOUNT,
+                );
+            }
+            if storage.has(&project_key(id)) {
+                storage.extend_ttl(
+                    &project_key(id),
+                    PERSISTENT_LIFETIME_THRESHOLD,
+                    PERSISTENT_BUMP_AMOUNT,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // ========================================
+    // BOUNTY QUERY FUNCTIONS
+    // ========================================
+
+    pub fn get_bounties(env: Env) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            bounties.push_back(id);
+        }
+        bounties
+    }
+
+    pub fn get_user_bounties(env: Env, user: Address) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.submissions.contains_key(user.clone()) {
+                bounties.push_back(id);
+            }
+        }
+        bounties
+    }
+
+    // Created bounties come from the owner count index; applications, wins
+    // and projects need a scan.
+    pub fn get_user_profile(env: Env, user: Address) -> UserProfile {
+        let storage = env.storage().persistent();
+        let counts: Map<Address, u32> =
+            storage.get(&owner_counts_key()).unwrap_or(Map::new(&env));
+
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut applied = 0;
+        let mut won = 0;
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.submissions.contains_key(user.clone()) {
+                applied += 1;
+            }
+            if bounty.winners.contains(user.clone()) {
+                won += 1;
+            }
+        }
+
+        UserProfile {
+            bounties_created: counts.get(user.clone()).unwrap_or(0),
+            bounties_applied: applied,
+            bounties_won: won,
+            projects_owned: Self::get_owner_projects(env.clone(), user).len(),
+        }
+    }
+
+    pub fn get_user_bounties_count(env: Env, user: Address) -> u32 {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut count = 0;
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.submissions.contains_key(user.clone()) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    pub fn get_owner_bounties(env: Env, owner: Address) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.owner == owner {
+                bounties.push_back(id);
+            }
+        }
+        bounties
+    }
+
+    pub fn get_owner_bounties_count(env: Env, owner: Address) -> u32 {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut count = 0;
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.owner == owner {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // Owners with the most live bounties, highest count first. `limit` is
+    // capped at MAX_BATCH_SIZE.
+    pub fn get_top_owners(env: Env, limit: u32) -> Vec<(Address, u32)> {
+        let counts: Map<Address, u32> = env
+            .storage()
+            .persistent()
+            .get(&owner_counts_key())
+            .unwrap_or(Map::new(&env));
+        let limit = limit.min(MAX_BATCH_SIZE);
+
+        let mut top: Vec<(Address, u32)> = Vec::new(&env);
+        for (owner, count) in counts.iter() {
+            let mut index = top.len();
+            while index > 0 && top.get(index - 1).unwrap().1 < count {
+                index -= 1;
+            }
+            if index < limit {
+                top.insert(index, (owner, count));
+                if top.len() > limit {
+                    top.pop_back();
+                }
+            }
+        }
+        top
+    }
+
+    // Admin-curated list of promoted bounties, capped at MAX_BATCH_SIZE.
+    // Featuring an already featured bounty (or unfeaturing one that isn't)
+    // leaves the list unchanged.
+    pub fn set_featured(env: Env, bounty_id: u64, featured: bool) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let storage = env.storage().persistent();
+        if !storage.has(&bounty_key(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut featured_ids: Vec<u64> =
+            storage.get(&featured_bounties_key()).unwrap_or(Vec::new(&env));
+        let index = featured_ids.first_index_of(bounty_id);
+        match (featured, index) {
+            (true, None) => {
+                if featured_ids.len() >= MAX_BATCH_SIZE {
+                    return Err(Error::TooManyItems);
+                }
+                featured_ids.push_back(bounty_id);
+            }
+            (false, Some(index)) => {
+                featured_ids.remove(index);
+            }
+            _ => {}
+        }
+        storage.set(&featured_bounties_key(), &featured_ids);
+
+        Events::emit_bounty_featured(&env, bounty_id, featured);
+        Ok(())
+    }
+
+    pub fn get_featured_bounties(env: Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&featured_bounties_key())
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // The owner's bounties that `delete_bounty` would accept, i.e. those
+    // without any submissions.
+    pub fn get_owner_deletable_bounties(env: Env, owner: Address) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.owner == owner && bounty.submissions.is_empty() {
+                bounties.push_back(id);
+            }
+        }
+        bounties
+    }
+
+    // Unsettled bounties nobody submitted to whose judging deadline has
+    // passed, i.e. candidates for an emergency refund during cleanup. Scans
+    // every bounty and returns at most MAX_BATCH_SIZE ids, oldest first.
+    pub fn get_admin_cleanable(env: Env) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if Self::is_unsettled(&bounty.status)
+                && bounty.submissions.is_empty()
+                && Self::deadline_clock(&env, &bounty.deadline_mode) > bounty.judging_deadline
+            {
+                bounties.push_back(id);
+                if bounties.len() == MAX_BATCH_SIZE {
+                    break;
+                }
+            }
+        }
+        bounties
+    }
+
+    pub fn get_bounties_by_token(env: Env, token: Address) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.token == token {
+                bounties.push_back(id);
+            }
+        }
+        bounties
+    }
+
+    pub fn get_bounties_by_token_count(env: Env, token: Address) -> u32 {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut count = 0;
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if bounty.token == token {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    pub fn get_active_bounties(env: Env) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut active = Vec::new(&env);
+
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if Self::is_unsettled(&bounty.status) {
+                active.push_back(id);
+            }
+        }
+        active
+    }
+
+    // Number of bounties `get_open_for_submission` lists.
+    pub fn get_open_bounty_count(env: Env) -> u32 {
+        Self::get_open_for_submission(env).len()
+    }
+
+    // Active bounties whose judging deadline falls on or before
+    // `now + within_seconds`, including overdue ones a keeper can already
+    // settle through `check_judging`.
+    pub fn get_bounties_due_for_judging(env: Env, within_seconds: u64) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut due = Vec::new(&env);
+
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            // Ledger-sequence bounties count the window in ledgers
+            let bounty = bounty.unwrap();
+            let horizon = Self::deadline_clock(&env, &bounty.deadline_mode)
+                .saturating_add(within_seconds);
+            if Self::is_unsettled(&bounty.status) && bounty.judging_deadline <= horizon {
+                due.push_back(id);
+            }
+        }
+        due
+    }
+
+    // Id the next created bounty will get, without reserving it.
+    pub fn peek_next_bounty_id(env: Env) -> u64 {
+        let start = Self::id_base(&env) + 1;
+        env.storage().persistent().get(&next_id_key()).unwrap_or(start)
+    }
+
+    // Id the next created project will get, without reserving it.
+    pub fn peek_next_project_id(env: Env) -> u64 {
+        let start = Self::id_base(&env) + 1;
+        env.storage()
+            .persistent()
+            .get(&next_project_id_key())
+            .unwrap_or(start)
+    }
+
+    pub fn get_bounties_count(env: Env) -> u32 {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+
+        let mut count = 0;
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            count += 1;
+        }
+
+        count
+    }
+
+    pub fn get_bounties_by_status(env: Env, status: Status) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if Self::effective_status(&env, &bounty) == status {
+                bounties.push_back(id);
+            }
+        }
+        bounties
+    }
+
+    // Bounties currently accepting submissions: Active and not yet past
+    // their submission deadline.
+    pub fn get_open_for_submission(env: Env) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            if Self::is_open_for_submission(&env, &bounty.unwrap()) {
+                bounties.push_back(id);
+            }
+        }
+        bounties
+    }
+
+    fn is_open_for_submission(env: &Env, bounty: &Bounty) -> bool {
+        bounty.status == Status::Active
+            && Self::deadline_clock(env, &bounty.deadline_mode) <= bounty.submission_deadline
+    }
+
+    pub fn get_bounties_by_status_count(env: Env, status: Status) -> u32 {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut count = 0;
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if Self::effective_status(&env, &bounty) == status {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // Number of bounties in every status from a single scan; statuses no
+    // bounty is in are listed with a zero count.
+    pub fn get_status_breakdown(env: Env) -> Map<Status, u32> {
+        let mut breakdown = Map::new(&env);
+        for status in [
+            Status::Active,
+            Status::Judging,
+            Status::Completed,
+            Status::Closed,
+            Status::Funding,
+        ] {
+            breakdown.set(status, 0u32);
+        }
+
+        let storage = env.storage().persistent();
+        let start = Self::id_base(&env) + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if let Some(bounty) = bounty {
+                let status = Self::effective_status(&env, &bounty);
+                let count = breakdown.get(status.clone()).unwrap_or(0);
+                breakdown.set(status, count + 1);
+            }
+        }
+        breakdown
+    }
+
+    // Bounties whose status is any of `statuses`, in a single scan.
+    pub fn get_bounties_by_statuses(env: Env, statuses: Vec<Status>) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_id_key()).unwrap_or(start);
+        let mut bounties = Vec::new(&env);
+        for id in start..next_id {
+            let bounty: Option<Bounty> = storage.get(&bounty_key(id));
+            if bounty.is_none() {
+                continue;
+            }
+
+            let bounty = bounty.unwrap();
+            if statuses.contains(Self::effective_status(&env, &bounty)) {
+                bounties.push_back(id);
+            }
+        }
+        bounties
+    }
+
+    // Total the owner would be charged to create a bounty with `reward`:
+    // (user-friendly total, decimal-adjusted total), fee included.
+    pub fn estimate_bounty_cost(env: Env, token: Address, reward: i128) -> (i128, i128) {
+        let decimals = Self::token_decimals(&env, &token);
+        let adjusted_reward = adjust_for_decimals(reward, decimals);
+        let adjusted_fee =
+            Self::charged_fee(&env, &token, adjusted_reward, FeeType::Bounty, decimals);
+        let adjusted_total = adjusted_reward + adjusted_fee;
+        (convert_from_token_amount(adjusted_total, decimals), adjusted_total)
+    }
+
+    // Bounty and project counts plus platform fees collected per token, read
+    // from counters kept up to date on every create and status change.
+    pub fn get_global_stats(env: Env) -> GlobalStats {
+        Self::load_stats(&env)
+    }
+
+    // Platform fees collected per token, in each token's own units. No price
+    // feed is available on-chain, so converting to a common unit is left to
+    // the client.
+    pub fn get_fee_breakdown(env: Env) -> Vec<(Address, i128)> {
+        let mut breakdown = Vec::new(&env);
+        for (token, collected) in Self::load_stats(&env).fees_collected.iter() {
+            breakdown.push_back((token, collected));
+        }
+        breakdown
+    }
+
+    pub fn get_operational_counters(env: Env) -> Counters {
+        env.storage()
+            .instance()
+            .get(&operational_counters_key())
+            .unwrap_or_default()
+    }
+
+    // Every token used to fund a bounty, project or hackathon, in first-use
+    // order.
+    pub fn get_used_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&used_tokens_key())
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // Total bounty reward ever escrowed in `token` (adjusted amount). Unlike
+    // the contract's token balance this never decreases on payouts.
+    pub fn get_lifetime_funded(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&lifetime_funded_key(token))
+            .unwrap_or(0)
+    }
+
+    // Total of `token` paid out to winners, applicants and contributors.
+    pub fn get_lifetime_paid(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&lifetime_paid_key(token))
+            .unwrap_or(0)
+    }
+
+    pub fn get_bounty(env: Env, bounty_id: u64) -> Result<Bounty, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+        bounty.status = Self::effective_status(&env, &bounty);
+        Ok(bounty)
+    }
+
+    // `get_bounty` that also extends the entry's TTL, keeping read-mostly
+    // bounties alive. Unlike `get_bounty` this writes ledger state, so it has
+    // to be submitted as a transaction and pays rent for the extension.
+    pub fn get_bounty_touch(env: Env, bounty_id: u64) -> Result<Bounty, Error> {
+        let bounty = Self::get_bounty(env.clone(), bounty_id)?;
+        env.storage().persistent().extend_ttl(
+            &bounty_key(bounty_id),
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        Ok(bounty)
+    }
+
+    // Existence check that skips deserializing the bounty.
+    pub fn bounty_exists(env: Env, bounty_id: u64) -> bool {
+        env.storage().persistent().has(&bounty_key(bounty_id))
+    }
+
+    // One-call view of a bounty for indexers catching up or starting fresh.
+    pub fn get_bounty_snapshot(env: Env, bounty_id: u64) -> Result<BountySnapshot, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        let applicant_count = bounty.applicants.len();
+        Ok(BountySnapshot {
+            id: bounty_id,
+            status: Self::effective_status(&env, &bounty),
+            payouts: Self::get_payouts(&env, bounty_id),
+            applicants: bounty.applicants.slice(0..applicant_count.min(MAX_BATCH_SIZE)),
+            applicant_count,
+            submission_count: bounty.submissions.len(),
+            owner: bounty.owner,
+            token: bounty.token,
+            title: bounty.title,
+            description: bounty.description,
+            reward: bounty.reward,
+            fee: bounty.fee,
+            created_at: bounty.created_at,
+            submission_deadline: bounty.submission_deadline,
+            judging_deadline: bounty.judging_deadline,
+            winners: bounty.winners,
+            distribution_completed: bounty.distribution_completed,
+        })
+    }
+
+    pub fn get_bounty_stats(env: Env, bounty_id: u64) -> Result<BountyStats, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        let mut total_distributed: i128 = 0;
+        for (_, amount) in Self::get_payouts(&env, bounty_id).iter() {
+            total_distributed += amount;
+        }
+        let now = Self::deadline_clock(&env, &bounty.deadline_mode);
+        Ok(BountyStats {
+            applicant_count: bounty.applicants.len(),
+            winner_count: bounty.winners.len(),
+            total_distributed,
+            submission_deadline_passed: now > bounty.submission_deadline,
+            judging_deadline_passed: now > bounty.judging_deadline,
+        })
+    }
+
+    pub fn get_submission(env: Env, bounty_id: u64, user: Address) -> Result<String, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        let submission = bounty.submissions.get(user);
+        if submission.is_none() {
+            return Err(Error::SubmissionNotFound);
+        }
+
+        Ok(submission.unwrap())
+    }
+
+    // Every link the applicant submitted, oldest first; only the last
+    // MAX_SUBMISSION_REVISIONS are kept. The last entry is `get_submission`.
+    pub fn get_submission_history(env: Env, bounty_id: u64, user: Address) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&submission_history_key(bounty_id, user))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // Submission link and proposal text; the proposal is empty when none was given.
+    pub fn get_submission_full(
+        env: Env,
+        bounty_id: u64,
+        user: Address,
+    ) -> Result<(String, String), Error> {
+        let link = Self::get_submission(env.clone(), bounty_id, user.clone())?;
+        let proposal: Option<String> =
+            env.storage().persistent().get(&proposal_key(bounty_id, user));
+        Ok((link, proposal.unwrap_or(String::from_str(&env, ""))))
+    }
+
+    // Whole submission map; bounties with more than MAX_BATCH_SIZE
+    // submissions fail with TooManyItems instead of overrunning the host's
+    // output limit, and have to be read through `get_submission_links`.
+    pub fn get_bounty_submissions(env: Env, bounty_id: u64) -> Result<Map<Address, String>, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        if bounty.submissions.len() > MAX_BATCH_SIZE {
+            return Err(Error::TooManyItems);
+        }
+        Ok(bounty.submissions)
+    }
+
+    // Number of applicants, read from a counter kept in step with the
+    // applicant list so the bounty itself is never loaded.
+    pub fn get_bounty_applicant_count(env: Env, bounty_id: u64) -> Result<u32, Error> {
+        let storage = env.storage().persistent();
+        if !storage.has(&bounty_key(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        Ok(storage.get(&applicant_count_key(bounty_id)).unwrap_or(0))
+    }
+
+    pub fn get_bounty_applicants(env: Env, bounty_id: u64) -> Result<Vec<Address>, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        Ok(bounty.applicants)
+    }
+
+    // Submission links in applicant order, without the applicant addresses,
+    // for public galleries. `limit` is capped at MAX_BATCH_SIZE.
+    pub fn get_submission_links(
+        env: Env,
+        bounty_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<String>, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        let end = start
+            .saturating_add(limit.min(MAX_BATCH_SIZE))
+            .min(bounty.applicants.len());
+        let mut links = Vec::new(&env);
+        for index in start..end {
+            let applicant = bounty.applicants.get(index).unwrap();
+            if let Some(link) = bounty.submissions.get(applicant) {
+                links.push_back(link);
+            }
+        }
+        Ok(links)
+    }
+
+    // 1-based position of `applicant` in submission order.
+    pub fn get_applicant_position(
+        env: Env,
+        bounty_id: u64,
+        applicant: Address,
+    ) -> Result<u32, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        match bounty.applicants.first_index_of(&applicant) {
+            Some(index) => Ok(index + 1),
+            None => Err(Error::SubmissionNotFound),
+        }
+    }
+
+    pub fn get_bounty_winners(env: Env, bounty_id: u64) -> Result<Vec<Address>, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        Ok(bounty.winners)
+    }
+
+    pub fn get_winner_count(env: Env, bounty_id: u64) -> Result<u32, Error> {
+        let bounty: Bounty = env
+            .storage()
+            .persistent()
+            .get(&bounty_key(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        Ok(bounty.winners.len())
+    }
+
+    // Winners paired with the distribution rank they were paid for. Winners
+    // beyond the last rank received nothing and are omitted.
+    pub fn get_bounty_winners_ranked(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<Vec<(u32, Address)>, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        let mut ranked = Vec::new(&env);
+        for (i, winner) in bounty.winners.iter().enumerate() {
+            let rank = i as u32 + 1;
+            if bounty.distribution.contains_key(rank) {
+                ranked.push_back((rank, winner));
+            }
+        }
+        Ok(ranked)
+    }
+
+    // Primary-token reward still held in escrow: the reward minus everything
+    // paid out so far while the bounty is Active, and zero once it is settled.
+    pub fn get_bounty_remaining(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        if !Self::is_unsettled(&bounty.status) {
+            return Ok(0);
+        }
+
+        let mut paid = 0i128;
+        for (_, amount) in Self::get_payouts(&env, bounty_id).iter() {
+            paid += amount;
+        }
+        Ok(bounty.reward - paid)
+    }
+
+    pub fn get_bounty_status(env: Env, bounty_id: u64) -> Result<Status, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        Ok(Self::effective_status(&env, &bounty))
+    }
+
+    // Lifecycle phase derived from the status and the bounty's deadline clock.
+    pub fn get_bounty_phase(env: Env, bounty_id: u64) -> Result<Phase, Error> {
+        let status = Self::get_bounty_status(env, bounty_id)?;
+        Ok(match status {
+            Status::Active => Phase::Submission,
+            Status::Judging => Phase::Judging,
+            Status::Completed => Phase::Settled,
+            Status::Closed => Phase::Closed,
+            Status::Funding => Phase::Funding,
+        })
+    }
+
+    // Re-publishes the bounty's core fields so an indexer can resync a single
+    // bounty without reading a full snapshot. Anyone may call it.
+    pub fn emit_bounty_state(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let bounty: Bounty = env
+            .storage()
+            .persistent()
+            .get(&bounty_key(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let status = Self::get_bounty_status(env.clone(), bounty_id)?;
+        Events::emit_bounty_state(
+            &env,
+            bounty_id,
+            bounty.owner,
+            bounty.token,
+            bounty.reward,
+            status,
+            bounty.submission_deadline,
+            bounty.judging_deadline,
+            bounty.applicants.len(),
+            bounty.winners.len(),
+        );
+        Ok(())
+    }
+
+    pub fn get_bounty_owner(env: Env, bounty_id: u64) -> Result<Address, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        Ok(bounty.owner)
+    }
+
+    // Seconds since the bounty was created, whatever its deadline mode.
+    pub fn get_bounty_age(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        let bounty: Bounty = env
+            .storage()
+            .persistent()
+            .get(&bounty_key(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        Ok(env.ledger().timestamp().saturating_sub(bounty.created_at))
+    }
+
+    // Fee rate the bounty was actually charged, in basis points, so audits
+    // can reconstruct the fee even after rates or caps change.
+    pub fn get_bounty_fee_rate(env: Env, bounty_id: u64) -> Result<u32, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        Ok(bounty.fee_bps_at_creation)
+    }
+
+    // The bounty's (rank, share) pairs with shares in basis points, ordered
+    // by rank. Distributions are always stored as percents.
+    pub fn get_bounty_distribution_bps(env: Env, bounty_id: u64) -> Result<Vec<(u32, u32)>, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+        let mut distribution = Vec::new(&env);
+        for (rank, percent) in bounty.distribution.iter() {
+            distribution.push_back((rank, percent * 100));
+        }
+        Ok(distribution)
+    }
+
+    // Stores the Active -> Judging transition once the submission deadline
+    // has passed. Callable by anyone; returns the (possibly updated) status.
+    pub fn advance_status(env: Env, bounty_id: u64) -> Result<Status, Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+        let status = Self::effective_status(&env, &bounty);
+        if status != bounty.status {
+            Self::set_bounty_status(&env, bounty_id, &mut bounty, status.clone());
+            storage.set(&bounty_key(bounty_id), &bounty);
+            Events::emit_bounty_status_updated(&env, bounty_id, status.clone());
+            if status == Status::Judging {
+                Events::emit_bounty_judging_started(&env, bounty_id, bounty.owner);
+            }
+        }
+        Ok(status)
+    }
+
+    // Recovery lever for bounties left in an inconsistent state: forces the
+    // stored status without moving any funds. A settled (Completed/Closed)
+    // bounty can never be moved back to Active or Judging, since that would
+    // re-enable payouts and refunds of escrow that is already gone, and an
+    // unsettled one can't be forced settled, which would strand its escrow.
+    // Judging can't be forced while submissions are still open.
+    pub fn admin_set_bounty_status(env: Env, bounty_id: u64, status: Status) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+        if Self::is_unsettled(&bounty.status) != Self::is_unsettled(&status) {
+            return Err(Error::InactiveBounty);
+        }
+        // Funding moves only with contributions and sponsor refunds
+        if bounty.status == Status::Funding || status == Status::Funding {
+            return Err(Error::InactiveBounty);
+        }
+        // A stored Judging status would let winners be selected early
+        if status == Status::Judging
+            && Self::deadline_clock(&env, &bounty.deadline_mode) <= bounty.submission_deadline
+        {
+            return Err(Error::TooEarly);
+        }
+
+        // Written directly so no settlement hooks fire; only a swap between
+        // the two settled states moves the lifecycle counters
+        let previous = bounty.status.clone();
+        match (&previous, &status) {
+            (Status::Completed, Status::Closed) => Self::update_counters(&env, |c| {
+                c.bounties_completed = c.bounties_completed.saturating_sub(1);
+                c.bounties_closed += 1;
+            }),
+            (Status::Closed, Status::Completed) => Self::update_counters(&env, |c| {
+                c.bounties_closed = c.bounties_closed.saturating_sub(1);
+                c.bounties_completed += 1;
+            }),
+            _ => {}
+        }
+        bounty.status = status.clone();
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Events::emit_bounty_status_forced(&env, bounty_id, previous, status);
+        Ok(())
+    }
+
+    // Sets the wait between requesting and executing an emergency action.
+    // Requests already pending keep the time they were scheduled for.
+    pub fn set_emergency_delay(env: Env, delay: u64) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if delay < MIN_EMERGENCY_DELAY {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().persistent().set(&emergency_delay_key(), &delay);
+        Events::emit_emergency_delay_updated(&env, delay);
+        Ok(())
+    }
+
+    pub fn get_emergency_delay(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&emergency_delay_key())
+            .unwrap_or(DEFAULT_EMERGENCY_DELAY)
+    }
+
+    // Schedules `action` to become executable after the emergency delay, so
+    // affected owners see it on-chain before any funds move. Only one action
+    // can be pending; a new request replaces it.
+    pub fn request_emergency_action(env: Env, action: EmergencyAction) -> Result<u64, Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        Self::check_emergency_target(&env, &action)?;
+
+        let executable_at = env
+            .ledger()
+            .timestamp()
+            .checked_add(Self::get_emergency_delay(env.clone()))
+            .ok_or(Error::ArithmeticError)?;
+        env.storage()
+            .persistent()
+            .set(&pending_emergency_key(), &(action.clone(), executable_at));
+        Events::emit_emergency_action_requested(&env, action, executable_at);
+        Ok(executable_at)
+    }
+
+    pub fn execute_emergency_action(env: Env) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let storage = env.storage().persistent();
+        let pending: Option<(EmergencyAction, u64)> = storage.get(&pending_emergency_key());
+        if pending.is_none() {
+            return Err(Error::NothingToClaim);
+        }
+
+        let (action, executable_at) = pending.unwrap();
+        if env.ledger().timestamp() < executable_at {
+            return Err(Error::TooEarly);
+        }
+
+        // The target may have settled while the request was pending
+        Self::check_emergency_target(&env, &action)?;
+        storage.remove(&pending_emergency_key());
+
+        match action.clone() {
+            EmergencyAction::RefundBounty(bounty_id) => {
+                let mut bounty: Bounty = storage.get(&bounty_key(bounty_id)).unwrap();
+                Self::refund_rewards(&env, &bounty, &bounty.owner);
+                Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Closed);
+                storage.set(&bounty_key(bounty_id), &bounty);
+                Events::emit_bounty_closed(&env, bounty_id);
+            }
+            EmergencyAction::RefundProject(project_id) => {
+                let mut project: Project = storage.get(&project_key(project_id)).unwrap();
+                let refund_amount = project.remaining_escrow;
+                if refund_amount > 0 {
+                    let token_client = get_token_client(&env, project.token.clone());
+                    token_client.transfer(&env.current_contract_address(), &project.owner, &refund_amount);
+                }
+                Self::set_project_status(&env, &mut project, ProjectStatus::Cancelled);
+                project.remaining_escrow = 0;
+                storage.set(&project_key(project_id), &project);
+                Events::emit_project_cancelled(&env, project_id, refund_amount);
+            }
+            EmergencyAction::PayInsurance(token, recipient, amount) => {
+                let key = insurance_pool_key(token.clone());
+                let pool: i128 = storage.get(&key).unwrap_or(0);
+                storage.set(&key, &(pool - amount));
+                let token_client = get_token_client(&env, token);
+                token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+            }
+        }
+
+        Events::emit_emergency_action_executed(&env, action);
+        Ok(())
+    }
+
+    pub fn cancel_emergency_action(env: Env) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let storage = env.storage().persistent();
+        let pending: Option<(EmergencyAction, u64)> = storage.get(&pending_emergency_key());
+        if pending.is_none() {
+            return Err(Error::NothingToClaim);
+        }
+
+        storage.remove(&pending_emergency_key());
+        Events::emit_emergency_action_cancelled(&env, pending.unwrap().0);
+        Ok(())
+    }
+
+    // Sets the share of each bounty fee, in basis points, kept in the
+    // contract as a reserve for emergency payouts instead of being forwarded.
+    pub fn set_insurance_bps(env: Env, bps: u32) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        if bps > MAX_INSURANCE_BPS {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().persistent().set(&insurance_bps_key(), &bps);
+        Events::emit_insurance_bps_updated(&env, bps);
+        Ok(())
+    }
+
+    pub fn get_insurance_bps(env: Env) -> u32 {
+        env.storage().persistent().get(&insurance_bps_key()).unwrap_or(0)
+    }
+
+    // Adjusted amount of `token` held in the insurance pool.
+    pub fn get_insurance_pool(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&insurance_pool_key(token))
+            .unwrap_or(0)
+    }
+
+    // Token used by `create_bounty_default`, for deployments dedicated to a
+    // single token. Passing `None` clears it.
+    pub fn set_default_token(env: Env, token: Option<Address>) -> Result<(), Error> {
+        let admin = Self::get_admin(&env);
+        admin.require_auth();
+
+        let storage = env.storage().persistent();
+        match &token {
+            Some(token) => storage.set(&default_token_key(), token),
+            None => storage.remove(&default_token_key()),
+        }
+
+        Events::emit_default_token_updated(&env, token);
+        Ok(())
+    }
+
+    pub fn get_default_token(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&default_token_key())
+    }
+
+    pub fn get_pending_emergency_action(env: Env) -> Option<(EmergencyAction, u64)> {
+        env.storage().persistent().get(&pending_emergency_key())
+    }
+
+    // Only entities that still hold escrow can be refunded, and insurance
+    // payouts must be covered by the pool
+    fn check_emergency_target(env: &Env, action: &EmergencyAction) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        match action {
+            EmergencyAction::RefundBounty(bounty_id) => {
+                let bounty: Option<Bounty> = storage.get(&bounty_key(*bounty_id));
+                if bounty.is_none() {
+                    return Err(Error::BountyNotFound);
+                }
+                if !Self::is_unsettled(&bounty.unwrap().status) {
+                    return Err(Error::InactiveBounty);
+                }
+            }
+            EmergencyAction::RefundProject(project_id) => {
+                let project: Option<Project> = storage.get(&project_key(*project_id));
+                if project.is_none() {
+                    return Err(Error::ProjectNotFound);
+                }
+                if project.unwrap().status != ProjectStatus::Active {
+                    return Err(Error::ProjectNotActive);
+                }
+            }
+            EmergencyAction::PayInsurance(token, _, amount) => {
+                if *amount <= 0 {
+                    return Err(Error::InvalidAmount);
+                }
+                let pool: i128 = storage.get(&insurance_pool_key(token.clone())).unwrap_or(0);
+                if *amount > pool {
+                    return Err(Error::InsufficientEscrow);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // ========================================
+    // BOUNTY CREATION & MANAGEMENT
+    // ========================================
+
+    pub fn create_bounty(
+        env: Env,
+        owner: Address,
+        token: Address,
+        reward: i128,
+        distribution: Vec<(u32, u32)>,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        fee_payer: Option<Address>,
+        idempotency_key: Option<BytesN<32>>,
+        options: Option<BountyOptions>,
+    ) -> Result<u64, Error> {
+        Self::create_bounty_internal(
+            &env,
+            owner,
+            Vec::from_array(&env, [(token, reward)]),
+            distribution,
+            submission_deadline,
+            judging_deadline,
+            title,
+            fee_payer,
+            idempotency_key,
+            options,
+            None,
+            None,
+        )
+    }
+
+    // Same as `create_bounty` but paid in the admin-set default token.
+    // Fails with `InvalidToken` when no default token is set.
+    pub fn create_bounty_default(
+        env: Env,
+        owner: Address,
+        reward: i128,
+        distribution: Vec<(u32, u32)>,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        fee_payer: Option<Address>,
+        idempotency_key: Option<BytesN<32>>,
+        options: Option<BountyOptions>,
+    ) -> Result<u64, Error> {
+        let token = Self::get_default_token(env.clone()).ok_or(Error::InvalidToken)?;
+        Self::create_bounty(
+            env,
+            owner,
+            token,
+            reward,
+            distribution,
+            submission_deadline,
+            judging_deadline,
+            title,
+            fee_payer,
+            idempotency_key,
+            options,
+        )
+    }
+
+    // Creates a bounty whose reward pool spans several tokens. The first entry
+    // is the primary token (`token`/`reward` on the bounty); the rest are
+    // escrowed alongside it, each charged its own fee, and split with the same
+    // distribution.
+    pub fn create_multi_token_bounty(
+        env: Env,
+        owner: Address,
+        rewards: Vec<(Address, i128)>,
+        distribution: Vec<(u32, u32)>,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        fee_payer: Option<Address>,
+        idempotency_key: Option<BytesN<32>>,
+        options: Option<BountyOptions>,
+    ) -> Result<u64, Error> {
+        Self::create_bounty_internal(
+            &env,
+            owner,
+            rewards,
+            distribution,
+            submission_deadline,
+            judging_deadline,
+            title,
+            fee_payer,
+            idempotency_key,
+            options,
+            None,
+            None,
+        )
+    }
+
+    // `create_bounty` with the reward split evenly across `num_winners` ranks
+    // (see `even_distribution`). Zero or more than 100 winners can't be split.
+    pub fn create_bounty_even(
+        env: Env,
+        owner: Address,
+        token: Address,
+        reward: i128,
+        num_winners: u32,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        fee_payer: Option<Address>,
+        idempotency_key: Option<BytesN<32>>,
+        options: Option<BountyOptions>,
+    ) -> Result<u64, Error> {
+        let distribution = even_distribution(&env, num_winners);
+        if distribution.is_empty() {
+            return Err(Error::DistributionMustSumTo100);
+        }
+        Self::create_bounty_internal(
+            &env,
+            owner,
+            Vec::from_array(&env, [(token, reward)]),
+            distribution,
+            submission_deadline,
+            judging_deadline,
+            title,
+            fee_payer,
+            idempotency_key,
+            options,
+            None,
+            None,
+        )
+    }
+
+    // Creates a bounty in Funding status whose reward of `goal` (user-friendly
+    // units) is raised from sponsors through `contribute`. The owner pays the
+    // platform fee on the goal up front; it is not returned if funding fails.
+    pub fn create_crowdfunded_bounty(
+        env: Env,
+        owner: Address,
+        token: Address,
+        goal: i128,
+        distribution: Vec<(u32, u32)>,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        funding_deadline: u64,
+        options: Option<BountyOptions>,
+    ) -> Result<u64, Error> {
+        Self::create_bounty_internal(
+            &env,
+            owner,
+            Vec::from_array(&env, [(token, goal)]),
+            distribution,
+            submission_deadline,
+            judging_deadline,
+            title,
+            None,
+            None,
+            options,
+            Some(funding_deadline),
+            None,
+        )
+    }
+
+    // Escrows up to `amount` (user-friendly units) from `sponsor` toward the
+    // goal of a Funding bounty; anything past the goal is not taken. The
+    // bounty turns Active once the goal is met. Returns the amount taken.
+    pub fn contribute(
+        env: Env,
+        sponsor: Address,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        sponsor.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+        if bounty.status != Status::Funding {
+            return Err(Error::InactiveBounty);
+        }
+
+        let mut crowdfund: Crowdfund = storage.get(&crowdfund_key(bounty_id)).unwrap();
+        if Self::deadline_clock(&env, &bounty.deadline_mode) > crowdfund.funding_deadline {
+            return Err(Error::DeadlinePassed);
+        }
+
+        // Sponsors are refunded in one call, so their number is bounded
+        let previous = crowdfund.contributions.get(sponsor.clone()).unwrap_or(0);
+        if previous == 0 && crowdfund.contributions.len() >= MAX_BATCH_SIZE {
+            return Err(Error::TooManyItems);
+        }
+
+        let decimals = Self::token_decimals(&env, &bounty.token);
+        let adjusted_amount =
+            checked_adjust_for_decimals(amount, decimals).ok_or(Error::ArithmeticError)?;
+        let accepted = adjusted_amount.min(crowdfund.goal - bounty.reward);
+
+        let token_client = get_token_client(&env, bounty.token.clone());
+        token_client.transfer(&sponsor, &env.current_contract_address(), &accepted);
+
+        crowdfund.contributions.set(sponsor.clone(), previous + accepted);
+        bounty.reward += accepted;
+        let funded = bounty.reward == crowdfund.goal;
+        if funded {
+            bounty.status = Status::Active;
+            Self::record_funding(&env, &bounty.token, bounty.reward);
+        }
+
+        storage.set(&crowdfund_key(bounty_id), &crowdfund);
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Events::emit_crowdfund_contributed(&env, bounty_id, sponsor, accepted);
+        if funded {
+            Events::emit_bounty_status_updated(&env, bounty_id, Status::Active);
+        }
+
+        Ok(accepted)
+    }
+
+    // Closes a Funding bounty that missed its goal by the funding deadline
+    // and returns every sponsor's contribution. Callable by anyone.
+    pub fn refund_contributors(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+        if bounty.status != Status::Funding {
+            return Err(Error::InactiveBounty);
+        }
+
+        let crowdfund: Crowdfund = storage.get(&crowdfund_key(bounty_id)).unwrap();
+        if Self::deadline_clock(&env, &bounty.deadline_mode) <= crowdfund.funding_deadline {
+            return Err(Error::TooEarly);
+        }
+
+        let token_client = get_token_client(&env, bounty.token.clone());
+        for (sponsor, amount) in crowdfund.contributions.iter() {
+            token_client.transfer(&env.current_contract_address(), &sponsor, &amount);
+        }
+
+        // Funding bounties count as open without holding settled escrow, so
+        // the open counts are released here rather than by the status change
+        let refunded = bounty.reward;
+        bounty.reward = 0;
+        bounty.status = Status::Closed;
+        Self::bounty_settled(&env, &bounty.owner);
+        Self::update_counters(&env, |c| c.bounties_closed += 1);
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Events::emit_contributors_refunded(&env, bounty_id, refunded);
+        Events::emit_bounty_closed(&env, bounty_id);
+
+        Ok(())
+    }
+
+    pub fn get_crowdfund(env: Env, bounty_id: u64) -> Result<Crowdfund, Error> {
+        let crowdfund: Option<Crowdfund> = env.storage().persistent().get(&crowdfund_key(bounty_id));
+        crowdfund.ok_or(Error::BountyNotFound)
+    }
+
+    // Creates the first of `occurrences` bounties repeating every `period`
+    // (in the bounty's deadline unit). Each time an occurrence completes, the
+    // next one is created with its deadlines moved by `period`, and its
+    // reward and fee are pulled from the owner through a token allowance
+    // granted to this contract. Returns the series id.
+    pub fn create_recurring_bounty(
+        env: Env,
+        owner: Address,
+        token: Address,
+        reward: i128,
+        distribution: Vec<(u32, u32)>,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        period: u64,
+        occurrences: u32,
+        options: Option<BountyOptions>,
+    ) -> Result<u64, Error> {
+        if period == 0 || occurrences == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if let Some(options) = &options
+            && options.reward_kind != RewardKind::Fungible
+        {
+            return Err(Error::InvalidReward);
+        }
+
+        let first_id = Self::create_bounty_internal(
+            &env,
+            owner.clone(),
+            Vec::from_array(&env, [(token.clone(), reward)]),
+            distribution,
+            submission_deadline,
+            judging_deadline,
+            title,
+            None,
+            None,
+            options,
+            None,
+            None,
+        )?;
+
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let series_id: u64 = storage.get(&next_series_id_key()).unwrap_or(base + 1);
+        storage.set(&next_series_id_key(), &(series_id + 1));
+
+        let series = Series {
+            owner,
+            token,
+            reward,
+            period,
+            remaining: occurrences - 1,
+            bounties: Vec::from_array(&env, [first_id]),
+        };
+        storage.set(&series_key(series_id), &series);
+        storage.set(&bounty_series_key(first_id), &series_id);
+        Events::emit_series_created(&env, series_id, first_id);
+
+        Ok(series_id)
+    }
+
+    // Creates a bounty linked under `parent_id`, which must exist and belong to
+    // the same owner. The child is otherwise an ordinary bounty.
+    pub fn create_sub_bounty(
+        env: Env,
+        owner: Address,
+        parent_id: u64,
+        token: Address,
+        reward: i128,
+        distribution: Vec<(u32, u32)>,
+        submission_deadline: u64,
+        judging_deadline: u64,
+        title: String,
+        options: Option<BountyOptions>,
+    ) -> Result<u64, Error> {
+        Self::create_bounty_internal(
+            &env,
+            owner,
+            Vec::from_array(&env, [(token, reward)]),
+            distribution,
+            submission_deadline,
+            judging_deadline,
+            title,
+            None,
+            None,
+            options,
+            None,
+            Some(parent_id),
+        )
+    }
+
+    // Ids of the bounties created under `parent_id`, oldest first.
+    pub fn get_sub_bounties(env: Env, parent_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&sub_bounties_key(parent_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // Bounties of a recurring series, oldest first.
+    pub fn get_series_bounties(env: Env, series_id: u64) -> Vec<u64> {
+        let series: Option<Series> = env.storage().persistent().get(&series_key(series_id));
+        match series {
+            Some(series) => series.bounties,
+            None => Vec::new(&env),
+        }
+    }
+
+    pub fn get_bounty_series(env: Env, bounty_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&bounty_series_key(bounty_id))
+    }
+
+    pub fn update_bounty(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+        new_title: Option<String>,
+        new_distribution: Vec<(u32, u32)>,
+        new_submission_deadline: Option<u64>,
+        new_description: Option<String>,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+
+        // Only the bounty owner can update the bounty
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+
+        // Check if the bounty is still active
+        if bounty.status != Status::Active {
+            return Err(Error::InactiveBounty);
+        }
+
+        let now = Self::deadline_clock(&env, &bounty.deadline_mode);
+
+        // Update distribution if provided. Applicants submitted against the
+        // split in force, so it is frozen once the submission window closes.
+        if !new_distribution.is_empty() {
+            if now > bounty.submission_deadline {
+                return Err(Error::BountyDeadlinePassed);
+            }
+            if new_distribution.len() > MAX_DISTRIBUTION_RANKS {
+                return Err(Error::TooManyItems);
+            }
+            if !validate_distribution_sum(&new_distribution) {
+                return Err(Error::DistributionMustSumTo100);
+            }
+            let mut distribution_map = Map::new(&env);
+            for (rank, percent) in new_distribution.iter() {
+                distribution_map.set(rank, percent);
+            }
+            bounty.distribution = distribution_map;
+        }
+
+        // Update submission deadline if provided. Once it has passed the
+        // bounty is judging, even before anyone calls `advance_status`, so it
+        // can't be reopened.
+        if let Some(submission_deadline) = new_submission_deadline {
+            if now > bounty.submission_deadline {
+                return Err(Error::BountyDeadlinePassed);
+            }
+            validate_deadlines(
+                submission_deadline,
+                bounty.judging_deadline,
+                now,
+                Self::get_min_judging_gap(env.clone()),
+            )?;
+
+            // Extensions are limited in number and total length so applicants
+            // are not kept waiting indefinitely; moving it earlier is free
+            if submission_deadline > bounty.submission_deadline {
+                let key = deadline_extensions_key(bounty_id);
+                let (count, total): (u32, u64) = storage.get(&key).unwrap_or((0, 0));
+                let total = total + (submission_deadline - bounty.submission_deadline);
+                let max_total = match bounty.deadline_mode {
+                    DeadlineMode::Timestamp => MAX_DEADLINE_EXTENSION_DAYS * 86_400,
+                    DeadlineMode::LedgerSeq => MAX_DEADLINE_EXTENSION_DAYS * DAY_IN_LEDGERS as u64,
+                };
+                if count >= MAX_DEADLINE_EXTENSIONS || total > max_total {
+                    return Err(Error::TooManyExtensions);
+                }
+                storage.set(&key, &(count + 1, total));
+            }
+            bounty.submission_deadline = submission_deadline;
+        }
+
+        // Update title if provided
+        if let Some(title) = &new_title {
+            bounty.title = title.clone();
+        }
+        if let Some(description) = &new_description {
+            if description.len() > MAX_DESCRIPTION_LENGTH {
+                return Err(Error::TooManyItems);
+            }
+            bounty.description = description.clone();
+        }
+
+        let mut updated_fields: Vec<Symbol> = Vec::new(&env);
+        if new_title.is_some() {
+            updated_fields.push_back(Symbol::new(&env, "title"));
+        }
+        if !new_distribution.is_empty() {
+            updated_fields.push_back(Symbol::new(&env, "distribution"));
+        }
+        if let Some(_submission_deadline) = new_submission_deadline {
+            updated_fields.push_back(Symbol::new(&env, "submission_deadline"));
+        }
+        if new_description.is_some() {
+            updated_fields.push_back(Symbol::new(&env, "description"));
+        }
+
+        // Save the updated bounty
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Events::emit_bounty_updated(&env, bounty_id, updated_fields);
+
+        Ok(())
+    }
+
+    pub fn delete_bounty(env: Env, owner: Address, bounty_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let bounty = bounty.unwrap();
+
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+
+        // Sponsor funds are only returned through `refund_contributors`
+        if bounty.status == Status::Funding {
+            return Err(Error::InactiveBounty);
+        }
+
+        // Check if there are any submissions
+        if !bounty.submissions.is_empty() {
+            return Err(Error::BountyHasSubmissions);
+        }
+
+        // Only an Active bounty still holds escrow; closing and completion
+        // already paid it out (already adjusted)
+        if Self::is_unsettled(&bounty.status) {
+            Self::refund_rewards(&env, &bounty, &owner);
+        }
+
+        // Remove bounty; deleting an occurrence ends its series
+        if Self::is_unsettled(&bounty.status) {
+            Self::bounty_settled(&env, &owner);
+            Self::advance_series(&env, bounty_id, &bounty, &Status::Closed);
+        }
+        storage.remove(&bounty_key(bounty_id));
+        storage.remove(&allowlist_key(bounty_id));
+        storage.remove(&applicant_count_key(bounty_id));
+        storage.remove(&crowdfund_key(bounty_id));
+        storage.remove(&deadline_extensions_key(bounty_id));
+        storage.remove(&bounty_series_key(bounty_id));
+        storage.remove(&sub_bounties_key(bounty_id));
+        storage.remove(&bounty_paused_key(bounty_id));
+        storage.remove(&fee_refundable_key(bounty_id));
+        if let Some(parent_id) = bounty.parent_id {
+            let mut siblings: Vec<u64> =
+                storage.get(&sub_bounties_key(parent_id)).unwrap_or(Vec::new(&env));
+            if let Some(index) = siblings.first_index_of(bounty_id) {
+                siblings.remove(index);
+                storage.set(&sub_bounties_key(parent_id), &siblings);
+            }
+        }
+        Self::update_owner_count(&env, &owner, false);
+        let mut featured_ids: Vec<u64> =
+            storage.get(&featured_bounties_key()).unwrap_or(Vec::new(&env));
+        if let Some(index) = featured_ids.first_index_of(bounty_id) {
+            featured_ids.remove(index);
+            storage.set(&featured_bounties_key(), &featured_ids);
+        }
+        Self::update_counters(&env, |c| c.bounties_deleted += 1);
+        Events::emit_bounty_deleted(&env, bounty_id);
+
+        Ok(())
+    } 
+
+    // Adds `applicant` to the bounty's invite list. Once the list has any
+    // entry, only listed addresses may apply.
+    pub fn add_allowed_applicant(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+        applicant: Address,
+    ) -> Result<(), Error> {
+        Self::set_allowed_applicant(env, owner, bounty_id, applicant, true)
+    }
+
+    // Removing the last entry makes the bounty open to everyone again.
+    pub fn remove_allowed_applicant(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+        applicant: Address,
+    ) -> Result<(), Error> {
+        Self::set_allowed_applicant(env, owner, bounty_id, applicant, false)
+    }
+
+    fn set_allowed_applicant(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+        applicant: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        if bounty.unwrap().owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+
+        let key = allowlist_key(bounty_id);
+        let mut list: Vec<Address> = storage.get(&key).unwrap_or(Vec::new(&env));
+        match (allowed, list.first_index_of(&applicant)) {
+            (true, None) => {
+                if list.len() >= MAX_BATCH_SIZE {
+                    return Err(Error::TooManyItems);
+                }
+                list.push_back(applicant);
+            }
+            (false, Some(index)) => {
+                list.remove(index);
+            }
+            _ => {}
+        }
+        if list.is_empty() {
+            storage.remove(&key);
+        } else {
+            storage.set(&key, &list);
+        }
+        Ok(())
+    }
+
+    // Empty when the bounty is open to everyone.
+    pub fn get_allowed_applicants(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&allowlist_key(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // Temporarily stops new submissions and submission updates on an open
+    // bounty. The deadlines keep running while it is paused.
+    pub fn pause_bounty(env: Env, owner: Address, bounty_id: u64) -> Result<(), Error> {
+        Self::set_bounty_paused(&env, owner, bounty_id, true)
+    }
+
+    pub fn resume_bounty(env: Env, owner: Address, bounty_id: u64) -> Result<(), Error> {
+        Self::set_bounty_paused(&env, owner, bounty_id, false)
+    }
+
+    pub fn is_bounty_paused(env: Env, bounty_id: u64) -> bool {
+        env.storage().persistent().has(&bounty_paused_key(bounty_id))
+    }
+
+    fn set_bounty_paused(
+        env: &Env,
+        owner: Address,
+        bounty_id: u64,
+        paused: bool,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+        let bounty: Bounty = storage.get(&bounty_key(bounty_id)).ok_or(Error::BountyNotFound)?;
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+        if paused {
+            if Self::effective_status(env, &bounty) != Status::Active {
+                return Err(Error::InactiveBounty);
+            }
+            storage.set(&bounty_paused_key(bounty_id), &true);
+        } else {
+            storage.remove(&bounty_paused_key(bounty_id));
+        }
+
+        Events::emit_bounty_paused(env, bounty_id, paused);
+        Ok(())
+    }
+
+    pub fn close_bounty(env: Env, owner: Address, bounty_id: u64) -> Result<(), Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+
+        // A settled bounty has nothing left to refund
+        if !Self::is_unsettled(&bounty.status) {
+            return Err(Error::InactiveBounty);
+        }
+
+        // Check if there are any submissions
+        if !bounty.submissions.is_empty() {
+            return Err(Error::BountyHasSubmissions);
+        }
+
+        // Return funds to owner (already adjusted)
+        Self::refund_rewards(&env, &bounty, &owner);
+
+        // Closing right after creation, before anyone applied, makes the
+        // platform fee refundable; whoever holds it pays it back through
+        // `refund_fee`
+        let window = Self::get_fee_refund_window(env.clone());
+        let charged = bounty.fee > 0 || bounty.extra_fees.iter().any(|(_, fee, _)| fee > 0);
+        if window > 0
+            && charged
+            && bounty.applicants.is_empty()
+            && env.ledger().timestamp() <= bounty.created_at + window
+        {
+            storage.set(&fee_refundable_key(bounty_id), &true);
+        }
+
+        // Update bounty status to Closed
+        Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Closed);
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Events::emit_bounty_closed(&env, bounty_id);
+
+        Ok(())
+    }
+
+    // Returns the platform fee of a bounty closed inside the fee refund
+    // window to its owner. The fee recipient (or the fee account) signs, since
+    // the forwarded part comes out of its balance.
+    pub fn refund_fee(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let storage = env.storage().persistent();
+        let bounty: Bounty = storage
+            .get(&bounty_key(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !storage.has(&fee_refundable_key(bounty_id)) {
+            return Err(Error::NothingToClaim);
+        }
+        storage.remove(&fee_refundable_key(bounty_id));
+
+        let holder = bounty
+            .fee_recipient
+            .clone()
+            .unwrap_or_else(|| Self::get_fee_account(&env));
+        holder.require_auth();
+
+        // The event reports the primary-token amount; extra-token fees come
+        // back alongside it
+        let refunded =
+            Self::refund_token_fee(&env, &bounty, &holder, &bounty.token, bounty.fee, bounty.insured_fee);
+        for (extra_token, extra_fee, extra_insured) in bounty.extra_fees.iter() {
+            Self::refund_token_fee(&env, &bounty, &holder, &extra_token, extra_fee, extra_insured);
+        }
+        Events::emit_fee_refunded(&env, bounty_id, bounty.owner, refunded);
+        Ok(())
+    }
+
+    // Pays one token's bounty fee back to the owner and returns the amount.
+    fn refund_token_fee(
+        env: &Env,
+        bounty: &Bounty,
+        holder: &Address,
+        token: &Address,
+        fee: i128,
+        insured: i128,
+    ) -> i128 {
+        if fee <= 0 {
+            return 0;
+        }
+        let storage = env.storage().persistent();
+        let token_client = get_token_client(env, token.clone());
+        let forwarded = fee - insured;
+        if bounty.fee_recipient.is_some() {
+            token_client.transfer(holder, &bounty.owner, &forwarded);
+        } else {
+            // The fee may still be held in the contract if the fee account
+            // could not receive it
+            let accrued_key = accrued_fees_key(token.clone());
+            let accrued: i128 = storage.get(&accrued_key).unwrap_or(0);
+            if accrued >= forwarded {
+                storage.set(&accrued_key, &(accrued - forwarded));
+                token_client.transfer(&env.current_contract_address(), &bounty.owner, &forwarded);
+            } else {
+                token_client.transfer(holder, &bounty.owner, &forwarded);
+            }
+        }
+        // The insured share comes back only as far as the pool still holds it
+        let pool_key = insurance_pool_key(token.clone());
+        let pool: i128 = storage.get(&pool_key).unwrap_or(0);
+        let from_pool = insured.min(pool);
+        if from_pool > 0 {
+            storage.set(&pool_key, &(pool - from_pool));
+            token_client.transfer(&env.current_contract_address(), &bounty.owner, &from_pool);
+        }
+        // Only the forwarded part was counted as collected
+        Self::update_stats(env, |stats| {
+            let collected = stats.fees_collected.get(token.clone()).unwrap_or(0);
+            stats.fees_collected.set(token.clone(), collected - forwarded);
+        });
+        forwarded + from_pool
+    }
+
+    // Cancels a bounty that already has submissions: every submitter receives
+    // `per_applicant` (user-friendly units) from the reward and the rest goes
+    // back to the owner.
+    pub fn close_with_consolation(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+        per_applicant: i128,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+
+        if !Self::is_unsettled(&bounty.status) {
+            return Err(Error::InactiveBounty);
+        }
+
+        if per_applicant <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let decimals = Self::token_decimals(&env, &bounty.token);
+        let consolation =
+            checked_adjust_for_decimals(per_applicant, decimals).ok_or(Error::ArithmeticError)?;
+        let total = consolation
+            .checked_mul(bounty.applicants.len() as i128)
+            .ok_or(Error::ArithmeticError)?;
+        if total > bounty.reward {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_client = get_token_client(&env, bounty.token.clone());
+        let mut payouts = Self::get_payouts(&env, bounty_id);
+        for applicant in bounty.applicants.iter() {
+            token_client.transfer(&env.current_contract_address(), &applicant, &consolation);
+            payouts.set(applicant.clone(), payouts.get(applicant).unwrap_or(0) + consolation);
+        }
+        Self::record_paid(&env, &bounty.token, total);
+        storage.set(&bounty_payouts_key(bounty_id), &payouts);
+
+        let remainder = bounty.reward - total;
+        if remainder > 0 {
+            token_client.transfer(&env.current_contract_address(), &owner, &remainder);
+        }
+        Self::refund_extra_rewards(&env, &bounty, &owner);
+
+        Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Closed);
+        bounty.distribution_completed = true;
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Events::emit_bounty_closed(&env, bounty_id);
+
+        Ok(())
+    }
+
+    pub fn apply_to_bounty(
+        env: Env,
+        applicant: Address,
+        bounty_id: u64,
+        submission_link: String,
+    ) -> Result<(), Error> {
+        applicant.require_auth();
+        Self::apply_internal(env, applicant, bounty_id, submission_link, None)
+    }
+
+    // Like `apply_to_bounty`, with a short proposal text stored next to the link.
+    pub fn apply_with_proposal(
+        env: Env,
+        applicant: Address,
+        bounty_id: u64,
+        submission_link: String,
+        proposal: String,
+    ) -> Result<(), Error> {
+        applicant.require_auth();
+        Self::apply_internal(env, applicant, bounty_id, submission_link, Some(proposal))
+    }
+
+    // Applies to each (bounty id, link) entry in one call. With `skip_invalid`
+    // entries that fail are passed over instead of reverting the whole batch.
+    // Returns the number of bounties applied to.
+    pub fn apply_to_bounties_batch(
+        env: Env,
+        applicant: Address,
+        entries: Vec<(u64, String)>,
+        skip_invalid: bool,
+    ) -> Result<u32, Error> {
+        if entries.len() > MAX_BATCH_SIZE {
+            return Err(Error::TooManyItems);
+        }
+        applicant.require_auth();
+
+        let mut applied = 0;
+        for (bounty_id, submission_link) in entries.iter() {
+            let result =
+                Self::apply_internal(env.clone(), applicant.clone(), bounty_id, submission_link, None);
+            match result {
+                Ok(()) => applied += 1,
+                Err(_) if skip_invalid => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(applied)
+    }
+
+    // Callers authorize `applicant` first.
+    fn apply_internal(
+        env: Env,
+        applicant: Address,
+        bounty_id: u64,
+        submission_link: String,
+        proposal: Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(proposal) = &proposal
+            && proposal.len() > MAX_PROPOSAL_LENGTH
+        {
+            return Err(Error::TooManyItems);
+        }
+
+        let storage = env.storage().persistent();
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+        // A Judging bounty only missed the deadline; settled ones are inactive
+        match Self::effective_status(&env, &bounty) {
+            Status::Active => {}
+            Status::Judging => return Err(Error::BountyDeadlinePassed),
+            _ => return Err(Error::InactiveBounty),
+        }
+        if storage.has(&bounty_paused_key(bounty_id)) {
+            return Err(Error::BountyPaused);
+        }
+        if applicant == bounty.owner && Self::get_block_owner_apply(env.clone()) {
+            return Err(Error::OwnerCannotApply);
+        }
+        let allowed: Vec<Address> = storage.get(&allowlist_key(bounty_id)).unwrap_or(Vec::new(&env));
+        if !allowed.is_empty() && !allowed.contains(&applicant) {
+            return Err(Error::ApplicantNotAllowed);
+        }
+        Self::enforce_submission_cooldown(&env, bounty_id, &applicant)?;
+        // Register applicant if new
+        if !bounty.submissions.contains_key(applicant.clone()) {
+            bounty.applicants.push_back(applicant.clone());
+            storage.set(&applicant_count_key(bounty_id), &bounty.applicants.len());
+        }
+        // Set/update submission
+        bounty
+            .submissions
+            .set(applicant.clone(), submission_link.clone());
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Self::record_revision(&env, bounty_id, &applicant, submission_link);
+        match proposal {
+            Some(proposal) if !proposal.is_empty() => {
+                storage.set(&proposal_key(bounty_id, applicant.clone()), &proposal)
+            }
+            _ => storage.remove(&proposal_key(bounty_id, applicant.clone())),
+        }
+        Events::emit_submission_added(&env, bounty_id, applicant);
+
+        Ok(())
+    }
+
+    pub fn update_submission(
+        env: Env,
+        applicant: Address,
+        bounty_id: u64,
+        new_submission_link: String,
+    ) -> Result<(), Error> {
+        applicant.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+        let mut bounty = bounty.unwrap();
+
+        // Past the submission deadline (Judging) vs. already settled
+        match Self::effective_status(&env, &bounty) {
+            Status::Active => {}
+            Status::Judging => return Err(Error::BountyDeadlinePassed),
+            _ => return Err(Error::InactiveBounty),
+        }
+        if storage.has(&bounty_paused_key(bounty_id)) {
+            return Err(Error::BountyPaused);
+        }
+
+        // Check if the applicant has an existing submission
+        if !bounty.submissions.contains_key(applicant.clone()) {
+            return Err(Error::SubmissionNotFound);
+        }
+        Self::enforce_submission_cooldown(&env, bounty_id, &applicant)?;
+
+        // Update the submission
+        bounty
+            .submissions
+            .set(applicant.clone(), new_submission_link.clone());
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Self::record_revision(&env, bounty_id, &applicant, new_submission_link);
+
+        // Emit an event for the update
+        Events::emit_submission_updated(&env, bounty_id, applicant);
+
+        Ok(())
+    }
+
+    // Takes the applicant's submission back while the bounty is still open,
+    // removing them from the applicant list.
+    pub fn withdraw_submission(env: Env, applicant: Address, bounty_id: u64) -> Result<(), Error> {
+        applicant.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+        let mut bounty = bounty.unwrap();
+
+        match Self::effective_status(&env, &bounty) {
+            Status::Active => {}
+            Status::Judging => return Err(Error::BountyDeadlinePassed),
+            _ => return Err(Error::InactiveBounty),
+        }
+
+        if !bounty.submissions.contains_key(applicant.clone()) {
+            return Err(Error::SubmissionNotFound);
+        }
+
+        bounty.submissions.remove(applicant.clone());
+        if let Some(index) = bounty.applicants.first_index_of(applicant.clone()) {
+            bounty.applicants.remove(index);
+        }
+        storage.set(&bounty_key(bounty_id), &bounty);
+        storage.set(&applicant_count_key(bounty_id), &bounty.applicants.len());
+        storage.remove(&proposal_key(bounty_id, applicant.clone()));
+        storage.remove(&submission_history_key(bounty_id, applicant.clone()));
+        Events::emit_submission_withdrawn(&env, bounty_id, applicant);
+
+        Ok(())
+    }
+
+    // With `allow_partial`, fewer winners than ranks may be given: only their
+    // ranks are paid and the unfilled ranks' shares go to the remainder
+    // recipient (the owner by default), completing the bounty.
+    pub fn select_winners(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+        winners: Vec<Address>,
+        note: Option<String>,
+        allow_partial: bool,
+    ) -> Result<SelectionReceipt, Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
+        if bounty.is_none() {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut bounty = bounty.unwrap();
+
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+        if bounty.distribution_completed {
+            return Err(Error::AlreadyDistributed);
+        }
+        if let Some(note) = &note
+            && note.len() > MAX_NOTE_LENGTH
+        {
+            return Err(Error::TooManyItems);
+        }
+        match Self::effective_status(&env, &bounty) {
+            Status::Judging => {}
+            Status::Active => return Err(Error::CannotSelectWinnersBeforeSubmissionDeadline),
+            _ => return Err(Error::InactiveBounty),
+        }
+        let now = Self::deadline_clock(&env, &bounty.deadline_mode);
+        if now > bounty.judging_deadline {
+            return Err(Error::JudgingDeadlinePassed);
+        }
+        if winners.is_empty() || (!allow_partial && winners.len() < bounty.distribution.len()) {
+            return Err(Error::NotEnoughWinners);
+        }
+        assert_unique_addresses(&winners)?;
+
+        // Get token client to transfer reward
+        let token_client = get_token_client(&env, bounty.token.clone());
+
+        // Use the full reward amount for distribution (fee already paid in create_bounty)
+        let total_reward = bounty.reward;
+
+        let mut distributed = 0i128;
+        let mut winners_paid = 0u32;
+        let mut payouts = Self::get_payouts(&env, bounty_id);
+
+        // Shares below the floor are topped up from the owner's allowance,
+        // along with the platform fee on the top-up
+        let (allocations, top_up) = Self::apply_winner_floor(
+            &env,
+            &bounty,
+            Self::winner_allocations(&env, &bounty, &winners),
+        )?;
+        if top_up > 0 {
+            if top_up.checked_mul(100).is_none() {
+                return Err(Error::ArithmeticError);
+            }
+            let decimals = Self::token_decimals(&env, &bounty.token);
+            let top_up_fee =
+                Self::charged_fee(&env, &bounty.token, top_up, FeeType::Bounty, decimals);
+            let pulled = token_client.try_transfer_from(
+                &env.current_contract_address(),
+                &owner,
+                &env.current_contract_address(),
+                &top_up.checked_add(top_up_fee).ok_or(Error::ArithmeticError)?,
+            );
+            if !matches!(pulled, Ok(Ok(()))) {
+                return Err(Error::InsufficientEscrow);
+            }
+            let insured =
+                Self::forward_bounty_fee(&env, &bounty.token, top_up_fee, &bounty.fee_recipient);
+            bounty.fee += top_up_fee;
+            bounty.insured_fee += insured;
+        }
 
-            let bounty = bounty.unwrap();
-            if bounty.status == status {
-                count += 1;
+        // Distribute to available winners
+        for (winner, amount) in allocations.iter() {
+            // Amount is already adjusted for token decimals
+            if amount > 0 {
+                Self::pay_winner(&env, &bounty, bounty_id, &bounty.token, &winner, amount);
             }
+            payouts.set(winner.clone(), payouts.get(winner).unwrap_or(0) + amount);
+
+            distributed += amount; // Track using adjusted amount
+            winners_paid += 1;
         }
-        count
-    }
+        storage.set(&bounty_payouts_key(bounty_id), &payouts);
 
-    pub fn get_bounty(env: Env, bounty_id: u64) -> Result<Bounty, Error> {
-        let storage = env.storage().persistent();
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
-        if bounty.is_none() {
-            return Err(Error::BountyNotFound);
+        // Return remaining funds to owner (if any)
+        let remaining = total_reward - (distributed - top_up);
+        let remainder_recipient = Self::remainder_recipient(&env, &bounty);
+        if remaining > 0 {
+            // Remaining amount is already adjusted for token decimals
+            token_client.transfer(
+                &env.current_contract_address(),
+                &remainder_recipient,
+                &remaining,
+            );
         }
 
-        let bounty = bounty.unwrap();
-        Ok(bounty)
+        // Additional reward tokens follow the same split
+        for (extra_token, extra_reward) in bounty.extra_rewards.iter() {
+            let extra_client = get_token_client(&env, extra_token.clone());
+            let mut extra_distributed = 0i128;
+            for (winner, amount) in
+                Self::allocate_rewards(&env, &bounty, &winners, extra_reward).iter()
+            {
+                if amount == 0 {
+                    continue;
+                }
+                Self::pay_winner(&env, &bounty, bounty_id, &extra_token, &winner, amount);
+                extra_distributed += amount;
+            }
+            let extra_remaining = extra_reward - extra_distributed;
+            if extra_remaining > 0 {
+                extra_client.transfer(
+                    &env.current_contract_address(),
+                    &remainder_recipient,
+                    &extra_remaining,
+                );
+            }
+        }
+
+        Self::open_claim_windows(&env, &bounty, bounty_id);
+        Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Completed);
+        bounty.winners = winners.clone();
+        bounty.distribution_completed = true;
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Self::announce_not_selected(&env, bounty_id, &bounty);
+        Events::emit_winners_selected(&env, bounty_id, winners, note);
+
+        Ok(SelectionReceipt {
+            distributed,
+            returned_to_owner: remaining,
+            winners_paid,
+        })
     }
 
-    pub fn get_submission(env: Env, bounty_id: u64, user: Address) -> Result<String, Error> {
+    // What `select_winners` would pay each of `winners`, in user-friendly
+    // units, without moving funds. The last entry is the remainder recipient
+    // and what they would get back, when anything is left over.
+    pub fn preview_select_winners(
+        env: Env,
+        bounty_id: u64,
+        winners: Vec<Address>,
+        allow_partial: bool,
+    ) -> Result<Vec<(Address, i128)>, Error> {
         let storage = env.storage().persistent();
         let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
         if bounty.is_none() {
@@ -349,212 +4070,322 @@ impl StallionContract {
         }
 
         let bounty = bounty.unwrap();
-        let submission = bounty.submissions.get(user);
-        if submission.is_none() {
-            return Err(Error::SubmissionNotFound);
+        if winners.is_empty() || (!allow_partial && winners.len() < bounty.distribution.len()) {
+            return Err(Error::NotEnoughWinners);
         }
-
-        Ok(submission.unwrap())
+        assert_unique_addresses(&winners)?;
+
+        let decimals = Self::token_decimals(&env, &bounty.token);
+        let (allocations, top_up) = Self::apply_winner_floor(
+            &env,
+            &bounty,
+            Self::winner_allocations(&env, &bounty, &winners),
+        )?;
+        let mut preview = Vec::new(&env);
+        let mut distributed = 0i128;
+        for (winner, amount) in allocations.iter() {
+            preview.push_back((winner, convert_from_token_amount(amount, decimals)));
+            distributed += amount;
+        }
+        let remaining = bounty.reward - (distributed - top_up);
+        if remaining > 0 {
+            preview.push_back((
+                Self::remainder_recipient(&env, &bounty),
+                convert_from_token_amount(remaining, decimals),
+            ));
+        }
+        Ok(preview)
     }
 
-    pub fn get_bounty_submissions(env: Env, bounty_id: u64) -> Result<Map<Address, String>, Error> {
-        let storage = env.storage().persistent();
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
-        if bounty.is_none() {
-            return Err(Error::BountyNotFound);
+    // Primary-token amount that selecting `num_winners` winners would leave
+    // for the remainder recipient (the owner unless configured otherwise).
+    // Ranks beyond the applicant count cannot be filled and count as left over.
+    pub fn preview_owner_remainder(
+        env: Env,
+        bounty_id: u64,
+        num_winners: u32,
+    ) -> Result<i128, Error> {
+        let bounty: Bounty = env
+            .storage()
+            .persistent()
+            .get(&bounty_key(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if num_winners == 0 {
+            return Err(Error::NotEnoughWinners);
         }
 
-        let bounty = bounty.unwrap();
-        Ok(bounty.submissions)
+        // Allocations depend only on rank, so applicants stand in for winners
+        let filled = num_winners.min(bounty.applicants.len());
+        let stand_ins = bounty.applicants.slice(0..filled);
+        let (allocations, top_up) = Self::apply_winner_floor(
+            &env,
+            &bounty,
+            Self::winner_allocations(&env, &bounty, &stand_ins),
+        )?;
+        let mut distributed = 0i128;
+        for (_, amount) in allocations.iter() {
+            distributed += amount;
+        }
+        let decimals = Self::token_decimals(&env, &bounty.token);
+        Ok(convert_from_token_amount(bounty.reward - (distributed - top_up), decimals))
     }
 
-    pub fn get_bounty_applicants(env: Env, bounty_id: u64) -> Result<Vec<Address>, Error> {
+    // Settles a bounty like `select_winners`, but pays the winners in
+    // `alt_token` out of the admin-funded pool and refunds the whole escrow
+    // to the owner. Winners receive the same nominal amounts they would have
+    // been paid in the escrow token, so this trusts the admin to only fund
+    // pools for tokens that trade at par with the escrow token (e.g. two USD
+    // stablecoins). No price oracle is consulted.
+    pub fn select_winners_alt(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+        winners: Vec<Address>,
+        alt_token: Address,
+    ) -> Result<SelectionReceipt, Error> {
+        owner.require_auth();
+
         let storage = env.storage().persistent();
+
         let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
         if bounty.is_none() {
             return Err(Error::BountyNotFound);
         }
 
-        let bounty = bounty.unwrap();
-        Ok(bounty.applicants)
-    }
+        let mut bounty = bounty.unwrap();
 
-    pub fn get_bounty_winners(env: Env, bounty_id: u64) -> Result<Vec<Address>, Error> {
-        let storage = env.storage().persistent();
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
-        if bounty.is_none() {
-            return Err(Error::BountyNotFound);
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
+        }
+        if bounty.distribution_completed {
+            return Err(Error::AlreadyDistributed);
+        }
+        match Self::effective_status(&env, &bounty) {
+            Status::Judging => {}
+            Status::Active => return Err(Error::CannotSelectWinnersBeforeSubmissionDeadline),
+            _ => return Err(Error::InactiveBounty),
+        }
+        if Self::deadline_clock(&env, &bounty.deadline_mode) > bounty.judging_deadline {
+            return Err(Error::JudgingDeadlinePassed);
+        }
+        if winners.len() < bounty.distribution.len() {
+            return Err(Error::NotEnoughWinners);
         }
+        assert_unique_addresses(&winners)?;
 
-        let bounty = bounty.unwrap();
-        Ok(bounty.winners)
-    }
+        // Rescale the escrow-token split into alt-token units
+        let escrow_decimals = Self::token_decimals(&env, &bounty.token);
+        let alt_decimals = Self::token_decimals(&env, &alt_token);
+        let mut allocations = Vec::new(&env);
+        let mut distributed = 0i128;
+        for (winner, amount) in
+            Self::allocate_rewards(&env, &bounty, &winners, bounty.reward).iter()
+        {
+            let alt_amount = convert_from_token_amount(
+                adjust_for_decimals(amount, alt_decimals),
+                escrow_decimals,
+            );
+            allocations.push_back((winner, alt_amount));
+            distributed += alt_amount;
+        }
 
-    pub fn get_bounty_status(env: Env, bounty_id: u64) -> Result<Status, Error> {
-        let storage = env.storage().persistent();
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
-        if bounty.is_none() {
-            return Err(Error::BountyNotFound);
+        let pool_key = alt_pool_key(alt_token.clone());
+        let pool: i128 = storage.get(&pool_key).unwrap_or(0);
+        if pool < distributed {
+            return Err(Error::InsufficientEscrow);
         }
+        storage.set(&pool_key, &(pool - distributed));
 
-        let bounty = bounty.unwrap();
-        Ok(bounty.status)
-    }
+        let mut winners_paid = 0u32;
+        for (winner, amount) in allocations.iter() {
+            if amount > 0 {
+                Self::pay_winner(&env, &bounty, bounty_id, &alt_token, &winner, amount);
+            }
+            winners_paid += 1;
+        }
 
-    // ========================================
-    // BOUNTY CREATION & MANAGEMENT
-    // ========================================
+        Self::refund_rewards(&env, &bounty, &bounty.owner);
 
-    pub fn create_bounty(
-        env: Env,
-        owner: Address,
-        token: Address,
-        reward: i128,
-        distribution: Vec<(u32, u32)>,
-        submission_deadline: u64,
-        judging_deadline: u64,
-        title: String,
-    ) -> Result<u64, Error> {
-        let storage = env.storage().persistent();
+        Self::open_claim_windows(&env, &bounty, bounty_id);
+        Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Completed);
+        bounty.winners = winners.clone();
+        bounty.distribution_completed = true;
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Self::announce_not_selected(&env, bounty_id, &bounty);
+        Events::emit_winners_selected(&env, bounty_id, winners, None);
+
+        Ok(SelectionReceipt {
+            distributed,
+            returned_to_owner: bounty.reward,
+            winners_paid,
+        })
+    }
 
-        if reward <= 0 {
-            return Err(Error::InvalidReward);
-        }
+    // Withdraws everything credited to `winner` on a claim-mode bounty.
+    // On acceptance-gated bounties this only works until the acceptance
+    // window closes.
+    pub fn claim_reward(env: Env, winner: Address, bounty_id: u64) -> Result<(), Error> {
+        winner.require_auth();
 
-        if !validate_distribution_sum(&distribution) {
-            return Err(Error::DistributionMustSumTo100);
+        let storage = env.storage().persistent();
+        let deadline: Option<u64> = storage.get(&acceptance_deadline_key(bounty_id));
+        if let Some(deadline) = deadline
+            && env.ledger().timestamp() > deadline
+        {
+            return Err(Error::DeadlinePassed);
         }
 
-        // Validate deadlines
-        if judging_deadline <= submission_deadline {
-            return Err(Error::JudgingDeadlineMustBeAfterSubmissionDeadline);
+        if !Self::pay_out_claims(&env, bounty_id, &winner) {
+            return Err(Error::NothingToClaim);
         }
 
-        // Get token decimals and adjust reward first to prevent precision loss in fee calculation
-        owner.require_auth();
-        let token_client = get_token_client(&env, token.clone());
-        let decimals = get_token_decimals(&env, &token);
-        let adjusted_reward = adjust_for_decimals(reward, decimals);
-        let adjusted_fee = calculate_fee(adjusted_reward, FeeType::Bounty);
-        let adjusted_total = adjusted_reward + adjusted_fee;
-
-        // Transfer reward + fee from owner to contract
-        token_client.transfer(&owner, &env.current_contract_address(), &adjusted_total);
+        Ok(())
+    }
 
-        // Immediately transfer fee to fee account
-        let fee_account = Self::get_fee_account(&env);
-        token_client.transfer(&env.current_contract_address(), &fee_account, &adjusted_fee);
+    // Pays out the claimable shares of several winners of a claim-mode
+    // bounty at once, e.g. from a keeper. Winners with nothing left to claim
+    // are skipped. Acceptance-gated bounties are excluded since each winner
+    // has to accept for themselves. Returns the number of winners paid.
+    pub fn claim_rewards_batch(
+        env: Env,
+        bounty_id: u64,
+        winners: Vec<Address>,
+    ) -> Result<u32, Error> {
+        if winners.len() > MAX_BATCH_SIZE {
+            return Err(Error::TooManyItems);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&acceptance_deadline_key(bounty_id))
+        {
+            return Err(Error::Unauthorized);
+        }
 
-        // Assign new bounty ID — upper 32 bits encode deployment epoch for global uniqueness
-        let base = Self::id_base(&env);
-        let id: u64 = storage.get(&next_id_key()).unwrap_or(base + 1);
-        storage.set(&next_id_key(), &(id + 1));
+        let mut paid = 0;
+        for winner in winners.iter() {
+            if Self::pay_out_claims(&env, bounty_id, &winner) {
+                paid += 1;
+            }
+        }
+        Ok(paid)
+    }
 
-        // Initialize bounty - store the adjusted reward amount
-        let mut distribution_map = Map::new(&env);
-        for (rank, percent) in distribution.iter() {
-            distribution_map.set(rank, percent);
+    // Winner accepts the payout of an acceptance-gated bounty (e.g. after
+    // filing tax forms off-chain) and withdraws it.
+    pub fn accept_and_claim(env: Env, winner: Address, bounty_id: u64) -> Result<(), Error> {
+        let deadline: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&acceptance_deadline_key(bounty_id));
+        if deadline.is_none() {
+            return Err(Error::NothingToClaim);
         }
-        let bounty = Bounty {
-            owner: owner.clone(),
-            token: token.clone(),
-            reward: adjusted_reward, // Store the adjusted reward amount
-            distribution: distribution_map,
-            submission_deadline,
-            judging_deadline,
-            title: title.clone(),
-            status: Status::Active,
-            applicants: Vec::new(&env),
-            submissions: Map::new(&env),
-            winners: Vec::new(&env),
-        };
-        storage.set(&bounty_key(id), &bounty);
-        Events::emit_bounty_created(&env, id);
 
-        Ok(id)
+        Self::claim_reward(env.clone(), winner.clone(), bounty_id)?;
+        Events::emit_winner_accepted(&env, bounty_id, winner);
+        Ok(())
     }
 
-    pub fn update_bounty(
+    // Returns to the owner every share still unclaimed once the acceptance
+    // window of the bounty has closed. Returns the winners who never accepted.
+    pub fn reclaim_unaccepted(
         env: Env,
         owner: Address,
         bounty_id: u64,
-        new_title: Option<String>,
-        new_distribution: Vec<(u32, u32)>,
-        new_submission_deadline: Option<u64>,
-    ) -> Result<(), Error> {
+    ) -> Result<Vec<Address>, Error> {
         owner.require_auth();
 
         let storage = env.storage().persistent();
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
 
-        // Only the bounty owner can update the bounty
+        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
         if bounty.is_none() {
             return Err(Error::BountyNotFound);
         }
 
-        let mut bounty = bounty.unwrap();
+        let bounty = bounty.unwrap();
+
         if bounty.owner != owner {
             return Err(Error::OnlyOwner);
         }
 
-        // Check if the bounty is still active
-        if bounty.status != Status::Active {
-            return Err(Error::InactiveBounty);
+        let deadline: Option<u64> = storage.get(&acceptance_deadline_key(bounty_id));
+        if deadline.is_none() {
+            return Err(Error::NothingToClaim);
         }
-
-        let now = env.ledger().timestamp();
-
-        // Update distribution if provided
-        if !new_distribution.is_empty() {
-            if !validate_distribution_sum(&new_distribution) {
-                return Err(Error::DistributionMustSumTo100);
-            }
-            let mut distribution_map = Map::new(&env);
-            for (rank, percent) in new_distribution.iter() {
-                distribution_map.set(rank, percent);
-            }
-            bounty.distribution = distribution_map;
+        if env.ledger().timestamp() <= deadline.unwrap() {
+            return Err(Error::TooEarly);
         }
 
-        // Update submission deadline if provided
-        if let Some(submission_deadline) = new_submission_deadline {
-            // Can't move submission deadline to the past
-            if submission_deadline < now {
-                return Err(Error::InvalidDeadlineUpdate);
-            }
-            // Can't move submission deadline past the judging deadline
-            if submission_deadline >= bounty.judging_deadline {
-                return Err(Error::JudgingDeadlineMustBeAfterSubmissionDeadline);
-            }
-            bounty.submission_deadline = submission_deadline;
+        let unaccepted = Self::return_unclaimed(&env, bounty_id, &bounty, &owner);
+        if unaccepted.is_empty() {
+            return Err(Error::NothingToClaim);
         }
 
-        // Update title if provided
-        if let Some(title) = &new_title {
-            bounty.title = title.clone();
-        }
+        Events::emit_unaccepted_reclaimed(&env, bounty_id, unaccepted.clone());
+        Ok(unaccepted)
+    }
 
-        let mut updated_fields: Vec<Symbol> = Vec::new(&env);
-        if new_title.is_some() {
-            updated_fields.push_back(Symbol::new(&env, "title"));
+    // Returns to the owner the shares of a claim-mode bounty that winners
+    // left unclaimed for longer than the admin-set claim window. Returns the
+    // winners whose shares were taken back.
+    pub fn reclaim_unclaimed(
+        env: Env,
+        owner: Address,
+        bounty_id: u64,
+    ) -> Result<Vec<Address>, Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+        let bounty: Bounty = storage.get(&bounty_key(bounty_id)).ok_or(Error::BountyNotFound)?;
+        if bounty.owner != owner {
+            return Err(Error::OnlyOwner);
         }
-        if !new_distribution.is_empty() {
-            updated_fields.push_back(Symbol::new(&env, "distribution"));
+
+        let opened_at: u64 = storage
+            .get(&claims_opened_at_key(bounty_id))
+            .ok_or(Error::NothingToClaim)?;
+        let window = Self::get_claim_window(env.clone());
+        if window == 0 || env.ledger().timestamp() <= opened_at.saturating_add(window) {
+            return Err(Error::TooEarly);
         }
-        if let Some(_submission_deadline) = new_submission_deadline {
-            updated_fields.push_back(Symbol::new(&env, "submission_deadline"));
+
+        let unclaimed = Self::return_unclaimed(&env, bounty_id, &bounty, &owner);
+        if unclaimed.is_empty() {
+            return Err(Error::NothingToClaim);
         }
 
-        // Save the updated bounty
-        storage.set(&bounty_key(bounty_id), &bounty);
-        Events::emit_bounty_updated(&env, bounty_id, updated_fields);
+        Events::emit_unclaimed_reclaimed(&env, bounty_id, unclaimed.clone());
+        Ok(unclaimed)
+    }
 
-        Ok(())
+    pub fn get_claimable_reward(
+        env: Env,
+        bounty_id: u64,
+        winner: Address,
+    ) -> Vec<(Address, i128)> {
+        env.storage()
+            .persistent()
+            .get(&claimable_reward_key(bounty_id, winner))
+            .unwrap_or(Vec::new(&env))
     }
 
-    pub fn delete_bounty(env: Env, owner: Address, bounty_id: u64) -> Result<(), Error> {
-        owner.require_auth();
+    pub fn check_judging(env: Env, bounty_id: u64) -> Result<(), Error> {
+        Self::settle_expired_judging(env, bounty_id, None)
+    }
+
+    // Same as `check_judging`, but pays the configured keeper incentive out of
+    // the bounty's reward to `keeper` when the call actually settles it.
+    pub fn keeper_check_judging(env: Env, keeper: Address, bounty_id: u64) -> Result<(), Error> {
+        keeper.require_auth();
+        Self::settle_expired_judging(env, bounty_id, Some(keeper))
+    }
 
+    fn settle_expired_judging(
+        env: Env,
+        bounty_id: u64,
+        keeper: Option<Address>,
+    ) -> Result<(), Error> {
         let storage = env.storage().persistent();
 
         let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
@@ -562,33 +4393,98 @@ impl StallionContract {
             return Err(Error::BountyNotFound);
         }
 
-        let bounty = bounty.unwrap();
+        let mut bounty = bounty.unwrap();
+
+        let now = Self::deadline_clock(&env, &bounty.deadline_mode);
+        if now <= bounty.judging_deadline || !Self::is_unsettled(&bounty.status) {
+            return Ok(());
+        }
+
+        // Get token client for adjustment
+        let token_client = get_token_client(&env, bounty.token.clone());
+
+        // Keeper incentive comes off the top of the primary reward
+        if let Some(keeper) = keeper {
+            let percent: u32 = storage.get(&keeper_incentive_key()).unwrap_or(0);
+            let incentive = bounty.reward * percent as i128 / 100;
+            if incentive > 0 {
+                token_client.transfer(&env.current_contract_address(), &keeper, &incentive);
+                Self::record_paid(&env, &bounty.token, incentive);
+                bounty.reward -= incentive;
+                Events::emit_keeper_rewarded(&env, bounty_id, keeper, incentive);
+            }
+        }
+
+        // Auto-distribute equally to all applicants (fee already paid in create_bounty)
+        let total_reward = bounty.reward;
+        let count = bounty.applicants.len() as i128;
+
+        if count == 0 {
+            // Return full reward to owner if no applicants (already adjusted)
+            Self::refund_rewards(&env, &bounty, &bounty.owner);
+
+            // Mark as settled so the reward cannot be refunded a second time
+            Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Completed);
+            storage.set(&bounty_key(bounty_id), &bounty);
+            Events::emit_auto_distributed(&env, bounty_id, Vec::new(&env));
+            return Ok(());
+        }
+
+        // Calculate share for each applicant
+        let share = total_reward / count;
 
-        if bounty.owner != owner {
-            return Err(Error::OnlyOwner);
+        // Distribute to each applicant (share is already adjusted)
+        let mut payouts = Self::get_payouts(&env, bounty_id);
+        let mut recipients = Vec::new(&env);
+        for applicant in bounty.applicants.iter() {
+            if share > 0 {
+                token_client.transfer(&env.current_contract_address(), &applicant, &share);
+            }
+            payouts.set(applicant.clone(), payouts.get(applicant.clone()).unwrap_or(0) + share);
+            recipients.push_back((applicant, share));
         }
-
-        // Check if there are any submissions
-        if bounty.submissions.len() > 0 {
-            return Err(Error::BountyHasSubmissions);
+        Self::record_paid(&env, &bounty.token, share * count);
+        storage.set(&bounty_payouts_key(bounty_id), &payouts);
+
+        // Rounding dust of the equal split goes to the remainder recipient
+        let remainder_recipient = Self::remainder_recipient(&env, &bounty);
+        let dust = total_reward - share * count;
+        if dust > 0 {
+            token_client.transfer(&env.current_contract_address(), &remainder_recipient, &dust);
         }
 
-        // Get token client for transfer
-        let token_client = get_token_client(&env, bounty.token.clone());
-
-        // Return funds to owner if the bounty has not been closed (already adjusted)
-        if bounty.status != Status::Closed {
-            token_client.transfer(&env.current_contract_address(), &owner, &bounty.reward);
+        // Additional reward tokens are split the same way
+        for (extra_token, extra_reward) in bounty.extra_rewards.iter() {
+            let extra_client = get_token_client(&env, extra_token.clone());
+            let extra_share = extra_reward / count;
+            if extra_share > 0 {
+                for applicant in bounty.applicants.iter() {
+                    extra_client.transfer(&env.current_contract_address(), &applicant, &extra_share);
+                }
+            }
+            Self::record_paid(&env, &extra_token, extra_share * count);
+            let extra_dust = extra_reward - extra_share * count;
+            if extra_dust > 0 {
+                extra_client.transfer(
+                    &env.current_contract_address(),
+                    &remainder_recipient,
+                    &extra_dust,
+                );
+            }
         }
 
-        // Remove bounty
-        storage.remove(&bounty_key(bounty_id));
-        Events::emit_bounty_deleted(&env, bounty_id);
+        Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Completed);
+        bounty.distribution_completed = true;
+        storage.set(&bounty_key(bounty_id), &bounty);
+        Events::emit_auto_distributed(&env, bounty_id, recipients);
 
         Ok(())
-    } 
+    }
 
-    pub fn close_bounty(env: Env, owner: Address, bounty_id: u64) -> Result<(), Error> {
+    // Owner-driven alternative to the equal split in `check_judging`: once the
+    // judging deadline has passed without winners, the owner takes the full
+    // reward back and the bounty is closed.
+    pub fn reclaim_after_judging(env: Env, owner: Address, bounty_id: u64) -> Result<(), Error> {
         owner.require_auth();
 
         let storage = env.storage().persistent();
@@ -604,244 +4500,255 @@ impl StallionContract {
             return Err(Error::OnlyOwner);
         }
 
-        // Check if there are any submissions
-        if bounty.submissions.len() > 0 {
-            return Err(Error::BountyHasSubmissions);
+        if !Self::is_unsettled(&bounty.status) {
+            return Err(Error::InactiveBounty);
         }
 
-        // Get token client for transfer
-        let token_client = get_token_client(&env, bounty.token.clone());
+        let now = Self::deadline_clock(&env, &bounty.deadline_mode);
+        if now <= bounty.judging_deadline {
+            return Err(Error::TooEarly);
+        }
 
         // Return funds to owner (already adjusted)
-        token_client.transfer(&env.current_contract_address(), &owner, &bounty.reward);
+        Self::refund_rewards(&env, &bounty, &owner);
 
-        // Update bounty status to Closed
-        bounty.status = Status::Closed;
+        Self::set_bounty_status(&env, bounty_id, &mut bounty, Status::Closed);
         storage.set(&bounty_key(bounty_id), &bounty);
         Events::emit_bounty_closed(&env, bounty_id);
 
         Ok(())
     }
 
-    pub fn apply_to_bounty(
-        env: Env,
-        applicant: Address,
-        bounty_id: u64,
-        submission_link: String,
-    ) -> Result<(), Error> {
-        applicant.require_auth();
+    // Publishes a final summary of a settled bounty for off-chain storage and
+    // drops its submission links so the entry stays cheap to keep alive.
+    // Callable by the owner or the admin once the bounty is Completed or Closed.
+    pub fn archive_bounty(env: Env, caller: Address, bounty_id: u64) -> Result<(), Error> {
+        caller.require_auth();
 
         let storage = env.storage().persistent();
+
         let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
         if bounty.is_none() {
             return Err(Error::BountyNotFound);
         }
 
         let mut bounty = bounty.unwrap();
-        let now = env.ledger().timestamp();
-        if bounty.status != Status::Active {
-            return Err(Error::InactiveBounty);
+
+        if caller != bounty.owner && caller != Self::get_admin(&env) {
+            return Err(Error::Unauthorized);
         }
-        if now > bounty.submission_deadline {
-            return Err(Error::BountyDeadlinePassed);
+
+        if !matches!(bounty.status, Status::Completed | Status::Closed) {
+            return Err(Error::TooEarly);
         }
-        // Register applicant if new
-        if !bounty.submissions.contains_key(applicant.clone()) {
-            bounty.applicants.push_back(applicant.clone());
+
+        bounty.submissions = Map::new(&env);
+        for applicant in bounty.applicants.iter() {
+            storage.remove(&proposal_key(bounty_id, applicant.clone()));
+            storage.remove(&submission_history_key(bounty_id, applicant));
         }
-        // Set/update submission
-        bounty
-            .submissions
-            .set(applicant.clone(), submission_link.clone());
         storage.set(&bounty_key(bounty_id), &bounty);
-        Events::emit_submission_added(&env, bounty_id, applicant);
+        Events::emit_bounty_archived(
+            &env,
+            bounty_id,
+            bounty.status.clone(),
+            bounty.winners.clone(),
+            Self::get_payouts(&env, bounty_id),
+        );
 
         Ok(())
     }
 
-    pub fn update_submission(
-        env: Env,
-        applicant: Address,
-        bounty_id: u64,
-        new_submission_link: String,
-    ) -> Result<(), Error> {
-        applicant.require_auth();
+    // ========================================
+    // PROJECT QUERY FUNCTIONS
+    // ========================================
 
+    pub fn get_project(env: Env, project_id: u64) -> Result<Project, Error> {
         let storage = env.storage().persistent();
+        let project: Option<Project> = storage.get(&project_key(project_id));
 
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
-        if bounty.is_none() {
-            return Err(Error::BountyNotFound);
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
         }
-        let mut bounty = bounty.unwrap();
 
-        let now = env.ledger().timestamp();
-
-        // Check if bounty is active
-        if bounty.status != Status::Active {
-            return Err(Error::InactiveBounty);
-        }
+        Ok(project.unwrap())
+    }
 
-        // Check if submission deadline has passed
-        if now > bounty.submission_deadline {
-            return Err(Error::BountyDeadlinePassed);
-        }
+    // Existence check that skips deserializing the project.
+    pub fn project_exists(env: Env, project_id: u64) -> bool {
+        env.storage().persistent().has(&project_key(project_id))
+    }
 
-        // Check if the applicant has an existing submission
-        if !bounty.submissions.contains_key(applicant.clone()) {
-            return Err(Error::SubmissionNotFound);
+    // Summaries of the requested projects in input order; unknown ids are
+    // skipped. At most MAX_BATCH_SIZE ids per call.
+    pub fn get_projects_summary(env: Env, ids: Vec<u64>) -> Result<Vec<ProjectSummary>, Error> {
+        if ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::TooManyItems);
         }
 
-        // Update the submission
-        bounty
-            .submissions
-            .set(applicant.clone(), new_submission_link);
-        storage.set(&bounty_key(bounty_id), &bounty);
-
-        // Emit an event for the update
-        Events::emit_submission_updated(&env, bounty_id, applicant);
+        let storage = env.storage().persistent();
+        let mut summaries = Vec::new(&env);
+        for id in ids.iter() {
+            let project: Option<Project> = storage.get(&project_key(id));
+            if project.is_none() {
+                continue;
+            }
 
-        Ok(())
+            let project = project.unwrap();
+            summaries.push_back(ProjectSummary {
+                id,
+                project_type: project.project_type,
+                status: project.status,
+                total_reward: project.total_reward,
+                remaining_escrow: project.remaining_escrow,
+                deadline: project.deadline,
+            });
+        }
+        Ok(summaries)
     }
 
-    pub fn select_winners(
+    // The lowest-order milestone still unpaid, or `None` once all are paid.
+    pub fn get_next_unpaid_milestone(
         env: Env,
-        owner: Address,
-        bounty_id: u64,
-        winners: Vec<Address>,
-    ) -> Result<(), Error> {
-        owner.require_auth();
-
+        project_id: u64,
+    ) -> Result<Option<MilestoneInfo>, Error> {
         let storage = env.storage().persistent();
-
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
-        if bounty.is_none() {
-            return Err(Error::BountyNotFound);
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
         }
 
-        let mut bounty = bounty.unwrap();
-
-        if bounty.owner != owner {
-            return Err(Error::OnlyOwner);
-        }
-        let now = env.ledger().timestamp();
-        if now < bounty.submission_deadline {
-            return Err(Error::CannotSelectWinnersBeforeSubmissionDeadline);
-        }
-        if now > bounty.judging_deadline {
-            return Err(Error::JudgingDeadlinePassed);
-        }
-        let num_spec = bounty.distribution.len();
-        if winners.len() < num_spec {
-            return Err(Error::NotEnoughWinners);
+        let project = project.unwrap();
+        let mut next: Option<MilestoneInfo> = None;
+        for milestone in project.milestones.iter() {
+            if milestone.is_paid {
+                continue;
+            }
+            if next.as_ref().is_none_or(|current| milestone.order < current.order) {
+                next = Some(milestone);
+            }
         }
+        Ok(next)
+    }
 
-        // Get token client to transfer reward
-        let token_client = get_token_client(&env, bounty.token.clone());
-
-        // Use the full reward amount for distribution (fee already paid in create_bounty)
-        let total_reward = bounty.reward;
-
-        // Calculate how many winners we can actually reward
-        let actual_winners = winners.len().min(bounty.applicants.len());
-        let mut distributed = 0i128;
-
-        // Distribute to available winners
-        for i in 0..actual_winners {
-            let rank = (i + 1) as u32;
-            if let Some(pct) = bounty.distribution.get(rank) {
-                let amount = total_reward * (pct as i128) / 100;
-                let winner = winners.get(i as u32).unwrap();
-
-                // Amount is already adjusted for token decimals
-                token_client.transfer(&env.current_contract_address(), &winner, &amount);
+    // Paid milestone value as a whole percentage of the project's total
+    // reward, so a large milestone weighs more than a small one.
+    pub fn get_project_value_progress(env: Env, project_id: u64) -> Result<u32, Error> {
+        let project: Project = env
+            .storage()
+            .persistent()
+            .get(&project_key(project_id))
+            .ok_or(Error::ProjectNotFound)?;
+        if project.total_reward <= 0 {
+            return Ok(0);
+        }
 
-                distributed += amount; // Track using adjusted amount
+        let mut paid = 0i128;
+        for milestone in project.milestones.iter() {
+            if milestone.is_paid {
+                paid += milestone.amount;
             }
         }
+        Ok((paid * 100 / project.total_reward) as u32)
+    }
 
-        // Return remaining funds to owner (if any)
-        let remaining = total_reward - distributed;
-        if remaining > 0 {
-            // Remaining amount is already adjusted for token decimals
-            token_client.transfer(
-                &env.current_contract_address(),
-                &bounty.owner,
-                &remaining,
-            );
+    pub fn get_project_status(env: Env, project_id: u64) -> Result<ProjectStatus, Error> {
+        let storage = env.storage().persistent();
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
         }
 
-        bounty.status = Status::Completed;
-        bounty.winners = winners.clone();
-        storage.set(&bounty_key(bounty_id), &bounty);
-        Events::emit_winners_selected(&env, bounty_id, winners);
-
-        Ok(())
+        let project = project.unwrap();
+        Ok(project.status)
     }
 
-    pub fn check_judging(env: Env, bounty_id: u64) -> Result<(), Error> {
+    pub fn get_project_owner(env: Env, project_id: u64) -> Result<Address, Error> {
         let storage = env.storage().persistent();
-
-        let bounty: Option<Bounty> = storage.get(&bounty_key(bounty_id));
-        if bounty.is_none() {
-            return Err(Error::BountyNotFound);
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
         }
 
-        let mut bounty = bounty.unwrap();
+        let project = project.unwrap();
+        Ok(project.owner)
+    }
 
-        let now = env.ledger().timestamp();
-        if now <= bounty.judging_deadline || bounty.status != Status::Active {
-            return Ok(());
-        }
+    // Total `contributor` has received from a gig's milestone payments, net
+    // of release fees, in token units.
+    pub fn get_contributor_earnings(env: Env, project_id: u64, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&project_earnings_key(project_id, contributor))
+            .unwrap_or(0)
+    }
 
-        // Get token client for adjustment
-        let token_client = get_token_client(&env, bounty.token.clone());
+    // Same as `get_contributor_earnings`, summed across every project paid
+    // in `token`.
+    pub fn get_total_contributor_earnings(env: Env, contributor: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&contributor_earnings_key(contributor, token))
+            .unwrap_or(0)
+    }
 
-        // Auto-distribute equally to all applicants (fee already paid in create_bounty)
-        let total_reward = bounty.reward;
-        let count = bounty.applicants.len() as i128;
+    pub fn get_projects(env: Env) -> Vec<u64> {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_project_id_key()).unwrap_or(start);
+        let mut projects = Vec::new(&env);
 
-        if count == 0 {
-            // Return full reward to owner if no applicants (already adjusted)
-            token_client.transfer(
-                &env.current_contract_address(),
-                &bounty.owner,
-                &bounty.reward,
-            );
-            return Ok(());
+        for id in start..next_id {
+            let project: Option<Project> = storage.get(&project_key(id));
+            if project.is_some() {
+                projects.push_back(id);
+            }
         }
 
-        // Calculate share for each applicant
-        let share = total_reward / count;
+        projects
+    }
 
-        // Distribute to each applicant (share is already adjusted)
-        for applicant in bounty.applicants.iter() {
-            token_client.transfer(&env.current_contract_address(), &applicant, &share);
-        }
+    pub fn get_projects_count(env: Env) -> u32 {
+        let storage = env.storage().persistent();
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_project_id_key()).unwrap_or(start);
 
-        bounty.status = Status::Completed;
-        storage.set(&bounty_key(bounty_id), &bounty);
-        Events::emit_auto_distributed(&env, bounty_id);
+        let mut count = 0;
+        for id in start..next_id {
+            let project: Option<Project> = storage.get(&project_key(id));
+            if project.is_some() {
+                count += 1;
+            }
+        }
 
-        Ok(())
+        count
     }
 
-    // ========================================
-    // PROJECT QUERY FUNCTIONS
-    // ========================================
-
-    pub fn get_project(env: Env, project_id: u64) -> Result<Project, Error> {
+    pub fn get_owner_projects(env: Env, owner: Address) -> Vec<u64> {
         let storage = env.storage().persistent();
-        let project: Option<Project> = storage.get(&project_key(project_id));
+        let base = Self::id_base(&env);
+        let start = base + 1;
+        let next_id: u64 = storage.get(&next_project_id_key()).unwrap_or(start);
+        let mut projects = Vec::new(&env);
 
-        if project.is_none() {
-            return Err(Error::ProjectNotFound);
+        for id in start..next_id {
+            let project: Option<Project> = storage.get(&project_key(id));
+            if project.is_none() {
+                continue;
+            }
+
+            let project = project.unwrap();
+            if project.owner == owner {
+                projects.push_back(id);
+            }
         }
 
-        Ok(project.unwrap())
+        projects
     }
 
-    pub fn get_projects(env: Env) -> Vec<u64> {
+    pub fn get_projects_by_status(env: Env, status: ProjectStatus) -> Vec<u64> {
         let storage = env.storage().persistent();
         let base = Self::id_base(&env);
         let start = base + 1;
@@ -850,7 +4757,12 @@ impl StallionContract {
 
         for id in start..next_id {
             let project: Option<Project> = storage.get(&project_key(id));
-            if project.is_some() {
+            if project.is_none() {
+                continue;
+            }
+
+            let project = project.unwrap();
+            if project.status == status {
                 projects.push_back(id);
             }
         }
@@ -858,13 +4770,13 @@ impl StallionContract {
         projects
     }
 
-    pub fn get_owner_projects(env: Env, owner: Address) -> Vec<u64> {
+    pub fn get_projects_by_status_count(env: Env, status: ProjectStatus) -> u32 {
         let storage = env.storage().persistent();
         let base = Self::id_base(&env);
         let start = base + 1;
         let next_id: u64 = storage.get(&next_project_id_key()).unwrap_or(start);
-        let mut projects = Vec::new(&env);
 
+        let mut count = 0;
         for id in start..next_id {
             let project: Option<Project> = storage.get(&project_key(id));
             if project.is_none() {
@@ -872,15 +4784,16 @@ impl StallionContract {
             }
 
             let project = project.unwrap();
-            if project.owner == owner {
-                projects.push_back(id);
+            if project.status == status {
+                count += 1;
             }
         }
 
-        projects
+        count
     }
 
-    pub fn get_projects_by_status(env: Env, status: ProjectStatus) -> Vec<u64> {
+    // Projects whose deadline falls within `from..=to`.
+    pub fn get_projects_due_between(env: Env, from: u64, to: u64) -> Vec<u64> {
         let storage = env.storage().persistent();
         let base = Self::id_base(&env);
         let start = base + 1;
@@ -894,7 +4807,7 @@ impl StallionContract {
             }
 
             let project = project.unwrap();
-            if project.status == status {
+            if project.deadline >= from && project.deadline <= to {
                 projects.push_back(id);
             }
         }
@@ -939,7 +4852,9 @@ impl StallionContract {
             if milestone.amount <= 0 {
                 return Err(Error::InvalidAmount);
             }
-            milestone_sum += milestone.amount;
+            milestone_sum = milestone_sum
+                .checked_add(milestone.amount)
+                .ok_or(Error::ArithmeticError)?;
         }
         if milestone_sum != total_reward {
             return Err(Error::InvalidMilestones);
@@ -947,16 +4862,28 @@ impl StallionContract {
 
         // Get token decimals and adjust total reward first to prevent precision loss in fee calculation
         let token_client = get_token_client(&env, token.clone());
-        let decimals = get_token_decimals(&env, &token);
-        let adjusted_reward = adjust_for_decimals(total_reward, decimals);
-        let adjusted_fee = calculate_fee(adjusted_reward, FeeType::Gig);
-        let adjusted_total = adjusted_reward + adjusted_fee;
+        let decimals = Self::token_decimals(&env, &token);
+        let adjusted_reward =
+            checked_adjust_for_decimals(total_reward, decimals).ok_or(Error::ArithmeticError)?;
+        // The fee is a percentage of the reward, so the reward must survive
+        // being multiplied by 100
+        if adjusted_reward.checked_mul(100).is_none() {
+            return Err(Error::ArithmeticError);
+        }
+        let adjusted_fee =
+            Self::charged_fee(&env, &token, adjusted_reward, FeeType::Gig, decimals);
+        let adjusted_total = adjusted_reward
+            .checked_add(adjusted_fee)
+            .ok_or(Error::ArithmeticError)?;
+        if token_client.balance(&owner) < adjusted_total {
+            return Err(Error::InsufficientBalance);
+        }
 
         token_client.transfer(&owner, &env.current_contract_address(), &adjusted_total);
 
         // Transfer platform fee to fee account
-        let fee_account = Self::get_fee_account(&env);
-        token_client.transfer(&env.current_contract_address(), &fee_account, &adjusted_fee);
+        Self::forward_fee(&env, &token, adjusted_fee);
+        Self::record_token(&env, &token);
 
         // Assign new project ID — upper 32 bits encode deployment epoch for global uniqueness
         let base = Self::id_base(&env);
@@ -970,6 +4897,7 @@ impl StallionContract {
                 amount: adjust_for_decimals(milestone.amount, decimals),
                 order: milestone.order,
                 is_paid: false,
+                payee: None,
             });
         }
 
@@ -983,9 +4911,14 @@ impl StallionContract {
             deadline,
             status: ProjectStatus::Active,
             milestones: milestone_infos,
+            release_fee_percent: storage.get(&milestone_release_fee_key()).unwrap_or(0),
         };
 
         storage.set(&project_key(id), &project);
+        Self::update_stats(&env, |stats| {
+            stats.total_projects += 1;
+            stats.active_projects += 1;
+        });
         Events::emit_project_gig_created(&env, id, total_reward);
 
         Ok(id)
@@ -1021,15 +4954,8 @@ impl StallionContract {
             return Err(Error::ProjectNotActive);
         }
 
-        let now = env.ledger().timestamp();
-
         if let Some(deadline) = new_deadline {
-            if deadline < project.deadline {
-                return Err(Error::InvalidDeadlineUpdate);
-            }
-            if deadline <= now {
-                return Err(Error::DeadlinePassed);
-            }
+            Self::check_project_deadline(&env, &project, deadline)?;
             project.deadline = deadline;
         }
 
@@ -1050,7 +4976,7 @@ impl StallionContract {
                 return Err(Error::InvalidMilestones);
             }
 
-            let decimals = get_token_decimals(&env, &project.token);
+            let decimals = Self::token_decimals(&env, &project.token);
             let adjusted_reward = adjust_for_decimals(project.total_reward, decimals);
 
             let mut milestone_infos = Vec::new(&env);
@@ -1059,6 +4985,7 @@ impl StallionContract {
                     amount: adjust_for_decimals(milestone.amount, decimals),
                     order: milestone.order,
                     is_paid: false,
+                    payee: None,
                 });
             }
 
@@ -1095,15 +5022,16 @@ impl StallionContract {
 
         // Get token decimals and adjust reward amount first to prevent precision loss in fee calculation
         let token_client = get_token_client(&env, token.clone());
-        let decimals = get_token_decimals(&env, &token);
+        let decimals = Self::token_decimals(&env, &token);
         let adjusted_reward = adjust_for_decimals(reward_amount, decimals);
-        let adjusted_fee = calculate_fee(adjusted_reward, FeeType::Job);
+        let adjusted_fee =
+            Self::charged_fee(&env, &token, adjusted_reward, FeeType::Job, decimals);
 
         token_client.transfer(&owner, &env.current_contract_address(), &adjusted_fee);
 
         // Transfer platform fee to fee account
-        let fee_account = Self::get_fee_account(&env);
-        token_client.transfer(&env.current_contract_address(), &fee_account, &adjusted_fee);
+        Self::forward_fee(&env, &token, adjusted_fee);
+        Self::record_token(&env, &token);
 
         // Assign new project ID — upper 32 bits encode deployment epoch for global uniqueness
         let base = Self::id_base(&env);
@@ -1120,9 +5048,14 @@ impl StallionContract {
             deadline,
             status: ProjectStatus::Active,
             milestones: Vec::new(&env),
+            release_fee_percent: 0,
         };
 
         storage.set(&project_key(id), &project);
+        Self::update_stats(&env, |stats| {
+            stats.total_projects += 1;
+            stats.active_projects += 1;
+        });
         Events::emit_project_job_created(&env, id);
 
         Ok(id)
@@ -1157,20 +5090,194 @@ impl StallionContract {
             return Err(Error::ProjectNotActive);
         }
 
-        let now = env.ledger().timestamp();
-
         if let Some(deadline) = new_deadline {
-            if deadline < project.deadline {
-                return Err(Error::InvalidDeadlineUpdate);
+            Self::check_project_deadline(&env, &project, deadline)?;
+            project.deadline = deadline;
+        }
+
+        storage.set(&project_key(project_id), &project);
+
+        Ok(())
+    }
+
+    // Moves the deadline of an active gig or job, under the same rules as
+    // `update_project_gig`/`update_project_job` (see `check_project_deadline`).
+    // Milestones carry no deadlines of their own, so only the project
+    // deadline is checked.
+    pub fn update_project_deadline(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        new_deadline: u64,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
+        }
+
+        let mut project = project.unwrap();
+
+        if project.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+
+        if project.status != ProjectStatus::Active {
+            return Err(Error::ProjectNotActive);
+        }
+
+        Self::check_project_deadline(&env, &project, new_deadline)?;
+
+        project.deadline = new_deadline;
+        storage.set(&project_key(project_id), &project);
+        Events::emit_project_updated(&env, project_id, new_deadline);
+
+        Ok(())
+    }
+
+    // Re-prices an unpaid gig milestone. An increase is pulled from the owner
+    // into escrow together with the gig fee on it; a decrease is refunded
+    // (the fee already paid on it is not).
+    pub fn update_milestone_amount(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        milestone_order: u32,
+        new_amount: i128,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        if new_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let storage = env.storage().persistent();
+
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
+        }
+
+        let mut project = project.unwrap();
+
+        if project.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+
+        if project.project_type != ProjectType::Gig {
+            return Err(Error::InvalidProjectType);
+        }
+
+        if project.status != ProjectStatus::Active {
+            return Err(Error::ProjectNotActive);
+        }
+
+        let mut milestone_index = None;
+        for (i, milestone) in project.milestones.iter().enumerate() {
+            if milestone.order == milestone_order {
+                if milestone.is_paid {
+                    return Err(Error::MilestoneAlreadyPaid);
+                }
+                milestone_index = Some(i as u32);
+                break;
             }
-            if deadline <= now {
-                return Err(Error::DeadlinePassed);
+        }
+        let milestone_index = milestone_index.ok_or(Error::MilestoneNotFound)?;
+        let mut milestone = project.milestones.get(milestone_index).unwrap();
+
+        let decimals = Self::token_decimals(&env, &project.token);
+        let adjusted_amount =
+            checked_adjust_for_decimals(new_amount, decimals).ok_or(Error::ArithmeticError)?;
+        let adjusted_delta = adjusted_amount
+            .checked_sub(milestone.amount)
+            .ok_or(Error::ArithmeticError)?;
+
+        let remaining_escrow = project
+            .remaining_escrow
+            .checked_add(adjusted_delta)
+            .ok_or(Error::ArithmeticError)?;
+        if remaining_escrow < 0 {
+            return Err(Error::InsufficientEscrow);
+        }
+        let total_reward = project
+            .total_reward
+            .checked_add(adjusted_delta)
+            .ok_or(Error::ArithmeticError)?;
+
+        let token_client = get_token_client(&env, project.token.clone());
+        if adjusted_delta > 0 {
+            if adjusted_delta.checked_mul(100).is_none() {
+                return Err(Error::ArithmeticError);
             }
-            project.deadline = deadline;
+            let fee = Self::charged_fee(&env, &project.token, adjusted_delta, FeeType::Gig, decimals);
+            let pulled = adjusted_delta.checked_add(fee).ok_or(Error::ArithmeticError)?;
+            if token_client.balance(&owner) < pulled {
+                return Err(Error::InsufficientBalance);
+            }
+            token_client.transfer(&owner, &env.current_contract_address(), &pulled);
+            Self::forward_fee(&env, &project.token, fee);
+        } else if adjusted_delta < 0 {
+            token_client.transfer(&env.current_contract_address(), &owner, &(-adjusted_delta));
+        }
+
+        milestone.amount = adjusted_amount;
+        project.milestones.set(milestone_index, milestone);
+        project.remaining_escrow = remaining_escrow;
+        project.total_reward = total_reward;
+
+        storage.set(&project_key(project_id), &project);
+        Events::emit_milestone_updated(&env, project_id, milestone_order, adjusted_amount, adjusted_delta);
+
+        Ok(())
+    }
+
+    // Pins an unpaid gig milestone to a specific payee (e.g. a subcontractor)
+    // so `release_milestone_payment` only pays it to them. `None` unpins it.
+    pub fn set_milestone_payee(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        milestone_order: u32,
+        payee: Option<Address>,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        if let Some(payee) = &payee
+            && is_zero_address(&env, payee)
+        {
+            return Err(Error::ContributorCannotBeZero);
+        }
+
+        let storage = env.storage().persistent();
+        let mut project: Project =
+            storage.get(&project_key(project_id)).ok_or(Error::ProjectNotFound)?;
+        if project.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+        if project.project_type != ProjectType::Gig {
+            return Err(Error::InvalidProjectType);
+        }
+        if project.status != ProjectStatus::Active {
+            return Err(Error::ProjectNotActive);
         }
 
+        let index = project
+            .milestones
+            .iter()
+            .position(|milestone| milestone.order == milestone_order)
+            .ok_or(Error::MilestoneNotFound)? as u32;
+        let mut milestone = project.milestones.get(index).unwrap();
+        if milestone.is_paid {
+            return Err(Error::MilestoneAlreadyPaid);
+        }
+        milestone.payee = payee.clone();
+        project.milestones.set(index, milestone);
         storage.set(&project_key(project_id), &project);
 
+        Events::emit_milestone_payee_updated(&env, project_id, milestone_order, payee);
         Ok(())
     }
 
@@ -1184,6 +5291,10 @@ impl StallionContract {
     ) -> Result<(), Error> {
         owner.require_auth();
 
+        if is_zero_address(&env, &contributor) {
+            return Err(Error::ContributorCannotBeZero);
+        }
+
         let storage = env.storage().persistent();
 
         // Get project
@@ -1212,8 +5323,9 @@ impl StallionContract {
         // Find milestone
         let mut milestone_found = false;
         let mut milestone_index: u32 = 0;
-        let decimals = get_token_decimals(&env, &project.token);
-        let adjusted_amount = adjust_for_decimals(amount, decimals);
+        let decimals = Self::token_decimals(&env, &project.token);
+        let adjusted_amount =
+            checked_adjust_for_decimals(amount, decimals).ok_or(Error::ArithmeticError)?;
 
         for (i, milestone) in project.milestones.iter().enumerate() {
             if milestone.order == milestone_order {
@@ -1229,6 +5341,11 @@ impl StallionContract {
                 if milestone.amount != adjusted_amount {
                     return Err(Error::InvalidAmount);
                 }
+                if let Some(payee) = &milestone.payee
+                    && *payee != contributor
+                {
+                    return Err(Error::Unauthorized);
+                }
                 break;
             }
         }
@@ -1241,10 +5358,14 @@ impl StallionContract {
         if project.remaining_escrow < adjusted_amount {
             return Err(Error::InsufficientEscrow);
         }
+        let remaining_escrow = project
+            .remaining_escrow
+            .checked_sub(adjusted_amount)
+            .filter(|remaining| *remaining >= 0)
+            .ok_or(Error::ArithmeticError)?;
 
         // Transfer payment to contributor (using adjusted amount)
-        let token_client = get_token_client(&env, project.token.clone());
-        token_client.transfer(&env.current_contract_address(), &contributor, &adjusted_amount);
+        Self::pay_out_milestone(&env, project_id, &project, &contributor, adjusted_amount);
 
         // Update milestone as paid
         let mut updated_milestone = project.milestones.get(milestone_index).unwrap();
@@ -1252,7 +5373,7 @@ impl StallionContract {
         project.milestones.set(milestone_index, updated_milestone);
 
         // Update remaining escrow
-        project.remaining_escrow -= adjusted_amount;
+        project.remaining_escrow = remaining_escrow;
 
         // Check if all milestones are paid
         let mut all_paid = true;
@@ -1264,13 +5385,133 @@ impl StallionContract {
         }
 
         if all_paid {
-            project.status = ProjectStatus::Completed;
-            Events::emit_project_completed(&env, project_id);
+            Self::set_project_status(&env, &mut project, ProjectStatus::Completed);
         }
 
         storage.set(&project_key(project_id), &project);
+
+        // Events are always ordered milestone_paid, then project_completed
+        // when this payment settles the last milestone
         Events::emit_milestone_paid(&env, project_id, milestone_order, contributor, adjusted_amount);
+        if all_paid {
+            Events::emit_project_completed(&env, project_id);
+        }
+
+        Ok(())
+    }
+
+    // Pays every unpaid milestone of a gig to `contributor` in one call and
+    // completes the project. Returns the total amount paid (already adjusted).
+    pub fn release_all_remaining(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        contributor: Address,
+    ) -> Result<i128, Error> {
+        owner.require_auth();
+
+        if is_zero_address(&env, &contributor) {
+            return Err(Error::ContributorCannotBeZero);
+        }
+
+        let storage = env.storage().persistent();
+
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
+        }
+
+        let mut project = project.unwrap();
+
+        if project.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+
+        if project.project_type != ProjectType::Gig {
+            return Err(Error::InvalidProjectType);
+        }
+
+        if project.status != ProjectStatus::Active {
+            return Err(Error::ProjectNotActive);
+        }
+
+        let mut total: i128 = 0;
+        for milestone in project.milestones.iter() {
+            if !milestone.is_paid {
+                total += milestone.amount;
+            }
+        }
+
+        if project.remaining_escrow < total {
+            return Err(Error::InsufficientEscrow);
+        }
+
+        // Milestones pinned to a payee go to that payee, the rest to
+        // `contributor` in one transfer
+        let mut unpinned: i128 = 0;
+        for i in 0..project.milestones.len() {
+            let mut milestone = project.milestones.get(i).unwrap();
+            if milestone.is_paid {
+                continue;
+            }
+            let payee = match milestone.payee.clone() {
+                Some(payee) => {
+                    if milestone.amount > 0 {
+                        Self::pay_out_milestone(&env, project_id, &project, &payee, milestone.amount);
+                    }
+                    payee
+                }
+                None => {
+                    unpinned += milestone.amount;
+                    contributor.clone()
+                }
+            };
+            milestone.is_paid = true;
+            project.milestones.set(i, milestone.clone());
+            Events::emit_milestone_paid(&env, project_id, milestone.order, payee, milestone.amount);
+        }
+        if unpinned > 0 {
+            Self::pay_out_milestone(&env, project_id, &project, &contributor, unpinned);
+        }
+
+        project.remaining_escrow -= total;
+        Self::set_project_status(&env, &mut project, ProjectStatus::Completed);
+
+        storage.set(&project_key(project_id), &project);
+        Events::emit_project_completed(&env, project_id);
+
+        Ok(total)
+    }
+
+    // Hands a project to `new_owner`, who from then on is the one allowed to
+    // release milestones, cancel it and receive its refunds.
+    pub fn transfer_project_ownership(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        new_owner: Address,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        // The zero address could never authorize anything on the project
+        if is_zero_address(&env, &new_owner) {
+            return Err(Error::InvalidAmount);
+        }
+
+        let storage = env.storage().persistent();
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
+        }
+
+        let mut project = project.unwrap();
+        if project.owner != owner {
+            return Err(Error::Unauthorized);
+        }
 
+        project.owner = new_owner.clone();
+        storage.set(&project_key(project_id), &project);
+        Events::emit_project_ownership_transferred(&env, project_id, owner, new_owner);
         Ok(())
     }
 
@@ -1278,6 +5519,34 @@ impl StallionContract {
         env: Env,
         owner: Address,
         project_id: u64,
+    ) -> Result<i128, Error> {
+        Self::cancel_gig_internal(env, owner, project_id, None)
+    }
+
+    // Cancels a gig after paying `contributor` `contributor_amount`
+    // (user-friendly units) out of the remaining escrow for unpaid partial
+    // work; the rest goes back to the owner. Returns the owner's refund.
+    pub fn cancel_gig_with_settlement(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        contributor: Address,
+        contributor_amount: i128,
+    ) -> Result<i128, Error> {
+        if contributor_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if is_zero_address(&env, &contributor) {
+            return Err(Error::ContributorCannotBeZero);
+        }
+        Self::cancel_gig_internal(env, owner, project_id, Some((contributor, contributor_amount)))
+    }
+
+    fn cancel_gig_internal(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        settlement: Option<(Address, i128)>,
     ) -> Result<i128, Error> {
         owner.require_auth();
 
@@ -1306,6 +5575,19 @@ impl StallionContract {
             return Err(Error::ProjectNotActive);
         }
 
+        // Settle partial work first, bounded by the remaining escrow
+        if let Some((contributor, amount)) = settlement {
+            let decimals = Self::token_decimals(&env, &project.token);
+            let adjusted_amount =
+                checked_adjust_for_decimals(amount, decimals).ok_or(Error::ArithmeticError)?;
+            if adjusted_amount > project.remaining_escrow {
+                return Err(Error::InsufficientEscrow);
+            }
+            Self::pay_out_milestone(&env, project_id, &project, &contributor, adjusted_amount);
+            project.remaining_escrow -= adjusted_amount;
+            Events::emit_project_settled(&env, project_id, contributor, adjusted_amount);
+        }
+
         // Calculate refund amount (remaining escrow)
         let refund_amount = project.remaining_escrow;
 
@@ -1316,7 +5598,7 @@ impl StallionContract {
         }
 
         // Update project status
-        project.status = ProjectStatus::Cancelled;
+        Self::set_project_status(&env, &mut project, ProjectStatus::Cancelled);
         project.remaining_escrow = 0;
 
         storage.set(&project_key(project_id), &project);
@@ -1324,6 +5606,43 @@ impl StallionContract {
 
         Ok(refund_amount)
     }
+
+    // Removes a Completed or Cancelled project to free its storage, along
+    // with its per-contributor earnings. Contributors' totals across projects
+    // are kept. Callable by the owner or the admin; project listings skip the
+    // missing entry.
+    pub fn purge_project(env: Env, caller: Address, project_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+
+        let storage = env.storage().persistent();
+
+        let project: Option<Project> = storage.get(&project_key(project_id));
+        if project.is_none() {
+            return Err(Error::ProjectNotFound);
+        }
+
+        let project = project.unwrap();
+
+        if caller != project.owner && caller != Self::get_admin(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        if project.status == ProjectStatus::Active {
+            return Err(Error::TooEarly);
+        }
+
+        let contributors: Vec<Address> = storage
+            .get(&project_contributors_key(project_id))
+            .unwrap_or(Vec::new(&env));
+        for contributor in contributors.iter() {
+            storage.remove(&project_earnings_key(project_id, contributor));
+        }
+        storage.remove(&project_contributors_key(project_id));
+        storage.remove(&project_key(project_id));
+        Events::emit_project_purged(&env, project_id);
+
+        Ok(())
+    }
     // ========================================
     // HACKATHON FUNCTIONS
     // ========================================
@@ -1368,15 +5687,16 @@ impl StallionContract {
 
         // Get token decimals and adjust total budget first to prevent precision loss in fee calculation
         let token_client = get_token_client(&env, token.clone());
-        let decimals = get_token_decimals(&env, &token);
+        let decimals = Self::token_decimals(&env, &token);
         let adjusted_budget = adjust_for_decimals(total_budget, decimals);
-        let adjusted_fee = calculate_fee(adjusted_budget, FeeType::Hackathon);
+        let adjusted_fee =
+            Self::charged_fee(&env, &token, adjusted_budget, FeeType::Hackathon, decimals);
         let adjusted_total = adjusted_budget + adjusted_fee;
 
         token_client.transfer(&owner, &env.current_contract_address(), &adjusted_total);
 
-        let fee_account = Self::get_fee_account(&env);
-        token_client.transfer(&env.current_contract_address(), &fee_account, &adjusted_fee);
+        Self::forward_fee(&env, &token, adjusted_fee);
+        Self::record_token(&env, &token);
 
         // Assign new hackathon ID — upper 32 bits encode deployment epoch for global uniqueness
         let base = Self::id_base(&env);
@@ -1450,7 +5770,7 @@ impl StallionContract {
         }
 
         if let Some(prize_pool) = new_prize_pool {
-            let decimals = get_token_decimals(&env, &hackathon.token);
+            let decimals = Self::token_decimals(&env, &hackathon.token);
             let mut adjusted_sum = 0;
             let mut positions = Map::new(&env);
             let mut adjusted_prize_pool = Vec::new(&env);
@@ -1569,6 +5889,7 @@ impl StallionContract {
             let winner_addr = provided_winners.get(prize.position).unwrap();
             // Prize amount is already adjusted
             token_client.transfer(&env.current_contract_address(), &winner_addr, &prize.amount);
+            Self::record_paid(&env, &hackathon.token, prize.amount);
             hackathon.remaining_escrow -= prize.amount;
         }
 
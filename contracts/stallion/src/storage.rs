@@ -1,5 +1,12 @@
+use soroban_sdk::{Address, BytesN};
+
 use crate::types::DataKey;
 
+// Every key starts with its own `DataKey` variant, and no two constructors
+// share a variant, so keys of different kinds can never serialize to the
+// same value. New keys get a new variant; `test_storage_keys_are_distinct`
+// lists every constructor.
+
 pub fn next_id_key() -> DataKey {
     DataKey::NextId
 }
@@ -12,6 +19,22 @@ pub fn admin_key() -> DataKey {
     DataKey::Admin
 }
 
+pub fn pending_admin_key() -> DataKey {
+    DataKey::PendingAdmin
+}
+
+pub fn pending_fee_account_key() -> DataKey {
+    DataKey::PendingFeeAccount
+}
+
+pub fn accrued_fees_key(token: Address) -> (DataKey, Address) {
+    (DataKey::AccruedFees, token)
+}
+
+pub fn approved_fee_recipient_key(recipient: Address) -> (DataKey, Address) {
+    (DataKey::ApprovedFeeRecipient, recipient)
+}
+
 pub fn fee_account_key() -> DataKey {
     DataKey::FeeAccount
 }
@@ -35,3 +58,198 @@ pub fn hackathon_key(id: u64) -> (DataKey, u64) {
 pub fn deployment_seq_key() -> DataKey {
     DataKey::DeploymentSeq
 }
+
+pub fn fee_cap_key(token: Address) -> (DataKey, Address) {
+    (DataKey::FeeCap, token)
+}
+
+pub fn token_decimals_key(token: Address) -> (DataKey, Address) {
+    (DataKey::TokenDecimals, token)
+}
+
+pub fn lifetime_funded_key(token: Address) -> (DataKey, Address) {
+    (DataKey::LifetimeFunded, token)
+}
+
+pub fn idempotency_record_key(
+    owner: Address,
+    key: BytesN<32>,
+) -> (DataKey, Address, BytesN<32>) {
+    (DataKey::IdempotencyKey, owner, key)
+}
+
+pub fn lifetime_paid_key(token: Address) -> (DataKey, Address) {
+    (DataKey::LifetimePaid, token)
+}
+
+pub fn operational_counters_key() -> DataKey {
+    DataKey::OperationalCounters
+}
+
+pub fn owner_counts_key() -> DataKey {
+    DataKey::OwnerCounts
+}
+
+pub fn global_stats_key() -> DataKey {
+    DataKey::GlobalStats
+}
+
+pub fn featured_bounties_key() -> DataKey {
+    DataKey::FeaturedBounties
+}
+
+pub fn used_tokens_key() -> DataKey {
+    DataKey::UsedTokens
+}
+
+pub fn event_seq_key() -> DataKey {
+    DataKey::EventSeq
+}
+
+pub fn submission_cooldown_key() -> DataKey {
+    DataKey::SubmissionCooldown
+}
+
+pub fn fee_refund_window_key() -> DataKey {
+    DataKey::FeeRefundWindow
+}
+
+pub fn max_open_per_owner_key() -> DataKey {
+    DataKey::MaxOpenPerOwner
+}
+
+pub fn open_bounty_count_key(owner: Address) -> (DataKey, Address) {
+    (DataKey::OpenBountyCount, owner)
+}
+
+pub fn min_judging_gap_key() -> DataKey {
+    DataKey::MinJudgingGap
+}
+
+pub fn block_owner_apply_key() -> DataKey {
+    DataKey::BlockOwnerApply
+}
+
+pub fn alt_pool_key(token: Address) -> (DataKey, Address) {
+    (DataKey::AltPool, token)
+}
+
+pub fn insurance_bps_key() -> DataKey {
+    DataKey::InsuranceBps
+}
+
+pub fn insurance_pool_key(token: Address) -> (DataKey, Address) {
+    (DataKey::InsurancePool, token)
+}
+
+pub fn emergency_delay_key() -> DataKey {
+    DataKey::EmergencyDelay
+}
+
+pub fn pending_emergency_key() -> DataKey {
+    DataKey::PendingEmergency
+}
+
+pub fn applicant_count_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::ApplicantCount, bounty_id)
+}
+
+pub fn crowdfund_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::Crowdfund, bounty_id)
+}
+
+pub fn series_key(series_id: u64) -> (DataKey, u64) {
+    (DataKey::Series, series_id)
+}
+
+pub fn bounty_series_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::BountySeries, bounty_id)
+}
+
+pub fn next_series_id_key() -> DataKey {
+    DataKey::NextSeriesId
+}
+
+pub fn deadline_extensions_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::DeadlineExtensions, bounty_id)
+}
+
+pub fn sub_bounties_key(parent_id: u64) -> (DataKey, u64) {
+    (DataKey::SubBounties, parent_id)
+}
+
+pub fn allowlist_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::Allowlist, bounty_id)
+}
+
+pub fn acceptance_deadline_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::AcceptanceDeadline, bounty_id)
+}
+
+pub fn project_earnings_key(project_id: u64, contributor: Address) -> (DataKey, u64, Address) {
+    (DataKey::ProjectEarnings, project_id, contributor)
+}
+
+pub fn project_contributors_key(project_id: u64) -> (DataKey, u64) {
+    (DataKey::ProjectContributors, project_id)
+}
+
+pub fn contributor_earnings_key(contributor: Address, token: Address) -> (DataKey, Address, Address) {
+    (DataKey::ContributorEarnings, contributor, token)
+}
+
+pub fn keeper_incentive_key() -> DataKey {
+    DataKey::KeeperIncentive
+}
+
+pub fn milestone_release_fee_key() -> DataKey {
+    DataKey::MilestoneReleaseFee
+}
+
+pub fn bounty_payouts_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::BountyPayouts, bounty_id)
+}
+
+pub fn claimable_reward_key(bounty_id: u64, winner: Address) -> (DataKey, u64, Address) {
+    (DataKey::ClaimableReward, bounty_id, winner)
+}
+
+pub fn proposal_key(bounty_id: u64, applicant: Address) -> (DataKey, u64, Address) {
+    (DataKey::Proposal, bounty_id, applicant)
+}
+
+pub fn submission_history_key(bounty_id: u64, applicant: Address) -> (DataKey, u64, Address) {
+    (DataKey::SubmissionHistory, bounty_id, applicant)
+}
+
+pub fn bounty_paused_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::BountyPaused, bounty_id)
+}
+
+pub fn claim_window_key() -> DataKey {
+    DataKey::ClaimWindow
+}
+
+pub fn claims_opened_at_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::ClaimsOpenedAt, bounty_id)
+}
+
+pub fn fee_refundable_key(bounty_id: u64) -> (DataKey, u64) {
+    (DataKey::FeeRefundable, bounty_id)
+}
+
+pub fn default_token_key() -> DataKey {
+    DataKey::DefaultToken
+}
+
+pub fn owner_create_cooldown_key() -> DataKey {
+    DataKey::OwnerCreateCooldown
+}
+
+pub fn last_creation_key(owner: Address) -> (DataKey, Address) {
+    (DataKey::LastCreation, owner)
+}
+
+pub fn last_submission_key(bounty_id: u64, applicant: Address) -> (DataKey, u64, Address) {
+    (DataKey::LastSubmission, bounty_id, applicant)
+}
@@ -3,15 +3,26 @@
 extern crate std;
 
 use crate::{
-    Error, StallionContract, StallionContractClient, Status,
+    AllocationMode, BountyOptions, RoundingMode, BountyStats, Counters, DeadlineMode, EmergencyAction, Error, PayoutMode, RemainderRecipient, RewardKind,
+    SelectionReceipt, StallionContract, StallionContractClient, Status, Phase,
     MilestoneData, ProjectStatus, ProjectType, HackathonStatus, HackathonPrize,
-    utils::{self, adjust_for_decimals, get_token_decimals, FeeType},
+    events::EVENT_SCHEMA_VERSION,
+    storage,
+    utils::{
+        self, adjust_for_decimals, get_token_decimals, FeeType, ACCEPTANCE_WINDOW,
+        DEFAULT_EMERGENCY_DELAY, MAX_INSURANCE_BPS, MIN_EMERGENCY_DELAY,
+    },
 };
 use soroban_sdk::{
-    Address, Env, FromVal, IntoVal, String, Symbol, Vec,
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, Ledger},
+    Address, BytesN, Env, FromVal, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec, contract,
+    contractimpl, map, symbol_short,
+    testutils::{
+        Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, IssuerFlags, Ledger,
+        storage::Persistent as _,
+    },
     token::{StellarAssetClient as TokenAdminClient, TokenClient},
     vec,
+    xdr::ScVal,
 };
 
 // ========================================
@@ -21,17 +32,17 @@ use soroban_sdk::{
 fn create_token_contract(e: &'_ Env) -> (TokenClient<'_>, Address) {
     e.mock_all_auths();
 
-    let issuer = Address::generate(&e);
-    let distributor = Address::generate(&e);
+    let issuer = Address::generate(e);
+    let distributor = Address::generate(e);
 
     // Create token contract with 7 decimals (standard for Stellar tokens)
     let sac = e.register_stellar_asset_contract_v2(issuer.clone());
     let token_address = sac.address();
 
     // client for SEP-41 functions
-    let token = TokenClient::new(&e, &token_address);
+    let token = TokenClient::new(e, &token_address);
     // client for Stellar Asset Contract functions
-    let token_sac = TokenAdminClient::new(&e, &token_address);
+    let token_sac = TokenAdminClient::new(e, &token_address);
 
     // note that we're explicitly working with a token that has 7 decimals
     // E.g. to mint 1 TOKEN, we need to use 1*10^7 in the mint function.
@@ -44,6 +55,49 @@ fn create_token_contract(e: &'_ Env) -> (TokenClient<'_>, Address) {
     (token, distributor)
 }
 
+// Minimal SEP-41 style token with configurable decimals, for exercising
+// tokens the Stellar Asset Contract cannot model.
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn __constructor(env: Env, decimals: u32) {
+        env.storage().instance().set(&symbol_short!("decimals"), &decimals);
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("decimals")).unwrap()
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        if from_balance < amount {
+            panic!("insufficient balance");
+        }
+        env.storage().persistent().set(&from, &(from_balance - amount));
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(to_balance + amount));
+    }
+}
+
+fn create_mock_token(e: &'_ Env, decimals: u32) -> (TokenClient<'_>, Address) {
+    let distributor = Address::generate(e);
+    let token_address = e.register(MockToken, (decimals,));
+    MockTokenClient::new(e, &token_address).mint(&distributor, &1_000_000_000);
+    (TokenClient::new(e, &token_address), distributor)
+}
+
 fn setup_test(
     env: &'_ Env,
 ) -> (
@@ -54,18 +108,53 @@ fn setup_test(
     Address,
     Address,
 ) {
-    let (token, distributor) = create_token_contract(&env);
-    let admin = Address::generate(&env);
-    let fee_account = Address::generate(&env);
+    let (token, distributor) = create_token_contract(env);
+    let admin = Address::generate(env);
+    let fee_account = Address::generate(env);
     let contract_id = env.register(StallionContract {}, (admin.clone(), fee_account.clone()));
-    let client = StallionContractClient::new(&env, &contract_id);
+    let client = StallionContractClient::new(env, &contract_id);
     (client, token, distributor, fee_account, admin, contract_id)
 }
 
+// Funds a fresh owner with reward + fee and creates a bounty whose submission
+// window closes in 1000s and judging window in 2000s.
+fn create_test_bounty(
+    env: &Env,
+    client: &StallionContractClient<'_>,
+    token: &TokenClient<'_>,
+    distributor: &Address,
+    reward: i128,
+    distribution: Vec<(u32, u32)>,
+) -> (Address, u64) {
+    let owner = Address::generate(env);
+    let fee = utils::calculate_fee(reward, FeeType::Bounty);
+    token.transfer(distributor, &owner, &adjust_for_decimals(reward + fee, 7));
+
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &reward,
+        &distribution,
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(env, "Test bounty"),
+        &None,
+        &None,
+        &None,
+    );
+    (owner, bounty_id)
+}
+
 // ========================================
 // EVENT VERIFICATION HELPERS
 // ========================================
 
+// Event data is published as `(seq, data)`; returns the `data` part.
+fn event_data(env: &Env, data: &Val) -> Val {
+    let (_seq, data): (u64, Val) = FromVal::from_val(env, data);
+    data
+}
+
 fn verify_bounty_created_event(env: &Env, contract_id: &Address, bounty_id: &u64) {
     let event = env
         .events()
@@ -79,7 +168,7 @@ fn verify_bounty_created_event(env: &Env, contract_id: &Address, bounty_id: &u64
         Symbol::from_val(env, &event.1.get_unchecked(0)),
         Symbol::new(env, "bounty_created")
     );
-    assert_eq!(u64::from_val(env, &event.2), *bounty_id);
+    assert_eq!(u64::from_val(env, &event_data(env, &event.2)), *bounty_id);
 }
 
 fn verify_bounty_updated_event(
@@ -101,8 +190,8 @@ fn verify_bounty_updated_event(
         Symbol::new(env, "bounty_updated")
     );
     assert_eq!(
-        Vec::from_val(env, &event.2),
-        vec![env, (bounty_id.clone(), updated_fields.clone())]
+        Vec::from_val(env, &event_data(env, &event.2)),
+        vec![env, (*bounty_id, updated_fields.clone())]
     );
 }
 
@@ -119,7 +208,7 @@ fn verify_bounty_deleted_event(env: &Env, contract_id: &Address, bounty_id: &u64
         Symbol::from_val(env, &event.1.get_unchecked(0)),
         Symbol::new(env, "bounty_deleted")
     );
-    assert_eq!(u64::from_val(env, &event.2), *bounty_id);
+    assert_eq!(u64::from_val(env, &event_data(env, &event.2)), *bounty_id);
 }
 
 fn verify_bounty_closed_event(env: &Env, contract_id: &Address, bounty_id: &u64) {
@@ -135,7 +224,7 @@ fn verify_bounty_closed_event(env: &Env, contract_id: &Address, bounty_id: &u64)
         Symbol::from_val(env, &event.1.get_unchecked(0)),
         Symbol::new(env, "bounty_closed")
     );
-    assert_eq!(u64::from_val(env, &event.2), *bounty_id);
+    assert_eq!(u64::from_val(env, &event_data(env, &event.2)), *bounty_id);
 }
 
 fn verify_admin_updated_event(env: &Env, contract_id: &Address, admin: &Address) {
@@ -151,7 +240,7 @@ fn verify_admin_updated_event(env: &Env, contract_id: &Address, admin: &Address)
         Symbol::from_val(env, &event.1.get_unchecked(0)),
         Symbol::new(env, "admin_updated")
     );
-    assert_eq!(Address::from_val(env, &event.2), admin.clone());
+    assert_eq!(Address::from_val(env, &event_data(env, &event.2)), admin.clone());
 }
 
 fn verify_fee_account_updated_event(env: &Env, contract_id: &Address, fee_account: &Address) {
@@ -167,7 +256,7 @@ fn verify_fee_account_updated_event(env: &Env, contract_id: &Address, fee_accoun
         Symbol::from_val(env, &event.1.get_unchecked(0)),
         Symbol::new(env, "fee_account_updated")
     );
-    assert_eq!(Address::from_val(env, &event.2), fee_account.clone());
+    assert_eq!(Address::from_val(env, &event_data(env, &event.2)), fee_account.clone());
 }
 
 fn verify_constructor_events(
@@ -185,7 +274,7 @@ fn verify_constructor_events(
         Symbol::from_val(env, &topics.get_unchecked(0)),
         Symbol::new(env, "admin_updated")
     );
-    assert_eq!(Address::from_val(env, &data), admin.clone());
+    assert_eq!(Address::from_val(env, &event_data(env, &data)), admin.clone());
 
     // Verify fee account event
     let (event_contract_id, topics, data) = events.next().unwrap();
@@ -194,7 +283,7 @@ fn verify_constructor_events(
         Symbol::from_val(env, &topics.get_unchecked(0)),
         Symbol::new(env, "fee_account_updated")
     );
-    assert_eq!(Address::from_val(env, &data), fee_account.clone());
+    assert_eq!(Address::from_val(env, &event_data(env, &data)), fee_account.clone());
 }
 
 // ========================================
@@ -208,6 +297,151 @@ fn test_constructor() {
     verify_constructor_events(&env, &contract_id, &admin, &fee_account);
 }
 
+#[test]
+fn test_constructor_emits_schema_version() {
+    let env = Env::default();
+    let (_client, _token, _distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+
+    let event = env
+        .events()
+        .all()
+        .try_last()
+        .expect("No events found")
+        .expect("Failed to get last event");
+
+    assert_eq!(event.0, contract_id.clone());
+    assert_eq!(
+        Symbol::from_val(&env, &event.1.get_unchecked(0)),
+        Symbol::new(&env, "schema_version")
+    );
+    assert_eq!(u32::from_val(&env, &event_data(&env, &event.2)), EVENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_get_config() {
+    let env = Env::default();
+    let (client, _token, _distributor, fee_account, admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let new_fee_account = Address::generate(&env);
+    client.update_fee_account(&new_fee_account);
+    client.set_submission_cooldown(&120);
+    client.set_keeper_incentive(&1);
+    client.set_fee_refund_window(&600);
+    client.set_max_open_per_owner(&5);
+    client.set_min_judging_gap(&3600);
+    client.set_block_owner_apply(&true);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.fee_account, new_fee_account);
+    assert_ne!(config.fee_account, fee_account);
+    assert_eq!(config.bounty_fee_percent, utils::BOUNTY_PLATFORM_FEE_PERCENT);
+    assert_eq!(config.gig_fee_percent, utils::GIG_PLATFORM_FEE_PERCENT);
+    assert_eq!(config.job_fee_percent, utils::JOB_PLATFORM_FEE_PERCENT);
+    assert_eq!(config.hackathon_fee_percent, utils::HACKATHON_PLATFORM_FEE_PERCENT);
+    assert_eq!(config.submission_cooldown, client.get_submission_cooldown());
+    assert_eq!(config.keeper_incentive_percent, client.get_keeper_incentive());
+    assert_eq!(config.fee_refund_window, 600);
+    assert_eq!(config.max_open_per_owner, 5);
+    assert_eq!(config.min_judging_gap, 3600);
+    assert!(config.block_owner_apply);
+    assert_eq!(config.emergency_delay, DEFAULT_EMERGENCY_DELAY);
+    assert_eq!(config.insurance_bps, 0);
+    assert_eq!(config.owner_create_cooldown, 0);
+    assert_eq!(config.default_token, None);
+    assert_eq!(config.claim_window, 0);
+    assert_eq!(config.event_schema_version, EVENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_queries_need_no_signatures() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id = client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+
+    // From here on nothing is signed, as when a client simulates a read
+    env.set_auths(&[]);
+    client.get_config();
+    client.is_admin(&admin);
+    client.get_global_stats();
+    client.get_fee_breakdown();
+    client.get_status_breakdown();
+    client.get_operational_counters();
+    client.check_solvency(&token.address);
+    client.get_insurance_pool(&token.address);
+    client.get_accrued_fees(&token.address);
+    client.get_bounties();
+    client.get_active_bounties();
+    client.get_bounties_by_status(&Status::Active);
+    client.get_owner_bounties(&owner);
+    client.get_user_profile(&applicant);
+    client.get_admin_cleanable();
+    client.peek_next_bounty_id();
+    client.get_bounty(&bounty_id);
+    client.get_bounty_snapshot(&bounty_id);
+    client.get_bounty_stats(&bounty_id);
+    client.get_bounty_phase(&bounty_id);
+    client.get_bounty_applicants(&bounty_id);
+    client.get_submission_full(&bounty_id, &applicant);
+    client.get_submission_history(&bounty_id, &applicant);
+    client.get_winner_count(&bounty_id);
+    client.get_claimable_reward(&bounty_id, &applicant);
+    client.is_bounty_paused(&bounty_id);
+    client.preview_select_winners(&bounty_id, &vec![&env, applicant.clone()], &false);
+    client.preview_owner_remainder(&bounty_id, &1);
+    client.get_project(&project_id);
+    client.get_project_value_progress(&project_id);
+    client.get_next_unpaid_milestone(&project_id);
+    client.get_hackathons();
+
+    // Anything that changes state still needs the right signature
+    assert!(client.try_close_bounty(&owner, &bounty_id).is_err());
+    assert!(client.try_set_insurance_bps(&10).is_err());
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Active);
+    assert_eq!(client.get_insurance_bps(), 0);
+}
+
+#[test]
+fn test_is_admin() {
+    let env = Env::default();
+    let (client, _token, _distributor, _fee_account, admin, _contract_id) = setup_test(&env);
+
+    assert!(client.is_admin(&admin));
+    assert!(!client.is_admin(&Address::generate(&env)));
+}
+
+#[test]
+fn test_event_sequence_numbers() {
+    let env = Env::default();
+    let (client, _token, _distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let seq_of = |data: &Val| {
+        let (seq, _data): (u64, Val) = FromVal::from_val(&env, data);
+        seq
+    };
+
+    // The constructor emits admin_updated, fee_account_updated and schema_version
+    let seqs: std::vec::Vec<u64> = env.events().all().iter().map(|event| seq_of(&event.2)).collect();
+    assert_eq!(seqs, [1, 2, 3]);
+
+    // The counter carries on across invocations
+    client.set_submission_cooldown(&10);
+    assert_eq!(seq_of(&env.events().all().get(0).unwrap().2), 4);
+    client.set_submission_cooldown(&20);
+    assert_eq!(seq_of(&env.events().all().get(0).unwrap().2), 5);
+}
+
 // ========================================
 // BOUNTY CREATION TESTS
 // ========================================
@@ -241,6 +475,9 @@ fn test_bounty_creation() {
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
         &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
     );
     verify_bounty_created_event(&env, &_contract_id, &bounty_id);
     let bounty = client.get_bounty(&bounty_id);
@@ -257,6 +494,9 @@ fn test_bounty_creation() {
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
         &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
     );
     assert!(result.is_err());
 
@@ -269,215 +509,3159 @@ fn test_bounty_creation() {
         &(env.ledger().timestamp() + 2000),
         &(env.ledger().timestamp() + 1000), // Judging before submission
         &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
     );
     assert!(result.is_err());
 }
 
-// ========================================
-// BOUNTY SUBMISSION TESTS
-// ========================================
-
 #[test]
-fn test_bounty_submissions() {
+fn test_token_decimals_override() {
     let env = Env::default();
-    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    let (client, _token, _distributor, _fee_account, _admin, contract_id) = setup_test(&env);
     env.mock_all_auths();
 
-    let user_friendly_amount = 1000; // Original token amount for contract input
-    let fee = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
-    let total_needed = user_friendly_amount + fee; // Need reward + fee
-    let token_amount = adjust_for_decimals(
-        total_needed,
-        get_token_decimals(&env, &token.address),
-    );
+    // The token reports 0 decimals but is treated as having 2
+    let (token, distributor) = create_mock_token(&env, 0);
+    client.set_token_decimals(&token.address, &Some(2));
+    assert_eq!(client.get_token_decimals_override(&token.address), Some(2));
 
     let owner = Address::generate(&env);
-    let applicant = Address::generate(&env);
-    token.transfer(&distributor, &owner, &token_amount);
-
-    // Create bounty
+    token.transfer(&distributor, &owner, &1050);
     let bounty_id = client.create_bounty(
         &owner,
         &token.address,
-        &user_friendly_amount,
+        &10,
         &vec![&env, (1, 100)],
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
-        &String::from_str(&env, "Test bounty"),
-    );
-
-    // Test valid submission
-    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link1"));
-
-    // Test submission update
-    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link2"));
-
-    let submissions = client.get_bounty_submissions(&bounty_id);
-    assert_eq!(
-        submissions.get(applicant.clone()).unwrap(),
-        String::from_str(&env, "link2")
+        &String::from_str(&env, "Override bounty"),
+        &None,
+        &None,
+        &None,
     );
+    assert_eq!(client.get_bounty(&bounty_id).reward, 1000);
+    assert_eq!(token.balance(&contract_id), 1000);
 
-    // Test submission after deadline
-    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
-    let result =
-        client.try_apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link3"));
-    assert!(result.is_err());
+    client.set_token_decimals(&token.address, &None);
+    assert_eq!(client.get_token_decimals_override(&token.address), None);
 }
 
-// ========================================
-// WINNER SELECTION & DISTRIBUTION TESTS
-// ========================================
-
 #[test]
-fn test_winner_selection() {
+fn test_create_bounty_rejects_absurd_token_decimals() {
     let env = Env::default();
-    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    let (client, _token, _distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
     env.mock_all_auths();
 
-    let user_friendly_amount = 1000; // Original token amount for contract input
-    let fee = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
-    let total_needed = user_friendly_amount + fee; // Need reward + fee
-    let token_amount = adjust_for_decimals(
-        total_needed,
-        get_token_decimals(&env, &token.address),
-    );
-
+    let (token, _distributor) = create_mock_token(&env, 30);
     let owner = Address::generate(&env);
-    let applicant1 = Address::generate(&env);
-    let applicant2 = Address::generate(&env);
-    token.transfer(&distributor, &owner, &token_amount);
-
-    // Create bounty with two winners
-    let bounty_id = client.create_bounty(
+    let result = client.try_create_bounty(
         &owner,
         &token.address,
-        &user_friendly_amount,
-        &vec![&env, (1, 60), (2, 40)],
+        &10,
+        &vec![&env, (1, 100)],
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
-        &String::from_str(&env, "Test bounty"),
+        &String::from_str(&env, "Absurd decimals"),
+        &None,
+        &None,
+        &None,
     );
+    assert_eq!(result, Err(Ok(Error::UnsupportedTokenDecimals)));
 
-    // Submit applications
-    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
-    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
-
-    // Move past submission deadline
-    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let result = client.try_set_token_decimals(&token.address, &Some(utils::MAX_DECIMALS + 1));
+    assert_eq!(result, Err(Ok(Error::UnsupportedTokenDecimals)));
+}
 
-    // Test winner selection
-    let winners = vec![&env, applicant1.clone(), applicant2.clone()];
-    client.select_winners(&owner, &bounty_id, &winners);
+#[test]
+fn test_create_bounty_rejects_non_token_address() {
+    let env = Env::default();
+    let (client, _token, _distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
 
-    let bounty = client.get_bounty(&bounty_id);
-    assert_eq!(bounty.status, Status::Completed);
+    let owner = Address::generate(&env);
+    let result = client.try_create_bounty(
+        &owner,
+        &Address::generate(&env),
+        &10,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Not a token"),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidToken)));
+}
 
-    // Fee is paid upfront, so winners split the full reward amount
-    let reward_amount = adjust_for_decimals(user_friendly_amount, get_token_decimals(&env, &token.address));
-    let applicant1_reward = (reward_amount * 60) / 100;
-    let applicant2_reward = (reward_amount * 40) / 100;
-    let platform_fee = adjust_for_decimals(fee, get_token_decimals(&env, &token.address));
+#[test]
+fn test_create_bounty_default_uses_default_token() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
 
-    // Verify token distribution - fee was paid upfront in create_bounty
-    assert_eq!(token.balance(&applicant1), applicant1_reward); // 60% of full reward
-    assert_eq!(token.balance(&applicant2), applicant2_reward); // 40% of full reward
-    assert_eq!(token.balance(&fee_account), platform_fee); // Fee paid upfront
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let create = || {
+        client.try_create_bounty_default(
+            &owner,
+            &1000,
+            &vec![&env, (1, 100)],
+            &(env.ledger().timestamp() + 1000),
+            &(env.ledger().timestamp() + 2000),
+            &String::from_str(&env, "Default token"),
+            &None,
+            &None,
+            &None,
+        )
+    };
+    assert_eq!(create(), Err(Ok(Error::InvalidToken)));
+
+    client.set_default_token(&Some(token.address.clone()));
+    assert_eq!(client.get_default_token(), Some(token.address.clone()));
+    let bounty_id = create().unwrap().unwrap();
+    assert_eq!(client.get_bounty(&bounty_id).token, token.address);
+    assert_eq!(token.balance(&owner), 0);
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+
+    client.set_default_token(&None);
+    assert_eq!(client.get_default_token(), None);
 }
 
-// ========================================
-// AUTO-DISTRIBUTION TESTS
-// ========================================
-
 #[test]
-fn test_auto_distribution() {
+fn test_fee_cap_binds_on_large_bounty() {
     let env = Env::default();
     let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
     env.mock_all_auths();
 
-    let user_friendly_amount = 1000; // Original token amount for contract input
-    let fee = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
-    let total_needed = user_friendly_amount + fee; // Need reward + fee
-    let token_amount = adjust_for_decimals(
-        total_needed,
-        get_token_decimals(&env, &token.address),
-    );
+    // Cap the fee at 10 tokens; 5% of 1000 would be 50
+    client.set_max_fee_absolute(&token.address, &Some(10));
+    assert_eq!(client.get_max_fee_absolute(&token.address), Some(10));
 
     let owner = Address::generate(&env);
-    let applicant1 = Address::generate(&env);
-    let applicant2 = Address::generate(&env);
-    token.transfer(&distributor, &owner, &token_amount);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1010, 7));
 
-    // Create bounty
     let bounty_id = client.create_bounty(
         &owner,
         &token.address,
-        &user_friendly_amount,
+        &1000,
         &vec![&env, (1, 100)],
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
-        &String::from_str(&env, "Test bounty"),
+        &String::from_str(&env, "Large bounty"),
+        &None,
+        &None,
+        &None,
     );
 
-    // Submit applications
-    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
-    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.fee, adjust_for_decimals(10, 7));
+    assert_eq!(token.balance(&fee_account), adjust_for_decimals(10, 7));
+    assert_eq!(token.balance(&owner), 0);
+}
 
-    // Move past judging deadline
-    env.ledger().set_timestamp(env.ledger().timestamp() + 2001);
+#[test]
+fn test_bounty_records_fee_rate_at_creation() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
 
-    // Trigger auto-distribution
-    client.check_judging(&bounty_id);
+    let (_owner, uncapped) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
 
-    // Verify token distribution - fee was paid upfront, reward split equally
-    let reward_amount = adjust_for_decimals(user_friendly_amount, get_token_decimals(&env, &token.address));
-    let reward_per_applicant = reward_amount / 2; // Full reward divided equally between both applicants
-    let platform_fee = adjust_for_decimals(fee, get_token_decimals(&env, &token.address));
+    // With a 10 token cap a 1000 token bounty pays 1% instead of 5%
+    client.set_max_fee_absolute(&token.address, &Some(10));
+    let (_owner, capped) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
 
-    assert_eq!(token.balance(&applicant1), reward_per_applicant);
-    assert_eq!(token.balance(&applicant2), reward_per_applicant);
-    assert_eq!(token.balance(&fee_account), platform_fee); // Fee paid upfront
+    assert_eq!(client.get_bounty_fee_rate(&uncapped), 500);
+    assert_eq!(client.get_bounty_fee_rate(&capped), 100);
+    assert_eq!(client.get_bounty(&capped).fee_bps_at_creation, 100);
+    assert_eq!(
+        client.try_get_bounty_fee_rate(&999),
+        Err(Ok(Error::BountyNotFound))
+    );
 }
 
-// ========================================
-// BOUNTY QUERY & GETTER TESTS
-// ========================================
-
 #[test]
-fn test_get_active_bounties() {
+fn test_distribution_rank_cap() {
     let env = Env::default();
     let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
     env.mock_all_auths();
 
-    let user_friendly_amount = 1000; // Amount per bounty in user-friendly format
-    let fee_per_bounty = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
-    let total_per_bounty = user_friendly_amount + fee_per_bounty;
-    // Need enough for 3 bounties (reward + fee each)
-    let token_amount = adjust_for_decimals(total_per_bounty * 3, get_token_decimals(&env, &token.address));
-
-    let owner = Address::generate(&env);
-    token.transfer(&distributor, &owner, &token_amount);
-
-    // Create first active bounty
-    let bounty1_id = client.create_bounty(
+    // 50 ranks of 2% sit exactly at the cap
+    let mut at_cap = Vec::new(&env);
+    for rank in 1..=utils::MAX_DISTRIBUTION_RANKS {
+        at_cap.push_back((rank, 2));
+    }
+    let (owner, bounty_id) = create_test_bounty(&env, &client, &token, &distributor, 1000, at_cap);
+    assert_eq!(client.get_bounty(&bounty_id).distribution.len(), utils::MAX_DISTRIBUTION_RANKS);
+
+    // One more rank (49 x 2% + 2 x 1%) is rejected on create and update
+    let mut over_cap = Vec::new(&env);
+    for rank in 1..=utils::MAX_DISTRIBUTION_RANKS + 1 {
+        over_cap.push_back((rank, if rank > 49 { 1 } else { 2 }));
+    }
+    let result = client.try_create_bounty(
         &owner,
         &token.address,
-        &user_friendly_amount,
-        &vec![&env, (1, 100)],
+        &10,
+        &over_cap,
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
-        &String::from_str(&env, "First bounty"),
+        &String::from_str(&env, "Too many ranks"),
+        &None,
+        &None,
+        &None,
     );
+    assert_eq!(result, Err(Ok(Error::TooManyItems)));
+    let result = client.try_update_bounty(&owner, &bounty_id, &None, &over_cap, &None, &None);
+    assert_eq!(result, Err(Ok(Error::TooManyItems)));
+}
 
-    // Create second active bounty
-    let bounty2_id = client.create_bounty(
-        &owner,
-        &token.address,
-        &user_friendly_amount,
-        &vec![&env, (1, 100)],
-        &(env.ledger().timestamp() + 1000),
-        &(env.ledger().timestamp() + 2000),
+#[test]
+fn test_get_bounty_distribution_bps() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (2, 40), (1, 60)]);
+
+    assert_eq!(
+        client.get_bounty_distribution_bps(&bounty_id),
+        vec![&env, (1, 6000), (2, 4000)]
+    );
+    assert_eq!(
+        client.try_get_bounty_distribution_bps(&999),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_fee_accrues_when_fee_account_cannot_receive() {
+    let env = Env::default();
+    let (client, _token, _distributor, fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // A revocable asset whose issuer freezes the fee account's balance
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = TokenClient::new(&env, &sac.address());
+    let token_sac = TokenAdminClient::new(&env, &sac.address());
+    let distributor = Address::generate(&env);
+    token_sac.mint(&distributor, &adjust_for_decimals(10_000, 7));
+    token_sac.set_authorized(&fee_account, &false);
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let fee = adjust_for_decimals(50, 7);
+    assert_eq!(client.get_bounty(&bounty_id).fee, fee);
+    assert_eq!(token.balance(&fee_account), 0);
+    assert_eq!(client.get_accrued_fees(&token.address), fee);
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7) + fee);
+
+    // Once unfrozen, the fee account pulls what was held for it
+    token_sac.set_authorized(&fee_account, &true);
+    assert_eq!(client.claim_accrued_fees(&token.address), fee);
+    assert_eq!(token.balance(&fee_account), fee);
+    assert_eq!(client.get_accrued_fees(&token.address), 0);
+    assert_eq!(
+        client.try_claim_accrued_fees(&token.address),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_fee_cap_above_percentage_fee() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    client.set_max_fee_absolute(&token.address, &Some(10));
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(105, 7));
+
+    // 5% of 100 is 5, below the cap
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &100,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Small bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.fee, adjust_for_decimals(5, 7));
+    assert_eq!(token.balance(&fee_account), adjust_for_decimals(5, 7));
+
+    // Removing the cap restores the plain percentage and negative caps are rejected
+    client.set_max_fee_absolute(&token.address, &None);
+    assert_eq!(client.get_max_fee_absolute(&token.address), None);
+    let result = client.try_set_max_fee_absolute(&token.address, &Some(-1));
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_create_bounty_with_fee_payer() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1000, 7));
+    token.transfer(&distributor, &sponsor, &adjust_for_decimals(100, 7));
+
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Sponsored bounty"),
+        &Some(sponsor.clone()),
+        &None,
+        &None,
+    );
+
+    // Both the owner and the fee payer authorized the call
+    let auths = env.auths();
+    assert!(auths.iter().any(|(addr, _)| *addr == owner));
+    assert!(auths.iter().any(|(addr, _)| *addr == sponsor));
+
+    // Owner pays only the reward, the sponsor only the 5% fee
+    assert_eq!(token.balance(&owner), 0);
+    assert_eq!(token.balance(&sponsor), adjust_for_decimals(50, 7));
+    assert_eq!(token.balance(&fee_account), adjust_for_decimals(50, 7));
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+    assert_eq!(client.get_bounty(&bounty_id).fee, adjust_for_decimals(50, 7));
+
+}
+
+#[test]
+fn test_create_bounty_even() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+
+    let bounty_id = client.create_bounty_even(
+        &owner,
+        &token.address,
+        &1000,
+        &3,
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Even bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    // 100 / 3 leaves 1% over, which goes to first place
+    let distribution = client.get_bounty(&bounty_id).distribution;
+    assert_eq!(distribution.get(1), Some(34));
+    assert_eq!(distribution.get(2), Some(33));
+    assert_eq!(distribution.get(3), Some(33));
+    assert_eq!(distribution.values().iter().sum::<u32>(), 100);
+
+    let result = client.try_create_bounty_even(
+        &owner,
+        &token.address,
+        &1000,
+        &0,
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "No winners"),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::DistributionMustSumTo100)));
+}
+
+#[test]
+fn test_max_open_per_owner() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    client.set_max_open_per_owner(&2);
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(40, 7));
+    let create = || {
+        client.try_create_bounty(
+            &owner,
+            &token.address,
+            &10,
+            &vec![&env, (1, 100)],
+            &(env.ledger().timestamp() + 1000),
+            &(env.ledger().timestamp() + 2000),
+            &String::from_str(&env, "Limited bounty"),
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    let first = create().unwrap().unwrap();
+    create().unwrap().unwrap();
+    assert_eq!(create(), Err(Ok(Error::OwnerBountyLimitReached)));
+
+    // Closing one frees a slot
+    client.close_bounty(&owner, &first);
+    create().unwrap().unwrap();
+    assert_eq!(create(), Err(Ok(Error::OwnerBountyLimitReached)));
+
+    client.set_max_open_per_owner(&0);
+    create().unwrap().unwrap();
+}
+
+#[test]
+fn test_create_bounty_idempotency_key() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(2100, 7));
+    let key = BytesN::from_array(&env, &[7; 32]);
+
+    let first = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &Some(key.clone()),
+        &None,
+    );
+    // A retried call with the same key is a no-op returning the same id
+    let retried = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &Some(key.clone()),
+        &None,
+    );
+
+    assert_eq!(first, retried);
+    assert_eq!(client.get_bounties_count(), 1);
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1050, 7));
+
+    // A different key creates a new bounty
+    let other = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &Some(BytesN::from_array(&env, &[8; 32])),
+        &None,
+    );
+    assert_ne!(first, other);
+    assert_eq!(client.get_bounties_count(), 2);
+}
+
+fn create_test_crowdfund(
+    env: &Env,
+    client: &StallionContractClient<'_>,
+    token: &TokenClient<'_>,
+    distributor: &Address,
+    goal: i128,
+) -> (u64, u64) {
+    let owner = Address::generate(env);
+    token.transfer(distributor, &owner, &adjust_for_decimals(utils::calculate_fee(goal, FeeType::Bounty), 7));
+
+    let funding_deadline = env.ledger().timestamp() + 500;
+    let bounty_id = client.create_crowdfunded_bounty(
+        &owner,
+        &token.address,
+        &goal,
+        &vec![env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(env, "Crowdfunded"),
+        &funding_deadline,
+        &None,
+    );
+    (bounty_id, funding_deadline)
+}
+
+#[test]
+fn test_crowdfunded_bounty_reaches_goal() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (bounty_id, _) = create_test_crowdfund(&env, &client, &token, &distributor, 1000);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Funding);
+    assert_eq!(client.get_bounty(&bounty_id).reward, 0);
+    assert_eq!(client.get_crowdfund(&bounty_id).goal, adjust_for_decimals(1000, 7));
+
+    // Nobody can apply before the reward is raised
+    let applicant = Address::generate(&env);
+    let result = client.try_apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+
+    let sponsor1 = Address::generate(&env);
+    let sponsor2 = Address::generate(&env);
+    token.transfer(&distributor, &sponsor1, &adjust_for_decimals(600, 7));
+    token.transfer(&distributor, &sponsor2, &adjust_for_decimals(600, 7));
+
+    assert_eq!(client.contribute(&sponsor1, &bounty_id, &600), adjust_for_decimals(600, 7));
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Funding);
+
+    // Only what is still missing from the goal is taken
+    assert_eq!(client.contribute(&sponsor2, &bounty_id, &600), adjust_for_decimals(400, 7));
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Active);
+    assert_eq!(token.balance(&sponsor2), adjust_for_decimals(200, 7));
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+    assert_eq!(client.get_bounty(&bounty_id).reward, adjust_for_decimals(1000, 7));
+
+    let contributions = client.get_crowdfund(&bounty_id).contributions;
+    assert_eq!(contributions.get(sponsor1.clone()), Some(adjust_for_decimals(600, 7)));
+    assert_eq!(contributions.get(sponsor2.clone()), Some(adjust_for_decimals(400, 7)));
+
+    let result = client.try_contribute(&sponsor2, &bounty_id, &100);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+    let result = client.try_refund_contributors(&bounty_id);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+
+    // From here on it is an ordinary bounty
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&client.get_bounty_owner(&bounty_id), &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    assert_eq!(token.balance(&applicant), adjust_for_decimals(1000, 7));
+}
+
+#[test]
+fn test_crowdfunded_bounty_refunds_sponsors() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (bounty_id, funding_deadline) = create_test_crowdfund(&env, &client, &token, &distributor, 1000);
+    let owner = client.get_bounty_owner(&bounty_id);
+    assert_eq!(client.get_global_stats().active_bounties, 1);
+
+    let sponsor1 = Address::generate(&env);
+    let sponsor2 = Address::generate(&env);
+    token.transfer(&distributor, &sponsor1, &adjust_for_decimals(300, 7));
+    token.transfer(&distributor, &sponsor2, &adjust_for_decimals(200, 7));
+    client.contribute(&sponsor1, &bounty_id, &300);
+    client.contribute(&sponsor2, &bounty_id, &200);
+
+    // The owner cannot walk away with sponsor funds
+    let result = client.try_delete_bounty(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+    let result = client.try_close_bounty(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+
+    let result = client.try_refund_contributors(&bounty_id);
+    assert_eq!(result, Err(Ok(Error::TooEarly)));
+
+    env.ledger().set_timestamp(funding_deadline + 1);
+    let result = client.try_contribute(&sponsor1, &bounty_id, &100);
+    assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+
+    client.refund_contributors(&bounty_id);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Closed);
+    assert_eq!(token.balance(&sponsor1), adjust_for_decimals(300, 7));
+    assert_eq!(token.balance(&sponsor2), adjust_for_decimals(200, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_global_stats().active_bounties, 0);
+}
+
+fn create_test_series(
+    env: &Env,
+    client: &StallionContractClient<'_>,
+    token: &TokenClient<'_>,
+    distributor: &Address,
+    occurrences: u32,
+    allowance: i128,
+) -> (Address, u64) {
+    let owner = Address::generate(env);
+    token.transfer(distributor, &owner, &adjust_for_decimals(1050 * occurrences as i128, 7));
+    token.approve(&owner, &client.address, &allowance, &(env.ledger().sequence() + 1000));
+
+    let series_id = client.create_recurring_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(env, "Weekly"),
+        &5000,
+        &occurrences,
+        &None,
+    );
+    (owner, series_id)
+}
+
+#[test]
+fn test_recurring_bounty_spawns_next_occurrence() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, series_id) =
+        create_test_series(&env, &client, &token, &distributor, 2, adjust_for_decimals(1050, 7));
+    let first = client.get_series_bounties(&series_id).get(0).unwrap();
+    assert_eq!(client.get_bounty_series(&first), Some(series_id));
+    let first_bounty = client.get_bounty(&first);
+
+    let winner = Address::generate(&env);
+    client.apply_to_bounty(&winner, &first, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &first, &vec![&env, winner.clone()], &None, &false);
+
+    // Completing the first occurrence escrows the second from the allowance
+    let bounties = client.get_series_bounties(&series_id);
+    assert_eq!(bounties.len(), 2);
+    let second = bounties.get(1).unwrap();
+    let second_bounty = client.get_bounty(&second);
+    assert_eq!(second_bounty.status, Status::Active);
+    assert_eq!(second_bounty.reward, adjust_for_decimals(1000, 7));
+    assert_eq!(second_bounty.submission_deadline, first_bounty.submission_deadline + 5000);
+    assert_eq!(second_bounty.judging_deadline, first_bounty.judging_deadline + 5000);
+    assert!(second_bounty.applicants.is_empty());
+    assert_eq!(client.get_bounty_series(&second), Some(series_id));
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&owner), 0);
+
+    // The last occurrence does not spawn another
+    client.apply_to_bounty(&winner, &second, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(second_bounty.submission_deadline + 1);
+    client.select_winners(&owner, &second, &vec![&env, winner.clone()], &None, &false);
+    assert_eq!(client.get_series_bounties(&series_id).len(), 2);
+    assert_eq!(token.balance(&winner), adjust_for_decimals(2000, 7));
+}
+
+#[test]
+fn test_recurring_bounty_ends_without_allowance() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, series_id) = create_test_series(&env, &client, &token, &distributor, 3, 0);
+    let first = client.get_series_bounties(&series_id).get(0).unwrap();
+
+    let winner = Address::generate(&env);
+    client.apply_to_bounty(&winner, &first, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    // Settlement still succeeds; only the series stops
+    client.select_winners(&owner, &first, &vec![&env, winner.clone()], &None, &false);
+    assert_eq!(client.get_bounty_status(&first), Status::Completed);
+    assert_eq!(client.get_series_bounties(&series_id).len(), 1);
+}
+
+#[test]
+fn test_sub_bounties_link_to_parent() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, parent) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1000, 7));
+    let create_child = |title: &str| {
+        client.create_sub_bounty(
+            &owner,
+            &parent,
+            &token.address,
+            &100,
+            &vec![&env, (1, 100)],
+            &(env.ledger().timestamp() + 1000),
+            &(env.ledger().timestamp() + 2000),
+            &String::from_str(&env, title),
+            &None,
+        )
+    };
+    let first = create_child("Design");
+    let second = create_child("Frontend");
+
+    assert_eq!(client.get_sub_bounties(&parent), vec![&env, first, second]);
+    assert_eq!(client.get_bounty(&first).parent_id, Some(parent));
+    assert_eq!(client.get_bounty(&parent).parent_id, None);
+    assert_eq!(client.get_sub_bounties(&first).len(), 0);
+
+    // Deleting a child unlinks it from the parent
+    client.delete_bounty(&owner, &first);
+    assert_eq!(client.get_sub_bounties(&parent), vec![&env, second]);
+
+    // The parent must exist and belong to the same owner
+    let stranger = Address::generate(&env);
+    let result = client.try_create_sub_bounty(
+        &stranger,
+        &parent,
+        &token.address,
+        &100,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Backend"),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::OnlyOwner)));
+    let result = client.try_create_sub_bounty(
+        &owner,
+        &(parent + 100),
+        &token.address,
+        &100,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Backend"),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_estimate_bounty_cost() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (friendly_total, raw_total) = client.estimate_bounty_cost(&token.address, &1000);
+    assert_eq!(friendly_total, 1050);
+    assert_eq!(raw_total, adjust_for_decimals(1050, 7));
+
+    // The estimate matches what create_bounty actually debits
+    let owner = Address::generate(&env);
+    let starting_balance = adjust_for_decimals(2000, 7);
+    token.transfer(&distributor, &owner, &starting_balance);
+    client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(starting_balance - token.balance(&owner), raw_total);
+
+    // Fee caps are reflected in the estimate
+    client.set_max_fee_absolute(&token.address, &Some(10));
+    let (friendly_total, raw_total) = client.estimate_bounty_cost(&token.address, &1000);
+    assert_eq!(friendly_total, 1010);
+    assert_eq!(raw_total, adjust_for_decimals(1010, 7));
+}
+
+// ========================================
+// BOUNTY SUBMISSION TESTS
+// ========================================
+
+#[test]
+fn test_bounty_submissions() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let user_friendly_amount = 1000; // Original token amount for contract input
+    let fee = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
+    let total_needed = user_friendly_amount + fee; // Need reward + fee
+    let token_amount = adjust_for_decimals(
+        total_needed,
+        get_token_decimals(&env, &token.address),
+    );
+
+    let owner = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    token.transfer(&distributor, &owner, &token_amount);
+
+    // Create bounty
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &user_friendly_amount,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    // Test valid submission
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link1"));
+
+    // Test submission update
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link2"));
+
+    let submissions = client.get_bounty_submissions(&bounty_id);
+    assert_eq!(
+        submissions.get(applicant.clone()).unwrap(),
+        String::from_str(&env, "link2")
+    );
+
+    // Test submission after deadline
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let result =
+        client.try_apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link3"));
+    assert!(result.is_err());
+}
+
+// ========================================
+// WINNER SELECTION & DISTRIBUTION TESTS
+// ========================================
+
+#[test]
+fn test_winner_selection() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let user_friendly_amount = 1000; // Original token amount for contract input
+    let fee = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
+    let total_needed = user_friendly_amount + fee; // Need reward + fee
+    let token_amount = adjust_for_decimals(
+        total_needed,
+        get_token_decimals(&env, &token.address),
+    );
+
+    let owner = Address::generate(&env);
+    let applicant1 = Address::generate(&env);
+    let applicant2 = Address::generate(&env);
+    token.transfer(&distributor, &owner, &token_amount);
+
+    // Create bounty with two winners
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &user_friendly_amount,
+        &vec![&env, (1, 60), (2, 40)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    // Submit applications
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
+    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
+
+    // Move past submission deadline
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+
+    // Test winner selection
+    let winners = vec![&env, applicant1.clone(), applicant2.clone()];
+    client.select_winners(&owner, &bounty_id, &winners, &None, &false);
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, Status::Completed);
+
+    // Fee is paid upfront, so winners split the full reward amount
+    let reward_amount = adjust_for_decimals(user_friendly_amount, get_token_decimals(&env, &token.address));
+    let applicant1_reward = (reward_amount * 60) / 100;
+    let applicant2_reward = (reward_amount * 40) / 100;
+    let platform_fee = adjust_for_decimals(fee, get_token_decimals(&env, &token.address));
+
+    // Verify token distribution - fee was paid upfront in create_bounty
+    assert_eq!(token.balance(&applicant1), applicant1_reward); // 60% of full reward
+    assert_eq!(token.balance(&applicant2), applicant2_reward); // 40% of full reward
+    assert_eq!(token.balance(&fee_account), platform_fee); // Fee paid upfront
+}
+
+#[test]
+fn test_apply_with_proposal() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let link = String::from_str(&env, "https://example.com/pr/1");
+    let proposal = String::from_str(&env, "Refactor the parser and add fuzz tests");
+
+    let applicant = Address::generate(&env);
+    client.apply_with_proposal(&applicant, &bounty_id, &link, &proposal);
+    assert_eq!(
+        client.get_submission_full(&bounty_id, &applicant),
+        (link.clone(), proposal)
+    );
+    assert_eq!(client.get_submission(&bounty_id, &applicant), link);
+
+    // Link-only submissions report an empty proposal
+    let other = Address::generate(&env);
+    client.apply_to_bounty(&other, &bounty_id, &link);
+    assert_eq!(
+        client.get_submission_full(&bounty_id, &other),
+        (link.clone(), String::from_str(&env, ""))
+    );
+
+    let long_proposal = String::from_bytes(&env, &[b'a'; utils::MAX_PROPOSAL_LENGTH as usize + 1]);
+    let result = client.try_apply_with_proposal(&other, &bounty_id, &link, &long_proposal);
+    assert_eq!(result, Err(Ok(Error::TooManyItems)));
+}
+
+#[test]
+fn test_apply_to_bounties_batch() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let mut entries = Vec::new(&env);
+    for _ in 0..3 {
+        let (_owner, bounty_id) =
+            create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+        entries.push_back((bounty_id, String::from_str(&env, "https://example.com/pr/1")));
+    }
+
+    let applicant = Address::generate(&env);
+    assert_eq!(client.apply_to_bounties_batch(&applicant, &entries, &false), 3);
+    for (bounty_id, link) in entries.iter() {
+        assert_eq!(client.get_submission(&bounty_id, &applicant), link);
+    }
+
+    // An unknown bounty reverts the batch unless invalid entries are skipped
+    let other = Address::generate(&env);
+    let (first_id, link) = entries.get(0).unwrap();
+    let mixed = vec![&env, (first_id, link.clone()), (first_id + 100, link)];
+    let result = client.try_apply_to_bounties_batch(&other, &mixed, &false);
+    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.apply_to_bounties_batch(&other, &mixed, &true), 1);
+    assert!(client.get_bounty(&first_id).applicants.contains(&other));
+}
+
+#[test]
+fn test_get_applicant_position() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+
+    let applicants = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for applicant in applicants.iter() {
+        client.apply_to_bounty(applicant, &bounty_id, &String::from_str(&env, "link"));
+    }
+    for (i, applicant) in applicants.iter().enumerate() {
+        assert_eq!(client.get_applicant_position(&bounty_id, applicant), i as u32 + 1);
+    }
+
+    let result = client.try_get_applicant_position(&bounty_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::SubmissionNotFound)));
+}
+
+#[test]
+fn test_get_submission_links() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let first = String::from_str(&env, "https://a.example");
+    let second = String::from_str(&env, "https://b.example");
+    let third = String::from_str(&env, "https://c.example");
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &first);
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &second);
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &third);
+
+    assert_eq!(
+        client.get_submission_links(&bounty_id, &0, &10),
+        vec![&env, first, second.clone(), third.clone()]
+    );
+    assert_eq!(
+        client.get_submission_links(&bounty_id, &1, &2),
+        vec![&env, second, third]
+    );
+    assert_eq!(client.get_submission_links(&bounty_id, &3, &10).len(), 0);
+    assert_eq!(
+        client.try_get_submission_links(&999, &0, &10),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_get_bounty_submissions_size_guard() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let link = String::from_str(&env, "link");
+    for _ in 0..utils::MAX_BATCH_SIZE {
+        client.apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+    }
+    assert_eq!(client.get_bounty_submissions(&bounty_id).len(), utils::MAX_BATCH_SIZE);
+
+    // One more and the full map is refused rather than trapping
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+    assert_eq!(
+        client.try_get_bounty_submissions(&bounty_id),
+        Err(Ok(Error::TooManyItems))
+    );
+    let links = client.get_submission_links(&bounty_id, &utils::MAX_BATCH_SIZE, &10);
+    assert_eq!(links, vec![&env, link]);
+}
+
+#[test]
+fn test_get_lifetime_funded() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_lifetime_funded(&token.address), 0);
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let funded = adjust_for_decimals(1000, 7);
+    assert_eq!(client.get_lifetime_funded(&token.address), funded);
+    assert_eq!(token.balance(&contract_id), funded);
+
+    // Paying out drains the locked value but not the lifetime total
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, applicant], &None, &false);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_lifetime_funded(&token.address), funded);
+
+    create_test_bounty(&env, &client, &token, &distributor, 500, vec![&env, (1, 100)]);
+    assert_eq!(
+        client.get_lifetime_funded(&token.address),
+        funded + adjust_for_decimals(500, 7)
+    );
+}
+
+#[test]
+fn test_get_lifetime_paid() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_lifetime_paid(&token.address), 0);
+
+    // Winners selected by the owner
+    let (owner, selected) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 60), (2, 40)]);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.apply_to_bounty(&first, &selected, &String::from_str(&env, "a"));
+    client.apply_to_bounty(&second, &selected, &String::from_str(&env, "b"));
+
+    // Split evenly once judging expires; the rounding dust is not a payout
+    let (_owner, expired) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicants = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    for applicant in applicants.iter() {
+        client.apply_to_bounty(applicant, &expired, &String::from_str(&env, "c"));
+    }
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &selected, &vec![&env, first.clone(), second.clone()], &None, &false);
+    assert_eq!(client.get_lifetime_paid(&token.address), adjust_for_decimals(1000, 7));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1000);
+    client.check_judging(&expired);
+    let share = adjust_for_decimals(1000, 7) / 3;
+    assert_eq!(token.balance(&applicants[0]), share);
+    assert_eq!(client.get_lifetime_paid(&token.address), adjust_for_decimals(1000, 7) + share * 3);
+}
+
+#[test]
+fn test_get_bounty_winners_ranked() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 60), (2, 40)],
+    );
+
+    let applicant1 = Address::generate(&env);
+    let applicant2 = Address::generate(&env);
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
+    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
+
+    assert_eq!(client.get_bounty_winners_ranked(&bounty_id).len(), 0);
+    assert_eq!(client.get_winner_count(&bounty_id), 0);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(
+        &owner,
+        &bounty_id,
+        &vec![&env, applicant2.clone(), applicant1.clone()],
+        &None,
+        &false,
+    );
+
+    assert_eq!(
+        client.get_bounty_winners_ranked(&bounty_id),
+        vec![&env, (1, applicant2), (2, applicant1)]
+    );
+    assert_eq!(client.get_winner_count(&bounty_id), 2);
+
+    let result = client.try_get_bounty_winners_ranked(&999);
+    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_get_winner_count(&999), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_select_winners_receipt() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 60), (2, 40)],
+    );
+
+    // Only one applicant, so the second rank's share returns to the owner
+    let applicant = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let receipt = client.select_winners(&owner, &bounty_id, &vec![&env, applicant.clone(), other], &None, &false);
+
+    let reward = adjust_for_decimals(1000, 7);
+    assert_eq!(receipt.distributed + receipt.returned_to_owner, reward);
+    assert_eq!(receipt.distributed, reward * 60 / 100);
+    assert_eq!(receipt.winners_paid, 1);
+    assert_eq!(token.balance(&applicant), receipt.distributed);
+    assert_eq!(token.balance(&owner), receipt.returned_to_owner);
+}
+
+#[test]
+fn test_select_winners_partial() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 60), (2, 40)],
+    );
+    let first = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &String::from_str(&env, "link"));
+
+    // Only one qualifying winner for two ranks
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let winners = vec![&env, first.clone()];
+    let result = client.try_select_winners(&owner, &bounty_id, &winners, &None, &false);
+    assert_eq!(result, Err(Ok(Error::NotEnoughWinners)));
+    assert_eq!(
+        client.preview_select_winners(&bounty_id, &winners, &true),
+        vec![&env, (first.clone(), 600), (owner.clone(), 400)]
+    );
+
+    let receipt = client.select_winners(&owner, &bounty_id, &winners, &None, &true);
+    assert_eq!(receipt.winners_paid, 1);
+    assert_eq!(token.balance(&first), adjust_for_decimals(600, 7));
+    assert_eq!(token.balance(&owner), adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+}
+
+#[test]
+fn test_select_winners_rejects_duplicate_winners() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 50), (2, 30), (3, 20)],
+    );
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &String::from_str(&env, "link"));
+
+    // The repeat is not adjacent to the first occurrence
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let winners = vec![&env, first.clone(), second.clone(), first.clone()];
+    let result = client.try_select_winners(&owner, &bounty_id, &winners, &None, &false);
+    assert_eq!(result, Err(Ok(Error::NotEnoughWinners)));
+    let result = client.try_preview_select_winners(&bounty_id, &winners, &false);
+    assert_eq!(result, Err(Ok(Error::NotEnoughWinners)));
+    let result = client.try_select_winners_alt(&owner, &bounty_id, &winners, &token.address);
+    assert_eq!(result, Err(Ok(Error::NotEnoughWinners)));
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Judging);
+}
+
+#[test]
+fn test_preview_select_winners() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 50), (2, 30), (3, 20)],
+    );
+    // Two applicants for three ranks: the third rank's share is left over
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let absent = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "link"));
+
+    let winners = vec![&env, first.clone(), second.clone(), absent];
+    let preview = client.preview_select_winners(&bounty_id, &winners, &false);
+    assert_eq!(
+        preview,
+        vec![
+            &env,
+            (first.clone(), 500),
+            (second.clone(), 300),
+            (owner.clone(), 200)
+        ]
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &winners, &None, &false);
+    for (address, amount) in preview.iter() {
+        assert_eq!(token.balance(&address), adjust_for_decimals(amount, 7));
+    }
+
+    let result = client.try_preview_select_winners(&bounty_id, &vec![&env, first], &false);
+    assert_eq!(result, Err(Ok(Error::NotEnoughWinners)));
+}
+
+#[test]
+fn test_preview_owner_remainder() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 50), (2, 30), (3, 20)],
+    );
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "link"));
+
+    assert_eq!(client.preview_owner_remainder(&bounty_id, &1), 500);
+    // Only two applicants, so the third rank stays unfilled either way
+    assert_eq!(client.preview_owner_remainder(&bounty_id, &3), 200);
+    let remainder = client.preview_owner_remainder(&bounty_id, &2);
+    assert_eq!(remainder, 200);
+    assert_eq!(
+        client.try_preview_owner_remainder(&bounty_id, &0),
+        Err(Ok(Error::NotEnoughWinners))
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, first, second], &None, &true);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(remainder, 7));
+}
+
+#[test]
+fn test_min_per_winner_tops_up_from_owner() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let top_up_fee = adjust_for_decimals(5, 6);
+    token.transfer(&distributor, &owner, &(adjust_for_decimals(105 + 10, 7) + top_up_fee));
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &100,
+        &vec![&env, (1, 90), (2, 10)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions { min_per_winner: 20, ..Default::default() }),
+    );
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "a"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "b"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let winners = vec![&env, first.clone(), second.clone()];
+
+    // The preview already shows the floored share
+    assert_eq!(
+        client.preview_select_winners(&bounty_id, &winners, &false),
+        vec![&env, (first.clone(), 90), (second.clone(), 20)]
+    );
+    // The top-up comes from the owner, not the escrow, so nothing is left
+    assert_eq!(client.preview_owner_remainder(&bounty_id, &2), 0);
+    assert_eq!(client.preview_owner_remainder(&bounty_id, &1), 10);
+
+    // Without an allowance the top-up cannot be pulled
+    let result = client.try_select_winners(&owner, &bounty_id, &winners, &None, &false);
+    assert_eq!(result, Err(Ok(Error::InsufficientEscrow)));
+
+    // The top-up carries the platform fee like the original reward did
+    let allowance = adjust_for_decimals(10, 7) + top_up_fee;
+    token.approve(&owner, &contract_id, &allowance, &(env.ledger().sequence() + 100));
+    let receipt = client.select_winners(&owner, &bounty_id, &winners, &None, &false);
+    assert_eq!(receipt.distributed, adjust_for_decimals(110, 7));
+    assert_eq!(receipt.returned_to_owner, 0);
+    assert_eq!(token.balance(&first), adjust_for_decimals(90, 7));
+    assert_eq!(token.balance(&second), adjust_for_decimals(20, 7));
+    assert_eq!(token.balance(&owner), 0);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(token.balance(&fee_account), adjust_for_decimals(5, 7) + top_up_fee);
+    assert_eq!(client.get_bounty(&bounty_id).fee, adjust_for_decimals(5, 7) + top_up_fee);
+}
+
+#[test]
+fn test_submission_cooldown() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(client.get_submission_cooldown(), 0);
+    client.set_submission_cooldown(&60);
+
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "v1"));
+
+    // Updating within the cooldown is rejected
+    env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+    let result =
+        client.try_update_submission(&applicant, &bounty_id, &String::from_str(&env, "v2"));
+    assert_eq!(result, Err(Ok(Error::SubmissionCooldown)));
+
+    // Once the cooldown has elapsed the update goes through
+    env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+    client.update_submission(&applicant, &bounty_id, &String::from_str(&env, "v2"));
+    assert_eq!(
+        client.get_submission(&bounty_id, &applicant),
+        String::from_str(&env, "v2")
+    );
+}
+
+#[test]
+fn test_owner_create_cooldown() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_owner_create_cooldown(), 0);
+    client.set_owner_create_cooldown(&60);
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(3000, 7));
+    let create = || {
+        client.try_create_bounty(
+            &owner,
+            &token.address,
+            &1000,
+            &vec![&env, (1, 100)],
+            &(env.ledger().timestamp() + 1000),
+            &(env.ledger().timestamp() + 2000),
+            &String::from_str(&env, "Test bounty"),
+            &None,
+            &None,
+            &None,
+        )
+    };
+    assert!(create().is_ok());
+
+    // A second creation within the cooldown is rejected
+    env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+    assert_eq!(create(), Err(Ok(Error::CreateCooldown)));
+
+    // Other owners are unaffected
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+    assert!(create().is_ok());
+}
+
+#[test]
+fn test_multi_token_bounty() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+    let (second, second_distributor) = create_token_contract(&env);
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1100, 7));
+    second.transfer(&second_distributor, &owner, &adjust_for_decimals(550, 7));
+
+    let bounty_id = client.create_multi_token_bounty(
+        &owner,
+        &vec![&env, (token.address.clone(), 1000), (second.address.clone(), 500)],
+        &vec![&env, (1, 60), (2, 40)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Two token bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.token, token.address);
+    assert_eq!(bounty.reward, adjust_for_decimals(1000, 7));
+    assert_eq!(
+        bounty.extra_rewards,
+        vec![&env, (second.address.clone(), adjust_for_decimals(500, 7))]
+    );
+    let second_fee = utils::calculate_fee(adjust_for_decimals(500, 7), FeeType::Bounty);
+    assert_eq!(bounty.extra_fees, vec![&env, (second.address.clone(), second_fee, 0)]);
+    assert_eq!(second.balance(&fee_account), second_fee);
+
+    let first = Address::generate(&env);
+    let runner_up = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&runner_up, &bounty_id, &String::from_str(&env, "link"));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, first.clone(), runner_up.clone()], &None, &false);
+
+    assert_eq!(token.balance(&first), adjust_for_decimals(600, 7));
+    assert_eq!(second.balance(&first), adjust_for_decimals(300, 7));
+    assert_eq!(second.balance(&runner_up), adjust_for_decimals(200, 7));
+
+    // Duplicate tokens in the pool are rejected
+    let result = client.try_create_multi_token_bounty(
+        &owner,
+        &vec![&env, (token.address.clone(), 10), (token.address.clone(), 10)],
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Duplicate"),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidReward)));
+}
+
+#[test]
+fn test_select_winners_alt_token() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+    let (alt, alt_distributor) = create_token_contract(&env);
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 60), (2, 40)],
+    );
+    let first = Address::generate(&env);
+    let runner_up = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&runner_up, &bounty_id, &String::from_str(&env, "link"));
+
+    alt.transfer(&alt_distributor, &admin, &adjust_for_decimals(1000, 7));
+    client.fund_alt_pool(&alt.address, &800);
+    assert_eq!(client.get_alt_pool(&alt.address), adjust_for_decimals(800, 7));
+
+    // The pool cannot cover the full reward yet
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let winners = vec![&env, first.clone(), runner_up.clone()];
+    let result = client.try_select_winners_alt(&owner, &bounty_id, &winners, &alt.address);
+    assert_eq!(result, Err(Ok(Error::InsufficientEscrow)));
+
+    client.fund_alt_pool(&alt.address, &200);
+    let receipt = client.select_winners_alt(&owner, &bounty_id, &winners, &alt.address);
+    assert_eq!(receipt.distributed, adjust_for_decimals(1000, 7));
+    assert_eq!(receipt.winners_paid, 2);
+
+    // Winners are paid from the pool, the escrow goes back to the owner
+    assert_eq!(alt.balance(&first), adjust_for_decimals(600, 7));
+    assert_eq!(alt.balance(&runner_up), adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&first), 0);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1000, 7));
+    assert_eq!(client.get_alt_pool(&alt.address), 0);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+
+    let result = client.try_select_winners_alt(&owner, &bounty_id, &winners, &alt.address);
+    assert_eq!(result, Err(Ok(Error::AlreadyDistributed)));
+}
+
+#[test]
+fn test_get_bounty_remaining() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 60), (2, 40)],
+    );
+    let reward = adjust_for_decimals(1000, 7);
+    assert_eq!(client.get_bounty_remaining(&bounty_id), reward);
+
+    // A partial payout recorded against the bounty reduces what is left
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let mut payouts = Map::new(&env);
+        payouts.set(first.clone(), adjust_for_decimals(300, 7));
+        env.storage()
+            .persistent()
+            .set(&storage::bounty_payouts_key(bounty_id), &payouts);
+    });
+    assert_eq!(
+        client.get_bounty_remaining(&bounty_id),
+        reward - adjust_for_decimals(300, 7)
+    );
+
+    // Nothing remains in escrow once the bounty is settled
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, first, second], &None, &false);
+    assert_eq!(client.get_bounty_remaining(&bounty_id), 0);
+}
+
+#[test]
+fn test_claim_mode_payouts() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 60), (2, 40)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Claim bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions { payout: PayoutMode::Claim, ..Default::default() }),
+    );
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "link"));
+
+    // Selection only records the shares
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, first.clone(), second.clone()], &None, &false);
+    assert_eq!(token.balance(&first), 0);
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+    assert_eq!(
+        client.get_claimable_reward(&bounty_id, &first),
+        vec![&env, (token.address.clone(), adjust_for_decimals(600, 7))]
+    );
+
+    client.claim_reward(&first, &bounty_id);
+    client.claim_reward(&second, &bounty_id);
+    assert_eq!(token.balance(&first), adjust_for_decimals(600, 7));
+    assert_eq!(token.balance(&second), adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let result = client.try_claim_reward(&first, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+}
+
+#[test]
+fn test_claim_rewards_batch() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 50), (2, 30), (3, 20)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Claim bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions { payout: PayoutMode::Claim, ..Default::default() }),
+    );
+
+    let winners = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for winner in winners.iter() {
+        client.apply_to_bounty(&winner, &bounty_id, &String::from_str(&env, "link"));
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &winners, &None, &false);
+
+    assert_eq!(client.claim_rewards_batch(&bounty_id, &winners), 3);
+    assert_eq!(token.balance(&winners.get(0).unwrap()), adjust_for_decimals(500, 7));
+    assert_eq!(token.balance(&winners.get(1).unwrap()), adjust_for_decimals(300, 7));
+    assert_eq!(token.balance(&winners.get(2).unwrap()), adjust_for_decimals(200, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+
+    // Everything is claimed, so a second batch pays nobody
+    assert_eq!(client.claim_rewards_batch(&bounty_id, &winners), 0);
+    assert_eq!(token.balance(&winners.get(0).unwrap()), adjust_for_decimals(500, 7));
+}
+
+#[test]
+fn test_reclaim_unclaimed_after_claim_window() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 60), (2, 40)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Claim bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions { payout: PayoutMode::Claim, ..Default::default() }),
+    );
+    let claimer = Address::generate(&env);
+    let idle = Address::generate(&env);
+    client.apply_to_bounty(&claimer, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&idle, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, claimer.clone(), idle.clone()], &None, &false);
+    client.claim_reward(&claimer, &bounty_id);
+
+    // Without a claim window unclaimed shares stay with the winners
+    assert_eq!(client.try_reclaim_unclaimed(&owner, &bounty_id), Err(Ok(Error::TooEarly)));
+    assert_eq!(client.try_set_claim_window(&3600), Err(Ok(Error::InvalidAmount)));
+    client.set_claim_window(&utils::MIN_CLAIM_WINDOW);
+    assert_eq!(client.try_reclaim_unclaimed(&owner, &bounty_id), Err(Ok(Error::TooEarly)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + utils::MIN_CLAIM_WINDOW + 1);
+    assert_eq!(
+        client.try_reclaim_unclaimed(&Address::generate(&env), &bounty_id),
+        Err(Ok(Error::OnlyOwner))
+    );
+    assert_eq!(client.reclaim_unclaimed(&owner, &bounty_id), vec![&env, idle.clone()]);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+    assert!(client.get_claimable_reward(&bounty_id, &idle).is_empty());
+    assert_eq!(client.try_claim_reward(&idle, &bounty_id), Err(Ok(Error::NothingToClaim)));
+    assert_eq!(client.try_reclaim_unclaimed(&owner, &bounty_id), Err(Ok(Error::NothingToClaim)));
+}
+
+#[test]
+fn test_winner_acceptance() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 60), (2, 40)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Acceptance bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions { require_winner_acceptance: true, ..Default::default() }),
+    );
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "link"));
+
+    // Push payout is held back until each winner accepts
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, first.clone(), second.clone()], &None, &false);
+    assert_eq!(token.balance(&first), 0);
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+
+    client.accept_and_claim(&first, &bounty_id);
+    assert_eq!(token.balance(&first), adjust_for_decimals(600, 7));
+
+    // The owner has to wait for the window to close
+    let result = client.try_reclaim_unaccepted(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::TooEarly)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + ACCEPTANCE_WINDOW + 1);
+    let result = client.try_accept_and_claim(&second, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+
+    let unaccepted = client.reclaim_unaccepted(&owner, &bounty_id);
+    assert_eq!(unaccepted, vec![&env, second.clone()]);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&second), 0);
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let result = client.try_reclaim_unaccepted(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+}
+
+#[test]
+fn test_select_winners_with_note() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+
+    // Overlong notes are rejected
+    let long_note = String::from_bytes(&env, &[b'a'; utils::MAX_NOTE_LENGTH as usize + 1]);
+    let result = client.try_select_winners(
+        &owner,
+        &bounty_id,
+        &vec![&env, applicant.clone()],
+        &Some(long_note),
+        &false,
+    );
+    assert_eq!(result, Err(Ok(Error::TooManyItems)));
+
+    let note = String::from_str(&env, "Best documented solution");
+    client.select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &Some(note.clone()), &false);
+
+    let event = env
+        .events()
+        .all()
+        .try_last()
+        .expect("No events found")
+        .expect("Failed to get last event");
+    assert_eq!(event.0, contract_id);
+    assert_eq!(
+        Symbol::from_val(&env, &event.1.get_unchecked(0)),
+        Symbol::new(&env, "winners_selected")
+    );
+    let (event_bounty_id, winners, event_note): (u64, Vec<Address>, Option<String>) =
+        FromVal::from_val(&env, &event_data(&env, &event.2));
+    assert_eq!(event_bounty_id, bounty_id);
+    assert_eq!(winners, vec![&env, applicant]);
+    assert_eq!(event_note, Some(note));
+}
+
+#[test]
+fn test_select_winners_announces_non_winners() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let winner = Address::generate(&env);
+    let mut others = Vec::new(&env);
+    client.apply_to_bounty(&winner, &bounty_id, &String::from_str(&env, "link"));
+    for _ in 0..2 {
+        let applicant = Address::generate(&env);
+        client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+        others.push_back(applicant);
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, winner], &None, &false);
+
+    let not_selected: std::vec::Vec<(u32, Vec<Address>)> = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| {
+            Symbol::from_val(&env, &event.1.get_unchecked(0))
+                == Symbol::new(&env, "applicants_not_selected")
+        })
+        .map(|event| {
+            assert_eq!(u64::from_val(&env, &event.1.get_unchecked(1)), bounty_id);
+            FromVal::from_val(&env, &event_data(&env, &event.2))
+        })
+        .collect();
+    assert_eq!(not_selected, [(0, others)]);
+}
+
+#[test]
+fn test_select_winners_retry_is_rejected() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+
+    client.select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    assert!(client.get_bounty(&bounty_id).distribution_completed);
+
+    // A retry fails without moving funds again
+    let result =
+        client.try_select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    assert_eq!(result, Err(Ok(Error::AlreadyDistributed)));
+    assert_eq!(token.balance(&applicant), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_select_winners_on_closed_bounty_is_rejected() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.close_bounty(&owner, &bounty_id);
+    let owner_balance = token.balance(&owner);
+
+    // Within the judging window, where an open bounty could be settled
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let winner = Address::generate(&env);
+    let result =
+        client.try_select_winners(&owner, &bounty_id, &vec![&env, winner.clone()], &None, &true);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+    assert_eq!(token.balance(&winner), 0);
+    assert_eq!(token.balance(&owner), owner_balance);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Closed);
+}
+
+#[test]
+fn test_judging_status_after_submission_deadline() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let applicant = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    client.apply_to_bounty(&applicant, &bounty_id, &link);
+
+    // Still in the submission phase at the deadline itself
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1000);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Active);
+    let result =
+        client.try_select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    assert_eq!(result, Err(Ok(Error::CannotSelectWinnersBeforeSubmissionDeadline)));
+
+    // Reads report Judging before anything is stored
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Judging);
+    assert_eq!(client.get_bounty(&bounty_id).status, Status::Judging);
+    assert_eq!(client.get_bounties_by_status(&Status::Judging), vec![&env, bounty_id]);
+
+    assert_eq!(client.advance_status(&bounty_id), Status::Judging);
+    let result = client.try_apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+    assert_eq!(result, Err(Ok(Error::BountyDeadlinePassed)));
+
+    client.select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+    assert_eq!(client.advance_status(&bounty_id), Status::Completed);
+}
+
+#[test]
+fn test_advance_status_announces_judging_once() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let judging_started = |env: &Env| -> std::vec::Vec<(u64, Address)> {
+        env.events()
+            .all()
+            .iter()
+            .filter(|event| {
+                Symbol::from_val(env, &event.1.get_unchecked(0))
+                    == Symbol::new(env, "bounty_judging_started")
+            })
+            .map(|event| {
+                (
+                    u64::from_val(env, &event.1.get_unchecked(1)),
+                    Address::from_val(env, &event_data(env, &event.2)),
+                )
+            })
+            .collect()
+    };
+
+    client.advance_status(&bounty_id);
+    assert!(judging_started(&env).is_empty());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.advance_status(&bounty_id);
+    assert_eq!(judging_started(&env), [(bounty_id, owner)]);
+
+    // Already stored as Judging, so a second advance is silent
+    client.advance_status(&bounty_id);
+    assert!(judging_started(&env).is_empty());
+}
+
+#[test]
+fn test_get_bounty_age() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(client.get_bounty_age(&bounty_id), 0);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+    assert_eq!(client.get_bounty_age(&bounty_id), 86_400);
+    assert_eq!(client.try_get_bounty_age(&(bounty_id + 100)), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_get_bounty_phase() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (closed_owner, closed_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.close_bounty(&closed_owner, &closed_id);
+    assert_eq!(client.get_bounty_phase(&closed_id), Phase::Closed);
+
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+
+    // The submission deadline itself still belongs to the submission phase
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1000);
+    assert_eq!(client.get_bounty_phase(&bounty_id), Phase::Submission);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    assert_eq!(client.get_bounty_phase(&bounty_id), Phase::Judging);
+
+    // Still Judging past the judging deadline until someone settles it
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1000);
+    assert_eq!(client.get_bounty_phase(&bounty_id), Phase::Judging);
+    client.check_judging(&bounty_id);
+    assert_eq!(client.get_bounty_phase(&bounty_id), Phase::Settled);
+    assert_eq!(client.try_get_bounty_phase(&(bounty_id + 100)), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_emit_bounty_state() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    let bounty = client.get_bounty(&bounty_id);
+
+    // Past the submission deadline the event reports the effective status
+    env.ledger().set_timestamp(bounty.submission_deadline + 1);
+    client.emit_bounty_state(&bounty_id);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, contract_id);
+    assert_eq!(
+        Symbol::from_val(&env, &event.1.get_unchecked(0)),
+        Symbol::new(&env, "bounty_state")
+    );
+    assert_eq!(u64::from_val(&env, &event.1.get_unchecked(1)), bounty_id);
+    let state: (Address, Address, i128, Status, u64, u64, u32, u32) =
+        FromVal::from_val(&env, &event_data(&env, &event.2));
+    assert_eq!(
+        state,
+        (
+            owner,
+            token.address.clone(),
+            bounty.reward,
+            Status::Judging,
+            bounty.submission_deadline,
+            bounty.judging_deadline,
+            1,
+            0,
+        )
+    );
+    assert_eq!(client.try_emit_bounty_state(&(bounty_id + 100)), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_ledger_sequence_deadlines() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let seq = env.ledger().sequence() as u64;
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(seq + 10),
+        &(seq + 20),
+        &String::from_str(&env, "Sequence bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions { deadline_mode: DeadlineMode::LedgerSeq, ..Default::default() }),
+    );
+    assert_eq!(client.get_bounty(&bounty_id).deadline_mode, DeadlineMode::LedgerSeq);
+
+    // Wall-clock time alone does not close the submission window
+    let applicant = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000_000);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Active);
+    client.apply_to_bounty(&applicant, &bounty_id, &link);
+
+    // Passing the submission deadline in ledgers does
+    env.ledger().set_sequence_number(seq as u32 + 11);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Judging);
+    let result = client.try_apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+    assert_eq!(result, Err(Ok(Error::BountyDeadlinePassed)));
+
+    // And the judging deadline too
+    env.ledger().set_sequence_number(seq as u32 + 21);
+    let result =
+        client.try_select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    assert_eq!(result, Err(Ok(Error::JudgingDeadlinePassed)));
+    client.check_judging(&bounty_id);
+    assert_eq!(token.balance(&applicant), adjust_for_decimals(1000, 7));
+}
+
+#[test]
+fn test_admin_set_bounty_status() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    client.apply_to_bounty(&applicant, &bounty_id, &link);
+
+    // Dangerous: judging early would let winners be picked while
+    // submissions are still open
+    let result = client.try_admin_set_bounty_status(&bounty_id, &Status::Judging);
+    assert_eq!(result, Err(Ok(Error::TooEarly)));
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+
+    // Dangerous: closing a funded bounty would strand its escrow
+    let result = client.try_admin_set_bounty_status(&bounty_id, &Status::Closed);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Active);
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+
+    // Benign: once submissions close the stored status may catch up
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.admin_set_bounty_status(&bounty_id, &Status::Judging);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, contract_id);
+    let data: (Status, Status) = FromVal::from_val(&env, &event_data(&env, &event.2));
+    assert_eq!(data, (Status::Active, Status::Judging));
+    assert_eq!(client.get_bounty(&bounty_id).status, Status::Judging);
+
+    // Dangerous: reopening a paid-out bounty would allow a second payout
+    client.select_winners(&owner, &bounty_id, &vec![&env, applicant], &None, &false);
+    let result = client.try_admin_set_bounty_status(&bounty_id, &Status::Active);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+
+    // Swapping settled states keeps the lifecycle counters in step
+    assert_eq!(client.get_operational_counters().bounties_completed, 1);
+    client.admin_set_bounty_status(&bounty_id, &Status::Closed);
+    let counters = client.get_operational_counters();
+    assert_eq!((counters.bounties_completed, counters.bounties_closed), (0, 1));
+    client.admin_set_bounty_status(&bounty_id, &Status::Completed);
+    let counters = client.get_operational_counters();
+    assert_eq!((counters.bounties_completed, counters.bounties_closed), (1, 0));
+}
+
+#[test]
+fn test_admin_set_bounty_status_leaves_series_alone() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, series_id) =
+        create_test_series(&env, &client, &token, &distributor, 2, adjust_for_decimals(1050, 7));
+    let first = client.get_series_bounties(&series_id).get(0).unwrap();
+    let owner_before = token.balance(&owner);
+
+    // Forcing the occurrence closed must not pull the next one from the
+    // owner's allowance
+    let result = client.try_admin_set_bounty_status(&first, &Status::Closed);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+    assert_eq!(client.get_series_bounties(&series_id).len(), 1);
+    assert_eq!(token.balance(&owner), owner_before);
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+
+    // A status change within the unsettled states leaves counters untouched
+    let stats = client.get_global_stats();
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.admin_set_bounty_status(&first, &Status::Judging);
+    assert_eq!(client.get_global_stats(), stats);
+    assert_eq!(client.get_series_bounties(&series_id).len(), 1);
+}
+
+#[test]
+fn test_emergency_action_timelock() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let owner_before = token.balance(&owner);
+
+    let result = client.try_set_emergency_delay(&(MIN_EMERGENCY_DELAY - 1));
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    client.set_emergency_delay(&MIN_EMERGENCY_DELAY);
+
+    let action = EmergencyAction::RefundBounty(bounty_id);
+    let executable_at = client.request_emergency_action(&action);
+    assert_eq!(executable_at, env.ledger().timestamp() + MIN_EMERGENCY_DELAY);
+    assert_eq!(client.get_pending_emergency_action(), Some((action.clone(), executable_at)));
+
+    // Nothing moves before the delay has passed
+    env.ledger().set_timestamp(executable_at - 1);
+    let result = client.try_execute_emergency_action();
+    assert_eq!(result, Err(Ok(Error::TooEarly)));
+    assert_eq!(token.balance(&owner), owner_before);
+
+    env.ledger().set_timestamp(executable_at);
+    client.execute_emergency_action();
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Closed);
+    assert_eq!(token.balance(&owner), owner_before + adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_pending_emergency_action(), None);
+
+    // Settled targets cannot be requested, and cancelling drops the request
+    let result = client.try_request_emergency_action(&action);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+    let (_, other_bounty) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.request_emergency_action(&EmergencyAction::RefundBounty(other_bounty));
+    client.cancel_emergency_action();
+    let result = client.try_execute_emergency_action();
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+}
+
+#[test]
+fn test_insurance_pool_accrues_fee_share() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let result = client.try_set_insurance_bps(&(MAX_INSURANCE_BPS + 1));
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    client.set_insurance_bps(&500);
+    assert_eq!(client.get_config().insurance_bps, 500);
+
+    let (_, first) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let bounty = client.get_bounty(&first);
+    let insured = bounty.fee * 500 / 10_000;
+    assert!(insured > 0);
+    assert_eq!(bounty.insured_fee, insured);
+    assert_eq!(client.get_insurance_pool(&token.address), 2 * insured);
+    assert_eq!(token.balance(&fee_account), 2 * (bounty.fee - insured));
+    // Fees collected only counts what reached the fee account
+    assert_eq!(
+        client.get_global_stats().fees_collected.get(token.address.clone()),
+        Some(2 * (bounty.fee - insured))
+    );
+    assert_eq!(
+        token.balance(&contract_id),
+        2 * (adjust_for_decimals(1000, 7) + insured)
+    );
+
+    // The pool is only spent through the emergency timelock
+    let recipient = Address::generate(&env);
+    let action = EmergencyAction::PayInsurance(token.address.clone(), recipient.clone(), 3 * insured);
+    let result = client.try_request_emergency_action(&action);
+    assert_eq!(result, Err(Ok(Error::InsufficientEscrow)));
+    let action = EmergencyAction::PayInsurance(token.address.clone(), recipient.clone(), insured);
+    let executable_at = client.request_emergency_action(&action);
+    env.ledger().set_timestamp(executable_at);
+    client.execute_emergency_action();
+    assert_eq!(token.balance(&recipient), insured);
+    assert_eq!(client.get_insurance_pool(&token.address), insured);
+}
+
+#[test]
+fn test_custom_fee_recipient() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let campaign = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(2100, 7));
+    let create = || {
+        client.try_create_bounty(
+            &owner,
+            &token.address,
+            &1000,
+            &vec![&env, (1, 100)],
+            &(env.ledger().timestamp() + 1000),
+            &(env.ledger().timestamp() + 2000),
+            &String::from_str(&env, "Sponsored bounty"),
+            &None,
+            &None,
+            &Some(BountyOptions { fee_recipient: Some(campaign.clone()), ..Default::default() }),
+        )
+    };
+
+    // Only recipients the admin approved can be named
+    assert_eq!(create(), Err(Ok(Error::Unauthorized)));
+    client.set_fee_recipient_approved(&campaign, &true);
+    assert!(client.is_fee_recipient_approved(&campaign));
+
+    let bounty_id = create().unwrap().unwrap();
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.fee_recipient, Some(campaign.clone()));
+    assert_eq!(token.balance(&campaign), bounty.fee);
+    assert_eq!(token.balance(&fee_account), 0);
+
+    client.set_fee_recipient_approved(&campaign, &false);
+    assert_eq!(create(), Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_frozen_fee_recipient_does_not_block_series() {
+    let env = Env::default();
+    let (client, _token, _distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // A revocable asset whose issuer can freeze the recipient's balance
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = TokenClient::new(&env, &sac.address());
+    let token_sac = TokenAdminClient::new(&env, &sac.address());
+    let distributor = Address::generate(&env);
+    token_sac.mint(&distributor, &adjust_for_decimals(10_000, 7));
+
+    let campaign = Address::generate(&env);
+    client.set_fee_recipient_approved(&campaign, &true);
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(2100, 7));
+    token.approve(&owner, &client.address, &adjust_for_decimals(1050, 7), &(env.ledger().sequence() + 1000));
+    let series_id = client.create_recurring_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Weekly"),
+        &5000,
+        &2,
+        &Some(BountyOptions { fee_recipient: Some(campaign.clone()), ..Default::default() }),
+    );
+    let fee = adjust_for_decimals(50, 7);
+    assert_eq!(token.balance(&campaign), fee);
+
+    // The recipient is frozen before the first occurrence settles
+    token_sac.set_authorized(&campaign, &false);
+    let first = client.get_series_bounties(&series_id).get(0).unwrap();
+    let winner = Address::generate(&env);
+    client.apply_to_bounty(&winner, &first, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &first, &vec![&env, winner.clone()], &None, &false);
+
+    // The winner is paid and the next occurrence's fee is held instead
+    assert_eq!(token.balance(&winner), adjust_for_decimals(1000, 7));
+    assert_eq!(client.get_series_bounties(&series_id).len(), 2);
+    assert_eq!(client.get_accrued_fees(&token.address), fee);
+    assert_eq!(token.balance(&fee_account), 0);
+}
+
+#[test]
+fn test_owner_cannot_apply_when_blocked() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    // Allowed by default
+    client.apply_to_bounty(&owner, &bounty_id, &String::from_str(&env, "mine"));
+
+    client.set_block_owner_apply(&true);
+    let result =
+        client.try_apply_to_bounty(&owner, &bounty_id, &String::from_str(&env, "mine again"));
+    assert_eq!(result, Err(Ok(Error::OwnerCannotApply)));
+
+    // Everyone else can still apply
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+}
+
+#[test]
+fn test_invite_only_bounty() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let invited = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Private bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions {
+            allowed_applicants: Some(vec![&env, invited.clone()]),
+            ..Default::default()
+        }),
+    );
+    assert_eq!(client.get_allowed_applicants(&bounty_id), vec![&env, invited.clone()]);
+
+    let link = String::from_str(&env, "link");
+    client.apply_to_bounty(&invited, &bounty_id, &link);
+    let result = client.try_apply_to_bounty(&stranger, &bounty_id, &link);
+    assert_eq!(result, Err(Ok(Error::ApplicantNotAllowed)));
+
+    // The owner can extend the invite list later
+    client.add_allowed_applicant(&owner, &bounty_id, &stranger);
+    client.apply_to_bounty(&stranger, &bounty_id, &link);
+    assert_eq!(client.get_bounty_applicants(&bounty_id).len(), 2);
+
+    // Emptying the list opens the bounty to everyone
+    client.remove_allowed_applicant(&owner, &bounty_id, &invited);
+    client.remove_allowed_applicant(&owner, &bounty_id, &stranger);
+    assert_eq!(client.get_allowed_applicants(&bounty_id).len(), 0);
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+
+    let result = client.try_add_allowed_applicant(&stranger, &bounty_id, &stranger);
+    assert_eq!(result, Err(Ok(Error::OnlyOwner)));
+}
+
+#[test]
+fn test_apply_errors_distinguish_deadline_from_settled() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, completed) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (_owner2, judging) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    client.apply_to_bounty(&applicant, &completed, &link);
+    client.apply_to_bounty(&applicant, &judging, &link);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &completed, &vec![&env, applicant.clone()], &None, &false);
+
+    // Still Active in storage, but past the submission deadline
+    let late = Address::generate(&env);
+    assert_eq!(client.try_apply_to_bounty(&late, &judging, &link), Err(Ok(Error::BountyDeadlinePassed)));
+    assert_eq!(client.try_update_submission(&applicant, &judging, &link), Err(Ok(Error::BountyDeadlinePassed)));
+
+    assert_eq!(client.try_apply_to_bounty(&late, &completed, &link), Err(Ok(Error::InactiveBounty)));
+    assert_eq!(client.try_update_submission(&applicant, &completed, &link), Err(Ok(Error::InactiveBounty)));
+}
+
+#[test]
+fn test_get_bounty_snapshot() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let winner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    client.apply_to_bounty(&winner, &bounty_id, &link);
+    client.apply_to_bounty(&other, &bounty_id, &link);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, winner.clone()], &None, &false);
+
+    let bounty = client.get_bounty(&bounty_id);
+    let snapshot = client.get_bounty_snapshot(&bounty_id);
+    assert_eq!(snapshot.id, bounty_id);
+    assert_eq!(snapshot.owner, bounty.owner);
+    assert_eq!(snapshot.token, bounty.token);
+    assert_eq!(snapshot.title, bounty.title);
+    assert_eq!(snapshot.reward, bounty.reward);
+    assert_eq!(snapshot.fee, bounty.fee);
+    assert_eq!(snapshot.status, client.get_bounty_status(&bounty_id));
+    assert_eq!(snapshot.created_at, bounty.created_at);
+    assert_eq!(snapshot.submission_deadline, bounty.submission_deadline);
+    assert_eq!(snapshot.judging_deadline, bounty.judging_deadline);
+    assert_eq!(snapshot.applicants, client.get_bounty_applicants(&bounty_id));
+    assert_eq!(snapshot.applicant_count, 2);
+    assert_eq!(snapshot.submission_count, 2);
+    assert_eq!(snapshot.winners, client.get_bounty_winners(&bounty_id));
+    assert_eq!(snapshot.payouts.get(winner), Some(adjust_for_decimals(1000, 7)));
+    assert!(snapshot.distribution_completed);
+
+    assert_eq!(client.try_get_bounty_snapshot(&999), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_get_bounty_stats() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 60), (2, 40)],
+    );
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    client.apply_to_bounty(&first, &bounty_id, &link);
+    client.apply_to_bounty(&second, &bounty_id, &link);
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+
+    let stats = client.get_bounty_stats(&bounty_id);
+    assert_eq!(stats.applicant_count, 3);
+    assert_eq!(stats.winner_count, 0);
+    assert_eq!(stats.total_distributed, 0);
+    assert!(!stats.submission_deadline_passed);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, first, second], &None, &false);
+
+    let stats = client.get_bounty_stats(&bounty_id);
+    assert_eq!(
+        stats,
+        BountyStats {
+            applicant_count: 3,
+            winner_count: 2,
+            total_distributed: adjust_for_decimals(1000, 7),
+            submission_deadline_passed: true,
+            judging_deadline_passed: false,
+        }
+    );
+
+    assert_eq!(client.try_get_bounty_stats(&999), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_external_reward_bounty() {
+    let env = Env::default();
+    let (client, token, _distributor, fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // The owner holds no tokens: nothing is escrowed for an external prize
+    let owner = Address::generate(&env);
+    let reference = String::from_str(&env, "ipfs://prize-nft");
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &0,
+        &vec![&env, (1, 60), (2, 40)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "NFT bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions {
+            reward_kind: RewardKind::External(reference.clone()),
+            ..Default::default()
+        }),
+    );
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.reward_kind, RewardKind::External(reference));
+    assert_eq!(bounty.reward, 0);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.apply_to_bounty(&first, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&second, &bounty_id, &String::from_str(&env, "link"));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let receipt = client.select_winners(
+        &owner,
+        &bounty_id,
+        &vec![&env, first.clone(), second.clone()],
+        &None,
+        &false,
+    );
+    assert_eq!(receipt.distributed, 0);
+    assert_eq!(client.get_bounty_winners(&bounty_id), vec![&env, first.clone(), second]);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+    assert_eq!(token.balance(&first), 0);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(token.balance(&fee_account), 0);
+}
+
+#[test]
+fn test_remainder_to_fee_account() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 60), (2, 40)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Community bounty"),
+        &None,
+        &None,
+        &Some(BountyOptions {
+            remainder_recipient: RemainderRecipient::FeeAccount,
+            ..Default::default()
+        }),
+    );
+    let fee_balance = token.balance(&fee_account);
+
+    // A single applicant leaves the second rank unfilled
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let receipt = client.select_winners(
+        &owner,
+        &bounty_id,
+        &vec![&env, applicant.clone(), Address::generate(&env)],
+        &None,
+        &false,
+    );
+
+    assert_eq!(receipt.returned_to_owner, adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&applicant), adjust_for_decimals(600, 7));
+    assert_eq!(token.balance(&fee_account), fee_balance + adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&owner), 0);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_single_winner_takes_full_reward() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+
+    let receipt = client.select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    let reward = adjust_for_decimals(1000, 7);
+    assert_eq!(
+        receipt,
+        SelectionReceipt { distributed: reward, returned_to_owner: 0, winners_paid: 1 }
+    );
+    assert_eq!(token.balance(&applicant), reward);
+    assert_eq!(token.balance(&owner), 0);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_largest_remainder_allocation() {
+    let env = Env::default();
+    let (client, _token, _distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // With a 0-decimal token a reward of 10 split 33/33/34 leaves 1 unit of dust
+    let (token, distributor) = create_mock_token(&env, 0);
+    let distribution = vec![&env, (1, 33), (2, 33), (3, 34)];
+
+    let mut bounty_ids = Vec::new(&env);
+    let mut owners = Vec::new(&env);
+    for allocation in [AllocationMode::Standard, AllocationMode::LargestRemainder] {
+        let owner = Address::generate(&env);
+        token.transfer(&distributor, &owner, &10);
+        let bounty_id = client.create_bounty(
+            &owner,
+            &token.address,
+            &10,
+            &distribution,
+            &(env.ledger().timestamp() + 1000),
+            &(env.ledger().timestamp() + 2000),
+            &String::from_str(&env, "Dusty bounty"),
+            &None,
+            &None,
+            &Some(BountyOptions { allocation, ..Default::default() }),
+        );
+        bounty_ids.push_back(bounty_id);
+        owners.push_back(owner);
+    }
+
+    let applicants = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for bounty_id in bounty_ids.iter() {
+        for applicant in applicants.iter() {
+            client.apply_to_bounty(applicant, &bounty_id, &String::from_str(&env, "link"));
+        }
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let winners = vec![
+        &env,
+        applicants[0].clone(),
+        applicants[1].clone(),
+        applicants[2].clone(),
+    ];
+
+    // Standard mode: 3 + 3 + 3, dust returns to the owner
+    let receipt = client.select_winners(&owners.get(0).unwrap(), &bounty_ids.get(0).unwrap(), &winners, &None, &false);
+    assert_eq!(receipt.distributed, 9);
+    assert_eq!(receipt.returned_to_owner, 1);
+    assert_eq!(token.balance(&owners.get(0).unwrap()), 1);
+
+    // Largest remainder: the top winner takes the dust and nothing returns to the owner
+    let receipt = client.select_winners(&owners.get(1).unwrap(), &bounty_ids.get(1).unwrap(), &winners, &None, &false);
+    assert_eq!(receipt.distributed, 10);
+    assert_eq!(receipt.returned_to_owner, 0);
+    assert_eq!(token.balance(&owners.get(1).unwrap()), 0);
+    assert_eq!(token.balance(&applicants[0]), 3 + 4);
+    assert_eq!(token.balance(&applicants[1]), 3 + 3);
+    assert_eq!(token.balance(&applicants[2]), 3 + 3);
+    assert_eq!(
+        client.get_bounty(&bounty_ids.get(1).unwrap()).allocation,
+        AllocationMode::LargestRemainder
+    );
+}
+
+#[test]
+fn test_rounding_modes() {
+    let env = Env::default();
+    let (client, _token, _distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // With a 0-decimal token a reward of 10 split 35/25/40 gives 3.5, 2.5 and 4
+    let (token, distributor) = create_mock_token(&env, 0);
+    let distribution = vec![&env, (1, 35), (2, 25), (3, 40)];
+    let applicants = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    let mut shares = std::vec::Vec::new();
+    let mut settled = None;
+    for rounding in [RoundingMode::Down, RoundingMode::Up, RoundingMode::Bankers] {
+        let owner = Address::generate(&env);
+        token.transfer(&distributor, &owner, &10);
+        let bounty_id = client.create_bounty(
+            &owner,
+            &token.address,
+            &10,
+            &distribution,
+            &(env.ledger().timestamp() + 1000),
+            &(env.ledger().timestamp() + 2000),
+            &String::from_str(&env, "Rounded bounty"),
+            &None,
+            &None,
+            &Some(BountyOptions { rounding, ..Default::default() }),
+        );
+        for applicant in applicants.iter() {
+            client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+        }
+        let preview = client.preview_select_winners(&bounty_id, &applicants, &false);
+        let amounts: std::vec::Vec<i128> = preview.iter().map(|(_, amount)| amount).collect();
+        shares.push(amounts);
+        if rounding == RoundingMode::Up {
+            settled = Some((owner, bounty_id));
+        }
+    }
+
+    // Down leaves 1 for the owner; Up is capped at the reward on the last
+    // rank; Bankers rounds 3.5 up to 4 and 2.5 down to 2
+    assert_eq!(shares, [std::vec![3, 2, 4, 1], std::vec![4, 3, 3], std::vec![4, 2, 4]]);
+
+    // Rounding up never pays out more than was escrowed
+    let (owner, bounty_id) = settled.unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &applicants, &None, &false);
+    assert_eq!(token.balance(&contract_id), 20);
+}
+
+// ========================================
+// AUTO-DISTRIBUTION TESTS
+// ========================================
+
+#[test]
+fn test_auto_distribution() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let user_friendly_amount = 1000; // Original token amount for contract input
+    let fee = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
+    let total_needed = user_friendly_amount + fee; // Need reward + fee
+    let token_amount = adjust_for_decimals(
+        total_needed,
+        get_token_decimals(&env, &token.address),
+    );
+
+    let owner = Address::generate(&env);
+    let applicant1 = Address::generate(&env);
+    let applicant2 = Address::generate(&env);
+    token.transfer(&distributor, &owner, &token_amount);
+
+    // Create bounty
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &user_friendly_amount,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    // Submit applications
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
+    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
+
+    // Move past judging deadline
+    env.ledger().set_timestamp(env.ledger().timestamp() + 2001);
+
+    // Trigger auto-distribution
+    client.check_judging(&bounty_id);
+
+    // Verify token distribution - fee was paid upfront, reward split equally
+    let reward_amount = adjust_for_decimals(user_friendly_amount, get_token_decimals(&env, &token.address));
+    let reward_per_applicant = reward_amount / 2; // Full reward divided equally between both applicants
+    let platform_fee = adjust_for_decimals(fee, get_token_decimals(&env, &token.address));
+
+    assert_eq!(token.balance(&applicant1), reward_per_applicant);
+    assert_eq!(token.balance(&applicant2), reward_per_applicant);
+    assert_eq!(token.balance(&fee_account), platform_fee); // Fee paid upfront
+}
+
+#[test]
+fn test_auto_distribution_single_applicant() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 60), (2, 40)],
+    );
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 2001);
+    client.check_judging(&bounty_id);
+
+    // The sole applicant takes the whole reward, and the event says so
+    let reward = adjust_for_decimals(1000, 7);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, contract_id);
+    assert_eq!(
+        event.1,
+        (Symbol::new(&env, "auto_distributed"),).into_val(&env)
+    );
+    let data: (u64, Vec<(Address, i128)>) = FromVal::from_val(&env, &event_data(&env, &event.2));
+    assert_eq!(data, (bounty_id, vec![&env, (applicant.clone(), reward)]));
+    assert_eq!(token.balance(&applicant), reward);
+}
+
+#[test]
+fn test_delete_after_auto_complete_does_not_double_refund() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    // A second bounty keeps funds in the contract that must not be touched
+    let (_other_owner, _other_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        500,
+        vec![&env, (1, 100)],
+    );
+
+    // No applicants: judging expiry refunds the owner and settles the bounty
+    env.ledger().set_timestamp(env.ledger().timestamp() + 2001);
+    client.check_judging(&bounty_id);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1000, 7));
+
+    client.delete_bounty(&owner, &bounty_id);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(500, 7));
+    assert_eq!(
+        client.try_get_bounty(&bounty_id),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_delete_after_close_attempts_no_refund() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    client.close_bounty(&owner, &bounty_id);
+    assert_eq!(token.balance(&contract_id), 0);
+
+    // The contract is empty, so any refund transfer here would trap
+    client.delete_bounty(&owner, &bounty_id);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1000, 7));
+}
+
+#[test]
+fn test_reclaim_after_judging() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+
+    let applicant1 = Address::generate(&env);
+    let applicant2 = Address::generate(&env);
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
+    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
+
+    // Not available while judging is still open
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1500);
+    let result = client.try_reclaim_after_judging(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::TooEarly)));
+
+    // Only the owner may reclaim
+    env.ledger().set_timestamp(env.ledger().timestamp() + 501);
+    let result = client.try_reclaim_after_judging(&applicant1, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::OnlyOwner)));
+
+    client.reclaim_after_judging(&owner, &bounty_id);
+    verify_bounty_closed_event(&env, &contract_id, &bounty_id);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Closed);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&applicant1), 0);
+    assert_eq!(token.balance(&applicant2), 0);
+
+    // The equal split no longer applies and a second reclaim is rejected
+    client.check_judging(&bounty_id);
+    assert_eq!(token.balance(&applicant1), 0);
+    let result = client.try_reclaim_after_judging(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+}
+
+#[test]
+fn test_archive_bounty() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    client.update_submission(&applicant, &bounty_id, &String::from_str(&env, "link v2"));
+    assert_eq!(client.get_submission_history(&bounty_id, &applicant).len(), 2);
+
+    // Active bounties cannot be archived, and only the owner or admin may archive
+    let result = client.try_archive_bounty(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::TooEarly)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, applicant.clone()], &None, &false);
+    let result = client.try_archive_bounty(&applicant, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.archive_bounty(&admin, &bounty_id);
+
+    let event = env
+        .events()
+        .all()
+        .try_last()
+        .expect("No events found")
+        .expect("Failed to get last event");
+    assert_eq!(
+        Symbol::from_val(&env, &event.1.get_unchecked(0)),
+        Symbol::new(&env, "bounty_archived")
+    );
+    let (status, winners, payouts): (Status, Vec<Address>, Map<Address, i128>) =
+        FromVal::from_val(&env, &event_data(&env, &event.2));
+    assert_eq!(status, Status::Completed);
+    assert_eq!(winners, vec![&env, applicant.clone()]);
+    assert_eq!(payouts, map![&env, (applicant.clone(), adjust_for_decimals(1000, 7))]);
+
+    // Submissions are gone while the rest of the record stays queryable
+    assert!(client.get_bounty_submissions(&bounty_id).is_empty());
+    assert!(client.get_submission_history(&bounty_id, &applicant).is_empty());
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+    assert_eq!(client.get_bounty_winners(&bounty_id), vec![&env, applicant.clone()]);
+
+    // The summary reports what was actually paid, e.g. consolation shares
+    let (consoled_owner, consoled_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.apply_to_bounty(&applicant, &consoled_id, &String::from_str(&env, "link"));
+    client.close_with_consolation(&consoled_owner, &consoled_id, &100);
+    client.archive_bounty(&consoled_owner, &consoled_id);
+    let event = env.events().all().last().unwrap();
+    let (status, _, payouts): (Status, Vec<Address>, Map<Address, i128>) =
+        FromVal::from_val(&env, &event_data(&env, &event.2));
+    assert_eq!(status, Status::Closed);
+    assert_eq!(payouts, map![&env, (applicant, adjust_for_decimals(100, 7))]);
+}
+
+#[test]
+fn test_keeper_check_judging_incentive() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_set_keeper_incentive(&(utils::MAX_KEEPER_INCENTIVE_PERCENT + 1)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_keeper_incentive(&1);
+    assert_eq!(client.get_keeper_incentive(), 1);
+
+    let (_owner, bounty_id) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant1 = Address::generate(&env);
+    let applicant2 = Address::generate(&env);
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link"));
+    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link"));
+
+    // Nothing is paid before the judging deadline
+    let keeper = Address::generate(&env);
+    client.keeper_check_judging(&keeper, &bounty_id);
+    assert_eq!(token.balance(&keeper), 0);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 2001);
+    client.keeper_check_judging(&keeper, &bounty_id);
+
+    let reward = adjust_for_decimals(1000, 7);
+    let incentive = reward / 100;
+    assert_eq!(token.balance(&keeper), incentive);
+    assert_eq!(token.balance(&applicant1), (reward - incentive) / 2);
+    assert_eq!(token.balance(&applicant2), (reward - incentive) / 2);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Completed);
+    // The incentive counts toward everything paid out of escrow
+    assert_eq!(client.get_lifetime_paid(&token.address), reward);
+}
+
+// ========================================
+// BOUNTY QUERY & GETTER TESTS
+// ========================================
+
+#[test]
+fn test_get_active_bounties() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let user_friendly_amount = 1000; // Amount per bounty in user-friendly format
+    let fee_per_bounty = utils::calculate_fee(user_friendly_amount, FeeType::Bounty);
+    let total_per_bounty = user_friendly_amount + fee_per_bounty;
+    // Need enough for 3 bounties (reward + fee each)
+    let token_amount = adjust_for_decimals(total_per_bounty * 3, get_token_decimals(&env, &token.address));
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &token_amount);
+
+    // Create first active bounty
+    let bounty1_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &user_friendly_amount,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "First bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    // Create second active bounty
+    let bounty2_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &user_friendly_amount,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
         &String::from_str(&env, "Second bounty"),
+        &None,
+        &None,
+        &None,
     );
 
     // Create and complete third bounty
@@ -489,22 +3673,25 @@ fn test_get_active_bounties() {
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
         &String::from_str(&env, "Third bounty"),
+        &None,
+        &None,
+        &None,
     );
     let winner = Address::generate(&env);
     
     // Move past submission deadline
     env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
     
-    client.select_winners(&owner, &bounty3_id, &vec![&env, winner]);
+    client.select_winners(&owner, &bounty3_id, &vec![&env, winner], &None, &false);
 
     // Get active bounties
     let active_bounties = client.get_active_bounties();
 
     // Verify only the first two bounties are active
     assert_eq!(active_bounties.len(), 2);
-    assert!(active_bounties.contains(&bounty1_id));
-    assert!(active_bounties.contains(&bounty2_id));
-    assert!(!active_bounties.contains(&bounty3_id));
+    assert!(active_bounties.contains(bounty1_id));
+    assert!(active_bounties.contains(bounty2_id));
+    assert!(!active_bounties.contains(bounty3_id));
 }
 
 #[test]
@@ -561,6 +3748,9 @@ fn test_getters() {
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
         &String::from_str(&env, "Bounty 1"),
+        &None,
+        &None,
+        &None,
     );
 
     // Bounty 2: Owner1, Token2
@@ -572,6 +3762,9 @@ fn test_getters() {
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
         &String::from_str(&env, "Bounty 2"),
+        &None,
+        &None,
+        &None,
     );
 
     // Bounty 3: Owner2, Token1
@@ -583,25 +3776,28 @@ fn test_getters() {
         &(env.ledger().timestamp() + 1000),
         &(env.ledger().timestamp() + 2000),
         &String::from_str(&env, "Bounty 3"),
+        &None,
+        &None,
+        &None,
     );
 
     // Test get_bounties
     let all_bounties = client.get_bounties();
     assert_eq!(all_bounties.len(), 3);
-    assert!(all_bounties.contains(&bounty1_id));
-    assert!(all_bounties.contains(&bounty2_id));
-    assert!(all_bounties.contains(&bounty3_id));
+    assert!(all_bounties.contains(bounty1_id));
+    assert!(all_bounties.contains(bounty2_id));
+    assert!(all_bounties.contains(bounty3_id));
 
     // Test get_owner_bounties and get_owner_bounties_count
     let owner1_bounties = client.get_owner_bounties(&owner1);
     assert_eq!(owner1_bounties.len(), 2);
-    assert!(owner1_bounties.contains(&bounty1_id));
-    assert!(owner1_bounties.contains(&bounty2_id));
+    assert!(owner1_bounties.contains(bounty1_id));
+    assert!(owner1_bounties.contains(bounty2_id));
     assert_eq!(client.get_owner_bounties_count(&owner1), 2);
 
     let owner2_bounties = client.get_owner_bounties(&owner2);
     assert_eq!(owner2_bounties.len(), 1);
-    assert!(owner2_bounties.contains(&bounty3_id));
+    assert!(owner2_bounties.contains(bounty3_id));
     assert_eq!(client.get_owner_bounties_count(&owner2), 1);
 
     // Test get_user_bounties and get_user_bounties_count after making submissions
@@ -617,14 +3813,14 @@ fn test_getters() {
     // Test get_user_bounties for applicant1 (should have submissions to bounty1 and bounty2)
     let applicant1_bounties = client.get_user_bounties(&applicant1);
     assert_eq!(applicant1_bounties.len(), 2);
-    assert!(applicant1_bounties.contains(&bounty1_id));
-    assert!(applicant1_bounties.contains(&bounty2_id));
+    assert!(applicant1_bounties.contains(bounty1_id));
+    assert!(applicant1_bounties.contains(bounty2_id));
     assert_eq!(client.get_user_bounties_count(&applicant1), 2);
 
     // Test get_user_bounties for applicant2 (should have submission to bounty1 only)
     let applicant2_bounties = client.get_user_bounties(&applicant2);
     assert_eq!(applicant2_bounties.len(), 1);
-    assert!(applicant2_bounties.contains(&bounty1_id));
+    assert!(applicant2_bounties.contains(bounty1_id));
     assert_eq!(client.get_user_bounties_count(&applicant2), 1);
 
     // Test get_user_bounties for owner1 (should be empty since owner1 hasn't made any submissions)
@@ -634,13 +3830,13 @@ fn test_getters() {
     // Test get_bounties_by_token and get_bounties_by_token_count
     let token1_bounties = client.get_bounties_by_token(&token.address);
     assert_eq!(token1_bounties.len(), 2);
-    assert!(token1_bounties.contains(&bounty1_id));
-    assert!(token1_bounties.contains(&bounty3_id));
+    assert!(token1_bounties.contains(bounty1_id));
+    assert!(token1_bounties.contains(bounty3_id));
     assert_eq!(client.get_bounties_by_token_count(&token.address), 2);
 
     let token2_bounties = client.get_bounties_by_token(&token2.address);
     assert_eq!(token2_bounties.len(), 1);
-    assert!(token2_bounties.contains(&bounty2_id));
+    assert!(token2_bounties.contains(bounty2_id));
     assert_eq!(client.get_bounties_by_token_count(&token2.address), 1);
 
     // Test submissions and applicants on bounty1
@@ -669,7 +3865,7 @@ fn test_getters() {
 
     // Test winners getter
     let winners = vec![&env, applicant1.clone(), applicant2.clone()];
-    client.select_winners(&owner1, &bounty1_id, &winners);
+    client.select_winners(&owner1, &bounty1_id, &winners, &None, &false);
 
     let stored_winners = client.get_bounty_winners(&bounty1_id);
     assert_eq!(stored_winners.len(), 2);
@@ -689,10 +3885,433 @@ fn test_getters() {
     assert_eq!(bounty.submissions, submissions);
 }
 
+#[test]
+fn test_get_open_bounty_count() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_open_bounty_count(), 0);
+
+    let (owner1, bounty1) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (_owner2, _bounty2) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(client.get_open_bounty_count(), 2);
+
+    // Closed bounties are not open
+    client.close_bounty(&owner1, &bounty1);
+    assert_eq!(client.get_open_bounty_count(), 1);
+
+    // A bounty created later is still open once the first ones' submission window ends
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+    let (_owner3, _bounty3) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 501);
+    assert_eq!(client.get_active_bounties().len(), 2);
+    assert_eq!(client.get_open_bounty_count(), 1);
+}
+
+#[test]
+fn test_get_owner_deletable_bounties() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, with_submission) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(11, 7));
+    let empty = client.create_bounty(
+        &owner,
+        &token.address,
+        &10,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Empty bounty"),
+        &None,
+        &None,
+        &None,
+    );
+    client.apply_to_bounty(&Address::generate(&env), &with_submission, &String::from_str(&env, "link"));
+
+    assert_eq!(client.get_owner_deletable_bounties(&owner), vec![&env, empty]);
+    assert_eq!(client.get_owner_deletable_bounties(&Address::generate(&env)), Vec::<u64>::new(&env));
+}
+
+#[test]
+fn test_get_admin_cleanable() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_, abandoned) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (_, submitted) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.apply_to_bounty(&Address::generate(&env), &submitted, &String::from_str(&env, "link"));
+    assert_eq!(client.get_admin_cleanable().len(), 0);
+
+    // Bounties still in their judging window are not eligible yet
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1500);
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(client.get_admin_cleanable().len(), 0);
+
+    // Only the first is past its judging deadline without submissions
+    env.ledger().set_timestamp(env.ledger().timestamp() + 501);
+    assert_eq!(client.get_admin_cleanable(), vec![&env, abandoned]);
+}
+
+#[test]
+fn test_get_top_owners() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // Creates `count` more bounties for `owner`, returning the last id
+    let create_more = |owner: &Address, count: u32| {
+        let mut id = 0;
+        for _ in 0..count {
+            token.transfer(&distributor, owner, &adjust_for_decimals(11, 7));
+            id = client.create_bounty(
+                owner,
+                &token.address,
+                &10,
+                &vec![&env, (1, 100)],
+                &(env.ledger().timestamp() + 1000),
+                &(env.ledger().timestamp() + 2000),
+                &String::from_str(&env, "Leaderboard bounty"),
+                &None,
+                &None,
+                &None,
+            );
+        }
+        id
+    };
+
+    let (owner1, _) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (owner2, _) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (owner3, _) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    create_more(&owner2, 2);
+    let last = create_more(&owner3, 1);
+
+    assert_eq!(
+        client.get_top_owners(&10),
+        vec![&env, (owner2.clone(), 3), (owner3.clone(), 2), (owner1.clone(), 1)]
+    );
+    assert_eq!(client.get_top_owners(&1), vec![&env, (owner2.clone(), 3)]);
+
+    // Deleting a bounty lowers the owner's count
+    client.delete_bounty(&owner3, &last);
+    let top = client.get_top_owners(&10);
+    assert_eq!(top.get(0).unwrap(), (owner2, 3));
+    assert_eq!(top.get(1).unwrap().1, 1);
+    assert_eq!(top.get(2).unwrap().1, 1);
+}
+
+#[test]
+fn test_get_global_stats() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty1) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (_owner2, _bounty2) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.close_bounty(&owner, &bounty1);
+
+    token.transfer(&distributor, &owner, &adjust_for_decimals(2060, 7));
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+    let gig = client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    client.cancel_project_gig(&owner, &gig);
+
+    let stats = client.get_global_stats();
+    assert_eq!(stats.total_bounties, 2);
+    assert_eq!(stats.active_bounties, 1);
+    assert_eq!(stats.total_projects, 2);
+    assert_eq!(stats.active_projects, 1);
+    // 5% on each bounty, 3% on each gig
+    assert_eq!(
+        stats.fees_collected.get(token.address.clone()),
+        Some(adjust_for_decimals(50 + 50 + 30 + 30, 7))
+    );
+}
+
+#[test]
+fn test_get_status_breakdown() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, completed) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (closed_owner, closed) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.close_bounty(&closed_owner, &closed);
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &completed, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &completed, &vec![&env, applicant], &None, &false);
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    let breakdown = client.get_status_breakdown();
+    assert_eq!(breakdown.get(Status::Active), Some(2));
+    assert_eq!(breakdown.get(Status::Completed), Some(1));
+    assert_eq!(breakdown.get(Status::Closed), Some(1));
+    assert_eq!(breakdown.get(Status::Judging), Some(0));
+    assert_eq!(breakdown.get(Status::Funding), Some(0));
+}
+
+#[test]
+fn test_get_fee_breakdown() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    let (other_token, other_distributor) = create_token_contract(&env);
+    env.mock_all_auths();
+
+    assert!(client.get_fee_breakdown().is_empty());
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    create_test_bounty(&env, &client, &token, &distributor, 400, vec![&env, (1, 100)]);
+    create_test_bounty(&env, &client, &other_token, &other_distributor, 200, vec![&env, (1, 100)]);
+
+    let breakdown: std::vec::Vec<(Address, i128)> = client.get_fee_breakdown().iter().collect();
+    assert_eq!(breakdown.len(), 2);
+    assert!(breakdown.contains(&(token.address.clone(), adjust_for_decimals(50 + 20, 7))));
+    assert!(breakdown.contains(&(other_token.address.clone(), adjust_for_decimals(10, 7))));
+}
+
+#[test]
+fn test_get_operational_counters() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_operational_counters(), Counters::default());
+
+    let (owner1, completed) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (owner2, closed) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (owner3, deleted) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    client.close_bounty(&owner2, &closed);
+    client.delete_bounty(&owner3, &deleted);
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &completed, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner1, &completed, &vec![&env, applicant], &None, &false);
+
+    assert_eq!(
+        client.get_operational_counters(),
+        Counters {
+            bounties_created: 3,
+            bounties_completed: 1,
+            bounties_closed: 1,
+            bounties_deleted: 1,
+        }
+    );
+}
+
+#[test]
+fn test_set_featured() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, featured) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (_owner2, _plain) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    client.set_featured(&featured, &true);
+    client.set_featured(&featured, &true);
+    assert_eq!(client.get_featured_bounties(), vec![&env, featured]);
+
+    client.set_featured(&featured, &false);
+    assert_eq!(client.get_featured_bounties(), Vec::<u64>::new(&env));
+
+    // Deleted bounties drop out of the list
+    client.set_featured(&featured, &true);
+    client.delete_bounty(&owner, &featured);
+    assert_eq!(client.get_featured_bounties(), Vec::<u64>::new(&env));
+
+    let result = client.try_set_featured(&999, &true);
+    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_get_user_profile() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (user, own_bounty) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (other_owner, won_bounty) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (_third_owner, lost_bounty) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    let link = String::from_str(&env, "link");
+    let rival = Address::generate(&env);
+    client.apply_to_bounty(&user, &won_bounty, &link);
+    client.apply_to_bounty(&user, &lost_bounty, &link);
+    client.apply_to_bounty(&rival, &own_bounty, &link);
+
+    token.transfer(&distributor, &user, &adjust_for_decimals(1030, 7));
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    client.create_project_gig(&user, &token.address, &1000, &milestones, &(env.ledger().timestamp() + 10000));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&other_owner, &won_bounty, &vec![&env, user.clone()], &None, &false);
+
+    let profile = client.get_user_profile(&user);
+    assert_eq!(profile.bounties_created, 1);
+    assert_eq!(profile.bounties_applied, 2);
+    assert_eq!(profile.bounties_won, 1);
+    assert_eq!(profile.projects_owned, 1);
+
+    let empty = client.get_user_profile(&Address::generate(&env));
+    assert_eq!(empty.bounties_created + empty.bounties_applied + empty.bounties_won + empty.projects_owned, 0);
+}
+
+#[test]
+fn test_get_open_for_submission() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, early) =
+        create_test_bounty(&env, &client, &token, &distributor, 100, vec![&env, (1, 100)]);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 500);
+    let (_owner, late) =
+        create_test_bounty(&env, &client, &token, &distributor, 100, vec![&env, (1, 100)]);
+    assert_eq!(client.get_open_for_submission(), vec![&env, early, late]);
+
+    // The first bounty's submission window closes while it is still Active
+    env.ledger().set_timestamp(env.ledger().timestamp() + 501);
+    assert_eq!(client.get_open_for_submission(), vec![&env, late]);
+    assert_eq!(client.get_open_bounty_count(), 1);
+}
+
+#[test]
+fn test_get_bounties_by_statuses() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (closed_owner, closed) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let (completed_owner, completed) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    client.close_bounty(&closed_owner, &closed);
+
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &completed, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&completed_owner, &completed, &vec![&env, applicant], &None, &false);
+
+    // Created after the others' deadline, so still Active
+    let (_owner, active) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    let result = client.get_bounties_by_statuses(&vec![&env, Status::Active, Status::Closed]);
+    assert_eq!(result, vec![&env, closed, active]);
+    assert_eq!(client.get_bounties_by_statuses(&Vec::new(&env)), Vec::<u64>::new(&env));
+}
+
+#[test]
+fn test_get_used_tokens() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_used_tokens(), Vec::<Address>::new(&env));
+
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    let (other_token, other_distributor) = create_token_contract(&env);
+    let owner = Address::generate(&env);
+    other_token.transfer(&other_distributor, &owner, &adjust_for_decimals(1050, 7));
+    client.create_bounty(
+        &owner,
+        &other_token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Other token bounty"),
+        &None,
+        &None,
+        &None,
+    );
+
+    assert_eq!(
+        client.get_used_tokens(),
+        vec![&env, token.address.clone(), other_token.address.clone()]
+    );
+}
+
+#[test]
+fn test_get_bounties_due_for_judging() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // Judging deadlines at 2000 and 3500
+    let (_owner1, bounty1) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1500);
+    let (owner2, bounty2) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+
+    assert_eq!(client.get_bounties_due_for_judging(&100), Vec::<u64>::new(&env));
+    assert_eq!(client.get_bounties_due_for_judging(&500), vec![&env, bounty1]);
+    assert_eq!(client.get_bounties_due_for_judging(&2000), vec![&env, bounty1, bounty2]);
+
+    // Overdue bounties stay listed until settled; closed ones drop out
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1000);
+    assert_eq!(client.get_bounties_due_for_judging(&0), vec![&env, bounty1]);
+    client.close_bounty(&owner2, &bounty2);
+    assert_eq!(client.get_bounties_due_for_judging(&2000), vec![&env, bounty1]);
+}
+
 // ========================================
 // SUBMISSION UPDATE TESTS
 // ========================================
 
+#[test]
+fn test_pause_bounty_blocks_submissions() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    client.apply_to_bounty(&applicant, &bounty_id, &link);
+
+    assert_eq!(
+        client.try_pause_bounty(&Address::generate(&env), &bounty_id),
+        Err(Ok(Error::OnlyOwner))
+    );
+    client.pause_bounty(&owner, &bounty_id);
+    assert!(client.is_bounty_paused(&bounty_id));
+    assert_eq!(
+        client.try_apply_to_bounty(&Address::generate(&env), &bounty_id, &link),
+        Err(Ok(Error::BountyPaused))
+    );
+    assert_eq!(
+        client.try_update_submission(&applicant, &bounty_id, &link),
+        Err(Ok(Error::BountyPaused))
+    );
+
+    client.resume_bounty(&owner, &bounty_id);
+    assert!(!client.is_bounty_paused(&bounty_id));
+    client.apply_to_bounty(&Address::generate(&env), &bounty_id, &link);
+    client.update_submission(&applicant, &bounty_id, &link);
+
+    // The deadline kept running while paused and still closes submissions
+    client.pause_bounty(&owner, &bounty_id);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    assert_eq!(
+        client.try_apply_to_bounty(&Address::generate(&env), &bounty_id, &link),
+        Err(Ok(Error::BountyDeadlinePassed))
+    );
+    assert_eq!(client.try_pause_bounty(&owner, &bounty_id), Err(Ok(Error::InactiveBounty)));
+}
+
 #[test]
 fn test_update_submission() {
     let env = Env::default();
@@ -721,6 +4340,9 @@ fn test_update_submission() {
         &(env.ledger().timestamp() + 1000), // submission deadline
         &(env.ledger().timestamp() + 2000), // judging deadline
         &String::from_str(&env, "Test Bounty"),
+        &None,
+        &None,
+        &None,
     );
 
     // Make some submissions
@@ -760,19 +4382,14 @@ fn test_update_submission() {
         String::from_str(&env, "link2")
     );
 
-    // Test updating a non-existent submission (should fail with InternalError)
+    // Test updating a non-existent submission
     let non_applicant = Address::generate(&env);
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        client.update_submission(
-            &non_applicant,
-            &bounty_id,
-            &String::from_str(&env, "should_fail"),
-        );
-    }));
-    assert!(
-        result.is_err(),
-        "Should not be able to update a non-existent submission"
+    let result = client.try_update_submission(
+        &non_applicant,
+        &bounty_id,
+        &String::from_str(&env, "should_fail"),
     );
+    assert_eq!(result, Err(Ok(Error::SubmissionNotFound)));
 
     // Test updating after deadline (should fail with BountyDeadlinePassed)
     let bounty = client.get_bounty(&bounty_id);
@@ -793,10 +4410,185 @@ fn test_update_submission() {
     );
 }
 
+#[test]
+fn test_submission_history() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant = Address::generate(&env);
+    let v1 = String::from_str(&env, "https://example.com/v1");
+    let v2 = String::from_str(&env, "https://example.com/v2");
+    let v3 = String::from_str(&env, "https://example.com/v3");
+    client.apply_to_bounty(&applicant, &bounty_id, &v1);
+    client.update_submission(&applicant, &bounty_id, &v2);
+    client.update_submission(&applicant, &bounty_id, &v3);
+
+    assert_eq!(
+        client.get_submission_history(&bounty_id, &applicant),
+        vec![&env, v1.clone(), v2, v3.clone()]
+    );
+    assert_eq!(client.get_submission(&bounty_id, &applicant), v3);
+
+    // Only the latest revisions are kept
+    for _ in 0..utils::MAX_SUBMISSION_REVISIONS {
+        client.update_submission(&applicant, &bounty_id, &v1);
+    }
+    let history = client.get_submission_history(&bounty_id, &applicant);
+    assert_eq!(history.len(), utils::MAX_SUBMISSION_REVISIONS);
+    assert!(!history.contains(v3));
+
+    client.withdraw_submission(&applicant, &bounty_id);
+    assert_eq!(client.get_submission_history(&bounty_id, &applicant).len(), 0);
+}
+
+#[test]
+fn test_missing_ids_return_typed_errors() {
+    let env = Env::default();
+    let (client, _token, _distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // Every lookup of a missing entity fails with a contract error rather
+    // than an unwrap panic
+    let user = Address::generate(&env);
+    let link = String::from_str(&env, "link");
+    let missing = 42;
+
+    assert_eq!(client.try_get_bounty(&missing), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_apply_to_bounty(&user, &missing, &link), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_update_submission(&user, &missing, &link), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_withdraw_submission(&user, &missing), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(
+        client.try_select_winners(&user, &missing, &vec![&env, user.clone()], &None, &false),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(client.try_close_bounty(&user, &missing), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_delete_bounty(&user, &missing), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_advance_status(&missing), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_contribute(&user, &missing, &1), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_refund_contributors(&missing), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(client.try_get_project(&missing), Err(Ok(Error::ProjectNotFound)));
+    assert_eq!(
+        client.try_release_milestone_payment(&user, &missing, &1, &user, &1),
+        Err(Ok(Error::ProjectNotFound))
+    );
+    assert_eq!(client.try_cancel_project_gig(&user, &missing), Err(Ok(Error::ProjectNotFound)));
+    assert_eq!(client.try_get_hackathon(&missing), Err(Ok(Error::HackathonNotFound)));
+}
+
+#[test]
+fn test_applicant_count_tracks_applicants() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(client.get_bounty_applicant_count(&bounty_id), 0);
+
+    let applicant1 = Address::generate(&env);
+    let applicant2 = Address::generate(&env);
+    let applicant3 = Address::generate(&env);
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
+    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
+    client.apply_with_proposal(
+        &applicant3,
+        &bounty_id,
+        &String::from_str(&env, "link3"),
+        &String::from_str(&env, "proposal"),
+    );
+    // Re-applying only replaces the link
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1b"));
+    assert_eq!(client.get_bounty_applicant_count(&bounty_id), 3);
+    assert_eq!(client.get_bounty_applicants(&bounty_id).len(), 3);
+
+    client.withdraw_submission(&applicant3, &bounty_id);
+    let applicants = client.get_bounty_applicants(&bounty_id);
+    assert_eq!(client.get_bounty_applicant_count(&bounty_id), applicants.len());
+    assert_eq!(applicants, vec![&env, applicant1.clone(), applicant2.clone()]);
+    assert!(!client.get_bounty_submissions(&bounty_id).contains_key(applicant3.clone()));
+    let result = client.try_get_submission_full(&bounty_id, &applicant3);
+    assert_eq!(result, Err(Ok(Error::SubmissionNotFound)));
+
+    let result = client.try_withdraw_submission(&applicant3, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::SubmissionNotFound)));
+
+    // Withdrawing closes with the submission window
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let result = client.try_withdraw_submission(&applicant1, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::BountyDeadlinePassed)));
+    assert_eq!(client.get_bounty_applicant_count(&bounty_id), 2);
+}
+
+#[test]
+fn test_reapply_after_withdraw_counts_once() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let applicant = Address::generate(&env);
+    client.apply_with_proposal(
+        &applicant,
+        &bounty_id,
+        &String::from_str(&env, "first"),
+        &String::from_str(&env, "proposal"),
+    );
+    client.withdraw_submission(&applicant, &bounty_id);
+
+    // Comes back as a fresh applicant, without the old proposal
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "second"));
+    assert_eq!(client.get_bounty_applicants(&bounty_id), vec![&env, applicant.clone()]);
+    assert_eq!(client.get_bounty_applicant_count(&bounty_id), 1);
+    assert_eq!(
+        client.get_bounty_submissions(&bounty_id).get(applicant.clone()),
+        Some(String::from_str(&env, "second"))
+    );
+    assert_eq!(
+        client.get_submission_history(&bounty_id, &applicant),
+        vec![&env, String::from_str(&env, "second")]
+    );
+    assert_eq!(client.get_submission_full(&bounty_id, &applicant).1, String::from_str(&env, ""));
+}
+
 // ========================================
 // BOUNTY UPDATE & DELETE TESTS
 // ========================================
 
+#[test]
+fn test_distribution_frozen_after_submission_deadline() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let split = vec![&env, (1, 70), (2, 30)];
+    client.update_bounty(&owner, &bounty_id, &None, &split, &None, &None);
+    assert_eq!(client.get_bounty(&bounty_id).distribution.get(2), Some(30));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    let result =
+        client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env, (1, 100)], &None, &None);
+    assert_eq!(result, Err(Ok(Error::BountyDeadlinePassed)));
+    assert_eq!(client.get_bounty(&bounty_id).distribution.get(2), Some(30));
+
+    // Nor can the submission window be reopened by moving its deadline
+    let bounty = client.get_bounty(&bounty_id);
+    let reopened = Some(env.ledger().timestamp() + 100);
+    let result = client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &reopened, &None);
+    assert_eq!(result, Err(Ok(Error::BountyDeadlinePassed)));
+    assert_eq!(client.get_bounty(&bounty_id).submission_deadline, bounty.submission_deadline);
+
+    // The title can still be fixed
+    let title = String::from_str(&env, "Renamed");
+    client.update_bounty(&owner, &bounty_id, &Some(title.clone()), &vec![&env], &None, &None);
+    assert_eq!(client.get_bounty(&bounty_id).title, title);
+}
+
 #[test]
 fn test_update_and_delete_bounty() {
     let env = Env::default();
@@ -837,6 +4629,9 @@ fn test_update_and_delete_bounty() {
         &submission_deadline,
         &judging_deadline,
         &title,
+        &None,
+        &None,
+        &None,
     );
 
     // Test 1: Try to update with non-owner (should fail)
@@ -846,6 +4641,7 @@ fn test_update_and_delete_bounty() {
         &Some(String::from_str(&env, "New Title")),
         &vec![&env],
         &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(Error::OnlyOwner)));
 
@@ -856,6 +4652,7 @@ fn test_update_and_delete_bounty() {
         &Some(String::from_str(&env, "Updated Title")),
         &vec![&env],
         &None,
+        &None,
     );
     verify_bounty_updated_event(
         &env,
@@ -870,7 +4667,7 @@ fn test_update_and_delete_bounty() {
 
     // Test 3: Update distribution
     let new_distribution = vec![&env, (1, 70), (2, 30)];
-    client.update_bounty(&owner, &bounty_id, &None, &new_distribution, &None);
+    client.update_bounty(&owner, &bounty_id, &None, &new_distribution, &None, &None);
 
     // Verify update
     let bounty = client.get_bounty(&bounty_id);
@@ -885,6 +4682,7 @@ fn test_update_and_delete_bounty() {
         &None,
         &vec![&env],
         &Some(new_submission_deadline),
+        &None,
     );
     verify_bounty_updated_event(
         &env,
@@ -901,7 +4699,12 @@ fn test_update_and_delete_bounty() {
     env.ledger().set_timestamp(env.ledger().timestamp() + 1);
     let past_deadline = env.ledger().timestamp() - 1;
     let result =
-        client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(past_deadline));
+        client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(past_deadline), &None);
+    assert_eq!(result, Err(Ok(Error::InvalidDeadlineUpdate)));
+
+    // A deadline of exactly now is rejected as well
+    let now = env.ledger().timestamp();
+    let result = client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(now), &None);
     assert_eq!(result, Err(Ok(Error::InvalidDeadlineUpdate)));
 
     // Test 6: Try to delete with non-owner (should fail)
@@ -925,6 +4728,9 @@ fn test_update_and_delete_bounty() {
         &submission_deadline,
         &judging_deadline,
         &title,
+        &None,
+        &None,
+        &None,
     );
 
     // Get initial owner balance
@@ -950,6 +4756,298 @@ fn test_update_and_delete_bounty() {
     );
 }
 
+#[test]
+fn test_bounty_description() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // Bounties created without one have an empty description
+    let (owner, plain) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(client.get_bounty(&plain).description, String::from_str(&env, ""));
+
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let brief = String::from_str(&env, "Port the parser to no_std and keep the API");
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Parser port"),
+        &None,
+        &None,
+        &Some(BountyOptions { description: Some(brief.clone()), ..Default::default() }),
+    );
+    assert_eq!(client.get_bounty(&bounty_id).description, brief);
+
+    let revised = String::from_str(&env, "Port the parser to no_std; API may change");
+    client.update_bounty(&owner, &bounty_id, &None, &vec![&env], &None, &Some(revised.clone()));
+    assert_eq!(client.get_bounty(&bounty_id).description, revised);
+    assert_eq!(client.get_bounty_snapshot(&bounty_id).description, revised);
+
+    let too_long = String::from_bytes(&env, &[b'a'; utils::MAX_DESCRIPTION_LENGTH as usize + 1]);
+    let result =
+        client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &None, &Some(too_long));
+    assert_eq!(result, Err(Ok(Error::TooManyItems)));
+    assert_eq!(client.get_bounty(&bounty_id).description, revised);
+}
+
+#[test]
+fn test_submission_deadline_extension_cap() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let day = 86_400;
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(2100, 7));
+    let create = |judging_deadline: u64| {
+        client.create_bounty(
+            &owner,
+            &token.address,
+            &1000,
+            &vec![&env, (1, 100)],
+            &(env.ledger().timestamp() + 1000),
+            &judging_deadline,
+            &String::from_str(&env, "Test bounty"),
+            &None,
+            &None,
+            &None,
+        )
+    };
+    let bounty_id = create(env.ledger().timestamp() + 60 * day);
+    let deadline = client.get_bounty(&bounty_id).submission_deadline;
+
+    // Moving the deadline earlier does not use up an extension
+    client.update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(deadline - 500), &None);
+    for i in 1..=utils::MAX_DEADLINE_EXTENSIONS as u64 {
+        client.update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(deadline + i * day), &None);
+    }
+    let result = client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(deadline + 4 * day), &None);
+    assert_eq!(result, Err(Ok(Error::TooManyExtensions)));
+
+    // A single extension past the total cap is refused too
+    let other = create(env.ledger().timestamp() + 60 * day);
+    let too_far = deadline + utils::MAX_DEADLINE_EXTENSION_DAYS * day + 1;
+    let result = client.try_update_bounty(&owner, &other, &None, &vec![&env], &Some(too_far), &None);
+    assert_eq!(result, Err(Ok(Error::TooManyExtensions)));
+    client.update_bounty(&owner, &other, &None, &vec![&env], &Some(too_far - 1), &None);
+}
+
+#[test]
+fn test_deadline_boundaries() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(utils::validate_deadlines(100, 200, 100, 0), Err(Error::InvalidDeadlineUpdate));
+    assert_eq!(
+        utils::validate_deadlines(200, 200, 100, 0),
+        Err(Error::JudgingDeadlineMustBeAfterSubmissionDeadline)
+    );
+    assert_eq!(utils::validate_deadlines(101, 102, 100, 0), Ok(()));
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let create = |submission: u64, judging: u64| {
+        client.try_create_bounty(
+            &owner,
+            &token.address,
+            &1000,
+            &vec![&env, (1, 100)],
+            &submission,
+            &judging,
+            &String::from_str(&env, "Deadline bounty"),
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    // Submission deadline equal to now, judging equal to submission
+    assert_eq!(create(100, 200), Err(Ok(Error::InvalidDeadlineUpdate)));
+    assert_eq!(
+        create(150, 150),
+        Err(Ok(Error::JudgingDeadlineMustBeAfterSubmissionDeadline))
+    );
+    let bounty_id = create(101, 102).unwrap().unwrap();
+
+    // Updates go through the same checks against the stored judging deadline
+    let result = client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(100), &None);
+    assert_eq!(result, Err(Ok(Error::InvalidDeadlineUpdate)));
+    let result = client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(102), &None);
+    assert_eq!(result, Err(Ok(Error::JudgingDeadlineMustBeAfterSubmissionDeadline)));
+}
+
+#[test]
+fn test_min_judging_gap() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    client.set_min_judging_gap(&500);
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1050, 7));
+    let create = |judging: u64| {
+        client.try_create_bounty(
+            &owner,
+            &token.address,
+            &1000,
+            &vec![&env, (1, 100)],
+            &1000,
+            &judging,
+            &String::from_str(&env, "Gap bounty"),
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    assert_eq!(create(1499), Err(Ok(Error::JudgingDeadlineMustBeAfterSubmissionDeadline)));
+    let bounty_id = create(1500).unwrap().unwrap();
+
+    // Moving the submission deadline closer to judging is checked too
+    let result = client.try_update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(1001), &None);
+    assert_eq!(result, Err(Ok(Error::JudgingDeadlineMustBeAfterSubmissionDeadline)));
+    client.update_bounty(&owner, &bounty_id, &None, &vec![&env], &Some(900), &None);
+    assert_eq!(client.get_bounty(&bounty_id).submission_deadline, 900);
+}
+
+#[test]
+fn test_health_check() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let report = client.health_check();
+    assert!(report.healthy);
+    assert!(report.admin_set);
+    assert!(report.fee_account_set);
+    assert!(report.bounty_ids_ok && report.project_ids_ok && report.hackathon_ids_ok);
+
+    create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert!(client.health_check().healthy);
+}
+
+#[test]
+fn test_peek_next_ids() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // Peeking does not reserve the id
+    let next_bounty = client.peek_next_bounty_id();
+    assert_eq!(client.peek_next_bounty_id(), next_bounty);
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(bounty_id, next_bounty);
+    assert_eq!(client.peek_next_bounty_id(), next_bounty + 1);
+
+    let next_project = client.peek_next_project_id();
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let project_id = client.create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, MilestoneData { amount: 1000, order: 1 }],
+        &(env.ledger().timestamp() + 10000),
+    );
+    assert_eq!(project_id, next_project);
+    assert_eq!(client.peek_next_project_id(), next_project + 1);
+}
+
+#[test]
+fn test_check_solvency() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.check_solvency(&token.address), (0, 0, true));
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    create_test_bounty(&env, &client, &token, &distributor, 500, vec![&env, (1, 100)]);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    client.create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, MilestoneData { amount: 1000, order: 1 }],
+        &(env.ledger().timestamp() + 10000),
+    );
+    let owed = adjust_for_decimals(2500, 7);
+    assert_eq!(client.check_solvency(&token.address), (owed, owed, true));
+
+    // Settling a bounty lowers both sides
+    let applicant = Address::generate(&env);
+    client.apply_to_bounty(&applicant, &bounty_id, &String::from_str(&env, "link"));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    client.select_winners(&owner, &bounty_id, &vec![&env, applicant], &None, &false);
+    let owed = adjust_for_decimals(1500, 7);
+    assert_eq!(client.check_solvency(&token.address), (owed, owed, true));
+
+    // Funds leaving the contract outside its accounting show up as a shortfall
+    token.transfer(&contract_id, &Address::generate(&env), &1);
+    assert_eq!(client.check_solvency(&token.address), (owed - 1, owed, false));
+}
+
+#[test]
+fn test_admin_handover_and_cancel() {
+    let env = Env::default();
+    let (client, _token, _distributor, _fee_account, admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let candidate = Address::generate(&env);
+    client.propose_admin(&candidate);
+    assert_eq!(client.get_pending_admin(), Some(candidate.clone()));
+
+    // A cancelled proposal can no longer be accepted
+    client.cancel_admin_proposal();
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NotAdmin)));
+    assert_eq!(client.get_config().admin, admin);
+
+    // A fresh proposal goes through once accepted
+    client.propose_admin(&candidate);
+    client.accept_admin();
+    assert_eq!(client.get_config().admin, candidate);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_fee_account_timelock() {
+    let env = Env::default();
+    let (client, _token, _distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let new_fee_account = Address::generate(&env);
+    let effective_at = env.ledger().timestamp() + 86_400;
+    client.propose_fee_account(&new_fee_account, &effective_at);
+    assert_eq!(
+        client.get_pending_fee_account(),
+        Some((new_fee_account.clone(), effective_at))
+    );
+
+    // Too early: the current fee account stays in place
+    env.ledger().set_timestamp(effective_at - 1);
+    assert_eq!(client.try_apply_fee_account(), Err(Ok(Error::TooEarly)));
+    assert_eq!(client.get_config().fee_account, fee_account);
+
+    env.ledger().set_timestamp(effective_at);
+    client.apply_fee_account();
+    assert_eq!(client.get_config().fee_account, new_fee_account);
+    assert_eq!(client.get_pending_fee_account(), None);
+    assert_eq!(client.try_apply_fee_account(), Err(Ok(Error::NothingToClaim)));
+
+    // The activation time must be in the future
+    let result = client.try_propose_fee_account(&fee_account, &env.ledger().timestamp());
+    assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+}
+
 #[test]
 fn test_admin_functions() {
     let env = Env::default();
@@ -1019,10 +5117,171 @@ fn test_admin_functions() {
     let result = client.try_update_fee_account(&new_fee_account);
     assert!(result.is_err());
 
-    // Test that non-admin can't make changes
-    let random_user = Address::generate(&env);
-    let result = client.try_update_fee_account(&random_user);
-    assert!(result.is_err());
+    // Test that non-admin can't make changes
+    let random_user = Address::generate(&env);
+    let result = client.try_update_fee_account(&random_user);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_bump_many_ttl() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(10_000, 7));
+
+    let bounty_id = client.create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
+    );
+    let project_id = client.create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, MilestoneData { amount: 1000, order: 1 }],
+        &(env.ledger().timestamp() + 10000),
+    );
+
+    // Input length is bounded
+    let mut ids = Vec::new(&env);
+    for id in 0..(utils::MAX_BATCH_SIZE as u64 + 1) {
+        ids.push_back(id);
+    }
+    let result = client.try_bump_many_ttl(&ids);
+    assert_eq!(result, Err(Ok(Error::TooManyItems)));
+
+    // Move the ledger close to the archival point of the freshly written entries
+    env.ledger().with_mut(|li| li.sequence_number += 4000);
+    client.bump_many_ttl(&vec![&env, bounty_id, project_id]);
+
+    let (bounty_ttl, project_ttl) = env.as_contract(&contract_id, || {
+        let persistent = env.storage().persistent();
+        (
+            persistent.get_ttl(&storage::bounty_key(bounty_id)),
+            persistent.get_ttl(&storage::project_key(project_id)),
+        )
+    });
+    assert_eq!(bounty_ttl, utils::PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(project_ttl, utils::PERSISTENT_BUMP_AMOUNT);
+
+    // Entries outlive their original TTL
+    env.ledger().with_mut(|li| li.sequence_number += 10_000);
+    assert_eq!(client.get_bounty(&bounty_id).owner, owner);
+    assert_eq!(client.get_project(&project_id).owner, owner);
+}
+
+#[test]
+fn test_get_bounty_touch_extends_ttl() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let ttl = || {
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().get_ttl(&storage::bounty_key(bounty_id))
+        })
+    };
+
+    env.ledger().with_mut(|li| li.sequence_number += 4000);
+    let before = ttl();
+
+    // A plain read leaves the TTL alone
+    client.get_bounty(&bounty_id);
+    assert_eq!(ttl(), before);
+
+    assert_eq!(client.get_bounty_touch(&bounty_id).owner, owner);
+    assert!(ttl() > before);
+    assert_eq!(ttl(), utils::PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(client.try_get_bounty_touch(&999), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_storage_keys_are_distinct() {
+    let env = Env::default();
+    let address = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[0; 32]);
+
+    // Same arguments everywhere, so only the namespace can tell keys apart
+    let keys: std::vec::Vec<Val> = std::vec![
+        storage::next_id_key().into_val(&env),
+        storage::bounty_key(1).into_val(&env),
+        storage::admin_key().into_val(&env),
+        storage::pending_admin_key().into_val(&env),
+        storage::pending_fee_account_key().into_val(&env),
+        storage::accrued_fees_key(address.clone()).into_val(&env),
+        storage::fee_account_key().into_val(&env),
+        storage::next_project_id_key().into_val(&env),
+        storage::project_key(1).into_val(&env),
+        storage::next_hackathon_id_key().into_val(&env),
+        storage::hackathon_key(1).into_val(&env),
+        storage::deployment_seq_key().into_val(&env),
+        storage::fee_cap_key(address.clone()).into_val(&env),
+        storage::token_decimals_key(address.clone()).into_val(&env),
+        storage::lifetime_funded_key(address.clone()).into_val(&env),
+        storage::lifetime_paid_key(address.clone()).into_val(&env),
+        storage::idempotency_record_key(address.clone(), hash).into_val(&env),
+        storage::operational_counters_key().into_val(&env),
+        storage::owner_counts_key().into_val(&env),
+        storage::global_stats_key().into_val(&env),
+        storage::featured_bounties_key().into_val(&env),
+        storage::used_tokens_key().into_val(&env),
+        storage::event_seq_key().into_val(&env),
+        storage::submission_cooldown_key().into_val(&env),
+        storage::fee_refund_window_key().into_val(&env),
+        storage::max_open_per_owner_key().into_val(&env),
+        storage::open_bounty_count_key(address.clone()).into_val(&env),
+        storage::min_judging_gap_key().into_val(&env),
+        storage::block_owner_apply_key().into_val(&env),
+        storage::emergency_delay_key().into_val(&env),
+        storage::pending_emergency_key().into_val(&env),
+        storage::alt_pool_key(address.clone()).into_val(&env),
+        storage::allowlist_key(1).into_val(&env),
+        storage::crowdfund_key(1).into_val(&env),
+        storage::series_key(1).into_val(&env),
+        storage::bounty_series_key(1).into_val(&env),
+        storage::sub_bounties_key(1).into_val(&env),
+        storage::insurance_bps_key().into_val(&env),
+        storage::insurance_pool_key(address.clone()).into_val(&env),
+        storage::owner_create_cooldown_key().into_val(&env),
+        storage::last_creation_key(address.clone()).into_val(&env),
+        storage::approved_fee_recipient_key(address.clone()).into_val(&env),
+        storage::submission_history_key(1, address.clone()).into_val(&env),
+        storage::default_token_key().into_val(&env),
+        storage::bounty_paused_key(1).into_val(&env),
+        storage::claim_window_key().into_val(&env),
+        storage::claims_opened_at_key(1).into_val(&env),
+        storage::fee_refundable_key(1).into_val(&env),
+        storage::next_series_id_key().into_val(&env),
+        storage::applicant_count_key(1).into_val(&env),
+        storage::acceptance_deadline_key(1).into_val(&env),
+        storage::project_earnings_key(1, address.clone()).into_val(&env),
+        storage::project_contributors_key(1).into_val(&env),
+        storage::contributor_earnings_key(address.clone(), address.clone()).into_val(&env),
+        storage::keeper_incentive_key().into_val(&env),
+        storage::milestone_release_fee_key().into_val(&env),
+        storage::bounty_payouts_key(1).into_val(&env),
+        storage::claimable_reward_key(1, address.clone()).into_val(&env),
+        storage::proposal_key(1, address.clone()).into_val(&env),
+        storage::last_submission_key(1, address.clone()).into_val(&env),
+    ];
+
+    let mut seen = std::collections::BTreeSet::new();
+    for key in keys.iter() {
+        let scval = ScVal::try_from_val(&env, key).unwrap();
+        assert!(seen.insert(scval), "duplicate storage key");
+    }
 }
 
 // ========================================
@@ -1061,6 +5320,9 @@ fn test_close_bounty() {
         &submission_deadline,
         &judging_deadline,
         &title,
+        &None,
+        &None,
+        &None,
     );
 
     // Get initial owner balance
@@ -1094,6 +5356,9 @@ fn test_close_bounty() {
         &submission_deadline,
         &judging_deadline,
         &title,
+        &None,
+        &None,
+        &None,
     );
 
     let result = client.try_close_bounty(&not_owner, &bounty_id2);
@@ -1124,16 +5389,117 @@ fn test_close_bounty() {
 
     // Test 6: Verify closed bounty appears in owner's bounties
     let owner_bounties = client.get_owner_bounties(&owner);
-    assert!(owner_bounties.contains(&bounty_id1));
+    assert!(owner_bounties.contains(bounty_id1));
 
     // Test 7: Verify closed bounty appears in status-based queries
     let closed_bounties = client.get_bounties_by_status(&Status::Closed);
-    assert!(closed_bounties.contains(&bounty_id1));
+    assert!(closed_bounties.contains(bounty_id1));
     assert_eq!(client.get_bounties_by_status_count(&Status::Closed), 1);
 
     // Test 8: Verify closed bounty does NOT appear in active bounties
     let active_bounties = client.get_active_bounties();
-    assert!(!active_bounties.contains(&bounty_id1));
+    assert!(!active_bounties.contains(bounty_id1));
+}
+
+#[test]
+fn test_close_bounty_fee_refund_window() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    client.set_fee_refund_window(&600);
+    let fee = adjust_for_decimals(50, 7);
+
+    // Closed within the window: the escrow comes back on the owner's
+    // signature alone, the fee once the fee account refunds it
+    let (owner, bounty_id) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(token.balance(&fee_account), fee);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 600);
+    client.close_bounty(&owner, &bounty_id);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(address, _)| address).collect();
+    assert_eq!(signers, std::vec![owner.clone()]);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&fee_account), fee);
+
+    client.refund_fee(&bounty_id);
+    assert_eq!(env.auths()[0].0, fee_account);
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1050, 7));
+    assert_eq!(token.balance(&fee_account), 0);
+
+    // The fee can't be refunded twice, nor a bounty closed twice
+    let result = client.try_refund_fee(&bounty_id);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+    let result = client.try_close_bounty(&owner, &bounty_id);
+    assert_eq!(result, Err(Ok(Error::InactiveBounty)));
+
+    // Closed after the window: the fee stays with the fee account
+    let (late_owner, late_id) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 601);
+    client.close_bounty(&late_owner, &late_id);
+    assert_eq!(token.balance(&late_owner), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&fee_account), fee);
+    let result = client.try_refund_fee(&late_id);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+
+    // Fees charged on extra reward tokens come back too
+    let (second, second_distributor) = create_token_contract(&env);
+    let multi_owner = Address::generate(&env);
+    token.transfer(&distributor, &multi_owner, &adjust_for_decimals(1050, 7));
+    second.transfer(&second_distributor, &multi_owner, &adjust_for_decimals(525, 7));
+    let multi_id = client.create_multi_token_bounty(
+        &multi_owner,
+        &vec![&env, (token.address.clone(), 1000), (second.address.clone(), 500)],
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Two token bounty"),
+        &None,
+        &None,
+        &None,
+    );
+    client.close_bounty(&multi_owner, &multi_id);
+    client.refund_fee(&multi_id);
+    assert_eq!(token.balance(&multi_owner), adjust_for_decimals(1050, 7));
+    assert_eq!(second.balance(&multi_owner), adjust_for_decimals(525, 7));
+    assert_eq!(second.balance(&fee_account), 0);
+
+    let result = client.try_set_fee_refund_window(&(utils::MAX_FEE_REFUND_WINDOW + 1));
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_close_with_consolation() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (owner, bounty_id) = create_test_bounty(
+        &env,
+        &client,
+        &token,
+        &distributor,
+        1000,
+        vec![&env, (1, 100)],
+    );
+    let applicant1 = Address::generate(&env);
+    let applicant2 = Address::generate(&env);
+    client.apply_to_bounty(&applicant1, &bounty_id, &String::from_str(&env, "link1"));
+    client.apply_to_bounty(&applicant2, &bounty_id, &String::from_str(&env, "link2"));
+
+    // Consolation cannot exceed the reward
+    let result = client.try_close_with_consolation(&owner, &bounty_id, &i128::MAX);
+    assert_eq!(result, Err(Ok(Error::ArithmeticError)));
+    let result = client.try_close_with_consolation(&owner, &bounty_id, &501);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    client.close_with_consolation(&owner, &bounty_id, &50);
+    verify_bounty_closed_event(&env, &contract_id, &bounty_id);
+
+    assert_eq!(token.balance(&applicant1), adjust_for_decimals(50, 7));
+    assert_eq!(token.balance(&applicant2), adjust_for_decimals(50, 7));
+    assert_eq!(token.balance(&owner), adjust_for_decimals(900, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_bounty_status(&bounty_id), Status::Closed);
 }
 
 // ========================================
@@ -1189,6 +5555,42 @@ fn test_create_project_gig() {
     assert_eq!(token.balance(&fee_account), adjusted_fee);
 }
 
+#[test]
+fn test_underfunded_owner_gets_insufficient_balance() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    // Enough for the reward but not the fee on top
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1000, 7));
+
+    let result = client.try_create_bounty(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, (1, 100)],
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 2000),
+        &String::from_str(&env, "Test bounty"),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+
+    let result = client.try_create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, MilestoneData { amount: 1000, order: 1 }],
+        &(env.ledger().timestamp() + 10000),
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(token.balance(&owner), adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
 #[test]
 fn test_create_project_gig_invalid_milestones() {
     let env = Env::default();
@@ -1225,6 +5627,72 @@ fn test_create_project_gig_invalid_milestones() {
     assert_eq!(result, Err(Ok(Error::InvalidMilestones)));
 }
 
+#[test]
+fn test_project_gig_amounts_near_i128_bounds() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 10000;
+
+    // Milestones whose sum overflows
+    let half = i128::MAX / 2 + 1;
+    let result = client.try_create_project_gig(
+        &owner,
+        &token.address,
+        &i128::MAX,
+        &vec![
+            &env,
+            MilestoneData { amount: half, order: 1 },
+            MilestoneData { amount: half, order: 2 },
+        ],
+        &deadline,
+    );
+    assert_eq!(result, Err(Ok(Error::ArithmeticError)));
+
+    // A reward that overflows once scaled to token decimals
+    let huge = i128::MAX / 10;
+    let result = client.try_create_project_gig(
+        &owner,
+        &token.address,
+        &huge,
+        &vec![&env, MilestoneData { amount: huge, order: 1 }],
+        &deadline,
+    );
+    assert_eq!(result, Err(Ok(Error::ArithmeticError)));
+
+    // A reward that scales fine but leaves no room for the fee
+    let large = i128::MAX / 10_000_000;
+    let result = client.try_create_project_gig(
+        &owner,
+        &token.address,
+        &large,
+        &vec![&env, MilestoneData { amount: large, order: 1 }],
+        &deadline,
+    );
+    assert_eq!(result, Err(Ok(Error::ArithmeticError)));
+
+    // Releasing with an amount that overflows is rejected before any transfer
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let project_id = client.create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![&env, MilestoneData { amount: 1000, order: 1 }],
+        &deadline,
+    );
+    let contributor = Address::generate(&env);
+    let result =
+        client.try_release_milestone_payment(&owner, &project_id, &1, &contributor, &i128::MAX);
+    assert_eq!(result, Err(Ok(Error::ArithmeticError)));
+    assert_eq!(token.balance(&contributor), 0);
+    assert_eq!(
+        client.get_project(&project_id).remaining_escrow,
+        adjust_for_decimals(1000, 7)
+    );
+}
+
 // ========================================
 // PROJECT CREATION TESTS - JOB
 // ========================================
@@ -1318,6 +5786,54 @@ fn test_release_milestone_payment() {
     assert_eq!(token.balance(&contributor), adjusted_amount);
 }
 
+#[test]
+fn test_contributor_earnings() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(2000, 7));
+
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 600, order: 1 },
+        MilestoneData { amount: 400, order: 2 },
+    ];
+    let deadline = env.ledger().timestamp() + 10000;
+    let first = client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    let second = client.create_project_gig(
+        &owner,
+        &token.address,
+        &500,
+        &vec![&env, MilestoneData { amount: 500, order: 1 }],
+        &deadline,
+    );
+
+    assert_eq!(client.get_contributor_earnings(&first, &contributor), 0);
+    client.release_milestone_payment(&owner, &first, &1, &contributor, &600);
+    client.release_milestone_payment(&owner, &first, &2, &contributor, &400);
+    client.release_milestone_payment(&owner, &second, &1, &contributor, &500);
+
+    assert_eq!(
+        client.get_contributor_earnings(&first, &contributor),
+        adjust_for_decimals(1000, 7)
+    );
+    assert_eq!(
+        client.get_contributor_earnings(&second, &contributor),
+        adjust_for_decimals(500, 7)
+    );
+    assert_eq!(
+        client.get_total_contributor_earnings(&contributor, &token.address),
+        adjust_for_decimals(1500, 7)
+    );
+    assert_eq!(
+        client.get_contributor_earnings(&first, &Address::generate(&env)),
+        0
+    );
+}
+
 #[test]
 fn test_release_all_milestones_completes_project() {
     let env = Env::default();
@@ -1366,6 +5882,39 @@ fn test_release_all_milestones_completes_project() {
     assert_eq!(token.balance(&contributor), adjusted_total);
 }
 
+#[test]
+fn test_final_milestone_event_order() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &1000);
+
+    // Skip the token transfer events and keep the contract's own, in order
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<Symbol> = events
+        .iter()
+        .filter(|event| event.0 == contract_id)
+        .map(|event| Symbol::from_val(&env, &event.1.get_unchecked(0)))
+        .collect();
+    assert_eq!(
+        contract_events,
+        std::vec![
+            Symbol::new(&env, "milestone_paid"),
+            Symbol::new(&env, "project_completed"),
+        ]
+    );
+}
+
 #[test]
 fn test_release_milestone_payment_unauthorized() {
     let env = Env::default();
@@ -1446,17 +5995,180 @@ fn test_release_milestone_payment_already_paid() {
     assert_eq!(result, Err(Ok(Error::MilestoneAlreadyPaid)));
 }
 
+#[test]
+fn test_milestone_release_fee() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_set_milestone_release_fee(&(utils::MAX_MILESTONE_RELEASE_FEE_PERCENT + 1)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_milestone_release_fee(&5);
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 600, order: 1 },
+        MilestoneData { amount: 400, order: 2 },
+    ];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    assert_eq!(client.get_project(&project_id).release_fee_percent, 5);
+    let upfront_fee = token.balance(&fee_account);
+
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &600);
+    assert_eq!(token.balance(&contributor), adjust_for_decimals(570, 7));
+    assert_eq!(token.balance(&fee_account), upfront_fee + adjust_for_decimals(30, 7));
+
+    client.release_milestone_payment(&owner, &project_id, &2, &contributor, &400);
+    assert_eq!(token.balance(&contributor), adjust_for_decimals(950, 7));
+    assert_eq!(token.balance(&fee_account), upfront_fee + adjust_for_decimals(50, 7));
+    assert_eq!(client.get_project(&project_id).remaining_escrow, 0);
+}
+
+#[test]
+fn test_release_all_remaining() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 500, order: 1 },
+        MilestoneData { amount: 300, order: 2 },
+        MilestoneData { amount: 200, order: 3 },
+    ];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &500);
+
+    // The two unpaid milestones are settled together
+    let paid = client.release_all_remaining(&owner, &project_id, &contributor);
+    assert_eq!(paid, adjust_for_decimals(500, 7));
+    assert_eq!(token.balance(&contributor), adjust_for_decimals(1000, 7));
+
+    let project = client.get_project(&project_id);
+    assert_eq!(project.status, ProjectStatus::Completed);
+    assert_eq!(project.remaining_escrow, 0);
+    for milestone in project.milestones.iter() {
+        assert!(milestone.is_paid);
+    }
+
+    let result = client.try_release_all_remaining(&owner, &project_id, &contributor);
+    assert_eq!(result, Err(Ok(Error::ProjectNotActive)));
+}
+
 // ========================================
 // PROJECT CANCELLATION TESTS
 // ========================================
 
 #[test]
-fn test_cancel_project_gig() {
+fn test_cancel_project_gig() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let total_reward = 1000;
+    let platform_fee = 30; // 3% of 1000
+    let total_needed = total_reward + platform_fee;
+
+    let token_amount = adjust_for_decimals(
+        total_needed,
+        get_token_decimals(&env, &token.address),
+    );
+    token.transfer(&distributor, &owner, &token_amount);
+
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 600, order: 1 },
+        MilestoneData { amount: 400, order: 2 },
+    ];
+
+    let deadline = env.ledger().timestamp() + 10000;
+
+    let project_id = client.create_project_gig(
+        &owner,
+        &token.address,
+        &total_reward,
+        &milestones,
+        &deadline,
+    );
+
+    let initial_balance = token.balance(&owner);
+
+    let refunded = client.cancel_project_gig(&owner, &project_id);
+    assert_eq!(refunded, adjust_for_decimals(total_reward, 7));
+
+    let project = client.get_project(&project_id);
+    assert_eq!(project.status, ProjectStatus::Cancelled);
+    assert_eq!(project.remaining_escrow, 0);
+
+    let adjusted_refund = adjust_for_decimals(total_reward, get_token_decimals(&env, &token.address));
+    assert_eq!(token.balance(&owner), initial_balance + adjusted_refund);
+}
+
+#[test]
+fn test_purge_project() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let project_id = client.create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &vec![
+            &env,
+            MilestoneData { amount: 400, order: 1 },
+            MilestoneData { amount: 600, order: 2 },
+        ],
+        &(env.ledger().timestamp() + 10000),
+    );
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &400);
+    let earned = adjust_for_decimals(400, 7);
+    assert_eq!(client.get_contributor_earnings(&project_id, &contributor), earned);
+
+    // Active projects still hold escrow and cannot be purged
+    assert_eq!(client.try_purge_project(&owner, &project_id), Err(Ok(Error::TooEarly)));
+    assert_eq!(
+        client.try_purge_project(&Address::generate(&env), &project_id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.cancel_project_gig(&owner, &project_id);
+    client.purge_project(&owner, &project_id);
+    assert_eq!(client.try_get_project(&project_id), Err(Ok(Error::ProjectNotFound)));
+    assert_eq!(client.get_owner_projects(&owner), Vec::<u64>::new(&env));
+
+    // Per-project earnings go with the project; lifetime totals stay
+    assert_eq!(client.get_contributor_earnings(&project_id, &contributor), 0);
+    assert_eq!(client.get_total_contributor_earnings(&contributor, &token.address), earned);
+}
+
+#[test]
+fn test_cancel_project_gig_partial_refund() {
     let env = Env::default();
     let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
     let total_reward = 1000;
     let platform_fee = 30; // 3% of 1000
     let total_needed = total_reward + platform_fee;
@@ -1483,67 +6195,87 @@ fn test_cancel_project_gig() {
         &deadline,
     );
 
+
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &600);
+
     let initial_balance = token.balance(&owner);
 
     let refunded = client.cancel_project_gig(&owner, &project_id);
-    assert_eq!(refunded, adjust_for_decimals(total_reward, 7));
+    assert_eq!(refunded, adjust_for_decimals(400, 7));
 
     let project = client.get_project(&project_id);
     assert_eq!(project.status, ProjectStatus::Cancelled);
     assert_eq!(project.remaining_escrow, 0);
 
-    let adjusted_refund = adjust_for_decimals(total_reward, get_token_decimals(&env, &token.address));
+    let adjusted_refund = adjust_for_decimals(400, get_token_decimals(&env, &token.address));
     assert_eq!(token.balance(&owner), initial_balance + adjusted_refund);
 }
 
 #[test]
-fn test_cancel_project_gig_partial_refund() {
+fn test_cancel_gig_with_settlement() {
     let env = Env::default();
-    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let contributor = Address::generate(&env);
-    let total_reward = 1000;
-    let platform_fee = 30; // 3% of 1000
-    let total_needed = total_reward + platform_fee;
-
-    let token_amount = adjust_for_decimals(
-        total_needed,
-        get_token_decimals(&env, &token.address),
-    );
-    token.transfer(&distributor, &owner, &token_amount);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
 
     let milestones = vec![
         &env,
         MilestoneData { amount: 600, order: 1 },
         MilestoneData { amount: 400, order: 2 },
     ];
-
     let deadline = env.ledger().timestamp() + 10000;
-
-    let project_id = client.create_project_gig(
-        &owner,
-        &token.address,
-        &total_reward,
-        &milestones,
-        &deadline,
-    );
-
-
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
     client.release_milestone_payment(&owner, &project_id, &1, &contributor, &600);
 
-    let initial_balance = token.balance(&owner);
+    // More than the 400 left in escrow is rejected
+    let result = client.try_cancel_gig_with_settlement(&owner, &project_id, &contributor, &401);
+    assert_eq!(result, Err(Ok(Error::InsufficientEscrow)));
+    let result =
+        client.try_cancel_gig_with_settlement(&owner, &project_id, &contributor, &i128::MAX);
+    assert_eq!(result, Err(Ok(Error::ArithmeticError)));
 
-    let refunded = client.cancel_project_gig(&owner, &project_id);
-    assert_eq!(refunded, adjust_for_decimals(400, 7));
+    let refunded = client.cancel_gig_with_settlement(&owner, &project_id, &contributor, &150);
+    assert_eq!(refunded, adjust_for_decimals(250, 7));
+    assert_eq!(token.balance(&contributor), adjust_for_decimals(750, 7));
+    assert_eq!(token.balance(&owner), adjust_for_decimals(250, 7));
+    assert_eq!(token.balance(&contract_id), 0);
 
     let project = client.get_project(&project_id);
     assert_eq!(project.status, ProjectStatus::Cancelled);
     assert_eq!(project.remaining_escrow, 0);
+}
 
-    let adjusted_refund = adjust_for_decimals(400, get_token_decimals(&env, &token.address));
-    assert_eq!(token.balance(&owner), initial_balance + adjusted_refund);
+#[test]
+fn test_milestone_payouts_reject_zero_contributor() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+
+    let zero_address = Address::from_string(&String::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+    let result = client.try_release_milestone_payment(&owner, &project_id, &1, &zero_address, &1000);
+    assert_eq!(result, Err(Ok(Error::ContributorCannotBeZero)));
+    let result = client.try_release_all_remaining(&owner, &project_id, &zero_address);
+    assert_eq!(result, Err(Ok(Error::ContributorCannotBeZero)));
+    let result = client.try_cancel_gig_with_settlement(&owner, &project_id, &zero_address, &100);
+    assert_eq!(result, Err(Ok(Error::ContributorCannotBeZero)));
+
+    // Nothing left escrow
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1000, 7));
+    assert_eq!(client.get_project(&project_id).remaining_escrow, adjust_for_decimals(1000, 7));
 }
 
 #[test]
@@ -1583,6 +6315,58 @@ fn test_cancel_project_gig_unauthorized() {
     assert_eq!(result, Err(Ok(Error::Unauthorized)));
 }
 
+#[test]
+fn test_transfer_project_ownership() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 500, order: 1 },
+        MilestoneData { amount: 500, order: 2 },
+    ];
+    let project_id = client.create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &milestones,
+        &(env.ledger().timestamp() + 10000),
+    );
+
+    let result = client.try_transfer_project_ownership(&new_owner, &project_id, &new_owner);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    let zero_address = Address::from_string(&String::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+    let result = client.try_transfer_project_ownership(&owner, &project_id, &zero_address);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    client.transfer_project_ownership(&owner, &project_id, &new_owner);
+    let event = env.events().all().last().unwrap();
+    let (previous, current): (Address, Address) =
+        FromVal::from_val(&env, &event_data(&env, &event.2));
+    assert_eq!((previous, current), (owner.clone(), new_owner.clone()));
+    assert_eq!(client.get_project_owner(&project_id), new_owner);
+
+    // Only the new owner can release milestones or cancel
+    let result =
+        client.try_release_milestone_payment(&owner, &project_id, &1, &contributor, &500);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    client.release_milestone_payment(&new_owner, &project_id, &1, &contributor, &500);
+    assert_eq!(token.balance(&contributor), adjust_for_decimals(500, 7));
+
+    let result = client.try_cancel_project_gig(&owner, &project_id);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    client.cancel_project_gig(&new_owner, &project_id);
+    assert_eq!(token.balance(&new_owner), adjust_for_decimals(500, 7));
+}
+
 // ========================================
 // PROJECT QUERY & GETTER TESTS
 // ========================================
@@ -1631,21 +6415,138 @@ fn test_get_projects() {
 
     let all_projects = client.get_projects();
     assert_eq!(all_projects.len(), 2);
-    assert!(all_projects.contains(&project_id1));
-    assert!(all_projects.contains(&project_id2));
+    assert!(all_projects.contains(project_id1));
+    assert!(all_projects.contains(project_id2));
 
     let owner1_projects = client.get_owner_projects(&owner1);
     assert_eq!(owner1_projects.len(), 1);
-    assert!(owner1_projects.contains(&project_id1));
+    assert!(owner1_projects.contains(project_id1));
 
     let owner2_projects = client.get_owner_projects(&owner2);
     assert_eq!(owner2_projects.len(), 1);
-    assert!(owner2_projects.contains(&project_id2));
+    assert!(owner2_projects.contains(project_id2));
 
     let active_projects = client.get_projects_by_status(&ProjectStatus::Active);
     assert_eq!(active_projects.len(), 2);
 }
 
+#[test]
+fn test_bounty_and_project_exists() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (_owner, bounty_id) =
+        create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let project_id = client.create_project_gig(
+        &owner,
+        &token.address,
+        &1000,
+        &milestones,
+        &(env.ledger().timestamp() + 10000),
+    );
+
+    assert!(client.bounty_exists(&bounty_id));
+    assert!(!client.bounty_exists(&(bounty_id + 1)));
+    assert!(client.project_exists(&project_id));
+    assert!(!client.project_exists(&(project_id + 1)));
+}
+
+#[test]
+fn test_get_projects_count() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let total = adjust_for_decimals(2 * (1000 + 30), get_token_decimals(&env, &token.address));
+    token.transfer(&distributor, &owner, &total);
+
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+
+    assert_eq!(client.get_projects_count(), 0);
+
+    let first = client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+
+    assert_eq!(client.get_projects_count(), 2);
+    assert_eq!(client.get_projects_by_status_count(&ProjectStatus::Active), 2);
+    assert_eq!(client.get_projects_by_status_count(&ProjectStatus::Cancelled), 0);
+
+    client.cancel_project_gig(&owner, &first);
+
+    assert_eq!(client.get_projects_count(), 2);
+    assert_eq!(client.get_projects_by_status_count(&ProjectStatus::Active), 1);
+    assert_eq!(client.get_projects_by_status_count(&ProjectStatus::Cancelled), 1);
+}
+
+#[test]
+fn test_get_projects_summary() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1040, 7));
+
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+    let gig_id = client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    let job_id = client.create_project_job(&owner, &token.address, &500, &deadline);
+
+    let summaries = client.get_projects_summary(&vec![&env, gig_id, 999, job_id]);
+    assert_eq!(summaries.len(), 2);
+
+    let gig = summaries.get(0).unwrap();
+    assert_eq!(gig.id, gig_id);
+    assert_eq!(gig.project_type, ProjectType::Gig);
+    assert_eq!(gig.status, ProjectStatus::Active);
+    assert_eq!(gig.total_reward, adjust_for_decimals(1000, 7));
+    assert_eq!(gig.remaining_escrow, adjust_for_decimals(1000, 7));
+    assert_eq!(gig.deadline, deadline);
+
+    let job = summaries.get(1).unwrap();
+    assert_eq!(job.id, job_id);
+    assert_eq!(job.project_type, ProjectType::Job);
+    assert_eq!(job.remaining_escrow, 0);
+
+    let too_many = Vec::from_array(&env, [0u64; utils::MAX_BATCH_SIZE as usize + 1]);
+    let result = client.try_get_projects_summary(&too_many);
+    assert_eq!(result, Err(Ok(Error::TooManyItems)));
+}
+
+#[test]
+fn test_get_projects_due_between() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1000, 7));
+
+    let now = env.ledger().timestamp();
+    let soon = client.create_project_job(&owner, &token.address, &100, &(now + 1000));
+    let later = client.create_project_job(&owner, &token.address, &100, &(now + 5000));
+    let much_later = client.create_project_job(&owner, &token.address, &100, &(now + 9000));
+
+    assert_eq!(
+        client.get_projects_due_between(&(now + 1000), &(now + 5000)),
+        vec![&env, soon, later]
+    );
+    assert_eq!(
+        client.get_projects_due_between(&(now + 5001), &(now + 10000)),
+        vec![&env, much_later]
+    );
+    assert_eq!(
+        client.get_projects_due_between(&(now + 2000), &(now + 3000)),
+        Vec::<u64>::new(&env)
+    );
+}
+
 // ========================================
 // PROJECT INTEGRATION TESTS
 // ========================================
@@ -1713,7 +6614,248 @@ fn test_project_lifecycle_integration() {
 
     let completed_projects = client.get_projects_by_status(&ProjectStatus::Completed);
     assert_eq!(completed_projects.len(), 1);
-    assert!(completed_projects.contains(&project_id));
+    assert!(completed_projects.contains(project_id));
+}
+
+#[test]
+fn test_get_project_status() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(2060, 7));
+
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+
+    let completed_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    let cancelled_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    assert_eq!(client.get_project_status(&completed_id), ProjectStatus::Active);
+    assert_eq!(client.get_project_status(&cancelled_id), ProjectStatus::Active);
+
+    client.release_milestone_payment(&owner, &completed_id, &1, &contributor, &1000);
+    assert_eq!(client.get_project_status(&completed_id), ProjectStatus::Completed);
+
+    client.cancel_project_gig(&owner, &cancelled_id);
+    assert_eq!(client.get_project_status(&cancelled_id), ProjectStatus::Cancelled);
+
+    let result = client.try_get_project_status(&999);
+    assert_eq!(result, Err(Ok(Error::ProjectNotFound)));
+}
+
+#[test]
+fn test_get_next_unpaid_milestone() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+
+    // Listed out of order; order 1 comes first regardless
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 600, order: 2 },
+        MilestoneData { amount: 400, order: 1 },
+    ];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    assert_eq!(client.get_next_unpaid_milestone(&project_id).unwrap().order, 1);
+
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &400);
+    let next = client.get_next_unpaid_milestone(&project_id).unwrap();
+    assert_eq!(next.order, 2);
+    assert_eq!(next.amount, adjust_for_decimals(600, 7));
+
+    client.release_milestone_payment(&owner, &project_id, &2, &contributor, &600);
+    assert_eq!(client.get_next_unpaid_milestone(&project_id), None);
+
+    let result = client.try_get_next_unpaid_milestone(&999);
+    assert_eq!(result, Err(Ok(Error::ProjectNotFound)));
+}
+
+#[test]
+fn test_milestone_payees() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let designer = Address::generate(&env);
+    let developer = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 400, order: 1 },
+        MilestoneData { amount: 600, order: 2 },
+    ];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    client.set_milestone_payee(&owner, &project_id, &1, &Some(designer.clone()));
+    client.set_milestone_payee(&owner, &project_id, &2, &Some(developer.clone()));
+    assert_eq!(
+        client.try_set_milestone_payee(&owner, &project_id, &3, &None),
+        Err(Ok(Error::MilestoneNotFound))
+    );
+    let project = client.get_project(&project_id);
+    assert_eq!(project.milestones.get(0).unwrap().payee, Some(designer.clone()));
+
+    // A milestone pinned to a payee cannot be released to anyone else
+    let result = client.try_release_milestone_payment(&owner, &project_id, &1, &developer, &400);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.release_milestone_payment(&owner, &project_id, &1, &designer, &400);
+    client.release_milestone_payment(&owner, &project_id, &2, &developer, &600);
+    assert_eq!(token.balance(&designer), adjust_for_decimals(400, 7));
+    assert_eq!(token.balance(&developer), adjust_for_decimals(600, 7));
+    assert_eq!(
+        client.try_set_milestone_payee(&owner, &project_id, &1, &None),
+        Err(Ok(Error::ProjectNotActive))
+    );
+
+    // Releasing everything at once still honours pinned payees
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    client.set_milestone_payee(&owner, &project_id, &1, &Some(designer.clone()));
+    let released = client.release_all_remaining(&owner, &project_id, &developer);
+    assert_eq!(released, adjust_for_decimals(1000, 7));
+    assert_eq!(token.balance(&designer), adjust_for_decimals(800, 7));
+    assert_eq!(token.balance(&developer), adjust_for_decimals(1200, 7));
+}
+
+#[test]
+fn test_get_project_value_progress() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 100, order: 1 },
+        MilestoneData { amount: 900, order: 2 },
+    ];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    assert_eq!(client.get_project_value_progress(&project_id), 0);
+
+    // Half the milestones by count, but only a tenth of the value
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &100);
+    assert_eq!(client.get_project_value_progress(&project_id), 10);
+
+    client.release_milestone_payment(&owner, &project_id, &2, &contributor, &900);
+    assert_eq!(client.get_project_value_progress(&project_id), 100);
+    assert_eq!(
+        client.try_get_project_value_progress(&999),
+        Err(Ok(Error::ProjectNotFound))
+    );
+}
+
+#[test]
+fn test_get_bounty_and_project_owner() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let (bounty_owner, bounty_id) = create_test_bounty(&env, &client, &token, &distributor, 1000, vec![&env, (1, 100)]);
+    assert_eq!(client.get_bounty_owner(&bounty_id), bounty_owner);
+    assert_eq!(client.try_get_bounty_owner(&999), Err(Ok(Error::BountyNotFound)));
+
+    let project_owner = Address::generate(&env);
+    token.transfer(&distributor, &project_owner, &adjust_for_decimals(10, 7));
+    let project_id = client.create_project_job(&project_owner, &token.address, &500, &(env.ledger().timestamp() + 10000));
+    assert_eq!(client.get_project_owner(&project_id), project_owner);
+    assert_eq!(client.try_get_project_owner(&999), Err(Ok(Error::ProjectNotFound)));
+}
+
+#[test]
+fn test_update_project_deadline() {
+    let env = Env::default();
+    let (client, token, distributor, _fee_account, _admin, _contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030, 7));
+
+    let milestones = vec![&env, MilestoneData { amount: 1000, order: 1 }];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+
+    client.update_project_deadline(&owner, &project_id, &(deadline + 5000));
+    assert_eq!(client.get_project(&project_id).deadline, deadline + 5000);
+
+    // Same rules as update_project_gig: no shortening, nothing in the past
+    let result = client.try_update_project_deadline(&owner, &project_id, &(deadline + 4000));
+    assert_eq!(result, Err(Ok(Error::InvalidDeadlineUpdate)));
+    let result = client.try_update_project_gig(&owner, &project_id, &None, &Some(deadline + 4000));
+    assert_eq!(result, Err(Ok(Error::InvalidDeadlineUpdate)));
+    env.ledger().set_timestamp(deadline + 6000);
+    let result = client.try_update_project_deadline(&owner, &project_id, &(deadline + 5500));
+    assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+    let result = client.try_update_project_gig(&owner, &project_id, &None, &Some(deadline + 5500));
+    assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+
+    let result = client.try_update_project_deadline(&Address::generate(&env), &project_id, &(deadline + 1));
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_update_milestone_amount() {
+    let env = Env::default();
+    let (client, token, distributor, fee_account, _admin, contract_id) = setup_test(&env);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    token.transfer(&distributor, &owner, &adjust_for_decimals(1030 + 500, 7));
+
+    let milestones = vec![
+        &env,
+        MilestoneData { amount: 600, order: 1 },
+        MilestoneData { amount: 400, order: 2 },
+    ];
+    let deadline = env.ledger().timestamp() + 10000;
+    let project_id =
+        client.create_project_gig(&owner, &token.address, &1000, &milestones, &deadline);
+    let owner_before = token.balance(&owner);
+    let fees_before = token.balance(&fee_account);
+
+    // Increasing pulls the difference into escrow, plus the gig fee on it
+    client.update_milestone_amount(&owner, &project_id, &2, &700);
+    let project = client.get_project(&project_id);
+    assert_eq!(project.milestones.get(1).unwrap().amount, adjust_for_decimals(700, 7));
+    assert_eq!(project.total_reward, adjust_for_decimals(1300, 7));
+    assert_eq!(project.remaining_escrow, adjust_for_decimals(1300, 7));
+    assert_eq!(token.balance(&owner), owner_before - adjust_for_decimals(309, 7));
+    assert_eq!(token.balance(&fee_account), fees_before + adjust_for_decimals(9, 7));
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(1300, 7));
+
+    // Decreasing refunds the difference to the owner, but not the fee
+    client.update_milestone_amount(&owner, &project_id, &1, &100);
+    let project = client.get_project(&project_id);
+    assert_eq!(project.milestones.get(0).unwrap().amount, adjust_for_decimals(100, 7));
+    assert_eq!(project.total_reward, adjust_for_decimals(800, 7));
+    assert_eq!(project.remaining_escrow, adjust_for_decimals(800, 7));
+    assert_eq!(token.balance(&owner), owner_before + adjust_for_decimals(191, 7));
+    assert_eq!(token.balance(&contract_id), adjust_for_decimals(800, 7));
+
+    // Paid milestones are frozen
+    let contributor = Address::generate(&env);
+    client.release_milestone_payment(&owner, &project_id, &1, &contributor, &100);
+    assert_eq!(client.get_lifetime_paid(&token.address), adjust_for_decimals(100, 7));
+    let result = client.try_update_milestone_amount(&owner, &project_id, &1, &200);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyPaid)));
 }
 
 // ========================================
@@ -1961,8 +7103,8 @@ fn test_hackathon_getters() {
 
     let all = client.get_hackathons();
     assert_eq!(all.len(), 2);
-    assert!(all.contains(&h1));
-    assert!(all.contains(&h2));
+    assert!(all.contains(h1));
+    assert!(all.contains(h2));
 
     assert_eq!(client.get_hackathons_count(), 2);
 
@@ -1971,9 +7113,9 @@ fn test_hackathon_getters() {
 
     let active = client.get_hackathons_by_status(&HackathonStatus::Active);
     assert_eq!(active.len(), 1);
-    assert!(active.contains(&h1));
+    assert!(active.contains(h1));
 
     let cancelled = client.get_hackathons_by_status(&HackathonStatus::Cancelled);
     assert_eq!(cancelled.len(), 1);
-    assert!(cancelled.contains(&h2));
+    assert!(cancelled.contains(h2));
 }
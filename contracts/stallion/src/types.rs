@@ -3,6 +3,7 @@ use soroban_sdk::{
 };
 
 #[contracterror]
+#[allow(clippy::enum_variant_names)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum Error {
     // Admin-related errors
@@ -14,6 +15,8 @@ pub enum Error {
     // Authorization errors
     OnlyOwner = 5,
     Unauthorized = 6,
+    OwnerCannotApply = 46,
+    ApplicantNotAllowed = 47,
     
     // Bounty-related errors
     BountyNotFound = 7,
@@ -26,6 +29,8 @@ pub enum Error {
     NotEnoughWinners = 14,
     DistributionMustSumTo100 = 15,
     InvalidDeadlineUpdate = 16,
+    TooManyExtensions = 48,
+    BountyPaused = 51,
     
     // Submission-related errors
     SubmissionNotFound = 17,
@@ -45,9 +50,14 @@ pub enum Error {
     InvalidReward = 25,
     InvalidAmount = 26,
     DeadlinePassed = 27,
+    UnsupportedTokenDecimals = 41,
+    InvalidToken = 42,
+    OwnerBountyLimitReached = 43,
+    ContributorCannotBeZero = 44,
+    InsufficientBalance = 50,
     
-    // System errors
-    InternalError = 28,
+    // System errors (28 was InternalError, which nothing returned)
+    ArithmeticError = 45,
 
     // Hackathon errors
     HackathonNotFound = 29,
@@ -57,14 +67,46 @@ pub enum Error {
     HackathonNotCompleted = 33,
     InvalidPosition = 34,
     AllPositionsNotFilled = 35,
+
+    // Batch errors
+    TooManyItems = 36,
+
+    // Timing errors
+    TooEarly = 37,
+    SubmissionCooldown = 38,
+    CreateCooldown = 49,
+    NothingToClaim = 39,
+    AlreadyDistributed = 40,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Status {
     Active,
+    // Submission deadline has passed; the owner is picking winners
+    Judging,
     Completed,
-    Closed
+    Closed,
+    // Crowdfunded bounty still collecting its reward; becomes Active once
+    // the goal is met
+    Funding,
+}
+
+// Where a bounty is in its lifecycle, as returned by `get_bounty_phase`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Phase {
+    // Accepting submissions
+    Submission,
+    // Submissions closed, winners not yet paid; includes bounties past their
+    // judging deadline that are waiting for `check_judging`
+    Judging,
+    // Reward handed out
+    Settled,
+    // Closed or refunded without winners
+    Closed,
+    // Crowdfunded bounty still collecting its reward
+    Funding,
 }
 
 #[contracttype]
@@ -82,11 +124,150 @@ pub enum ProjectStatus {
     Cancelled,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AllocationMode {
+    // Each rank gets `reward * pct / 100`; rounding dust returns to the owner
+    Standard,
+    // Rounding dust of the filled ranks goes to the highest-ranked winner
+    LargestRemainder,
+}
+
+// How each rank's `reward * pct / 100` is rounded to whole token units.
+// Shares are capped at what is left of the reward, so rounding up never
+// pays out more than was escrowed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    Down,
+    Up,
+    // Half-way shares round to the nearest even unit
+    Bankers,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DeadlineMode {
+    // Deadlines are ledger timestamps (seconds)
+    Timestamp,
+    // Deadlines are ledger sequence numbers, immune to validator clock drift
+    LedgerSeq,
+}
+
+// Funding state of a crowdfunded bounty, stored next to it. The amount
+// raised so far is the bounty's `reward`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Crowdfund {
+    pub goal: i128,
+    pub funding_deadline: u64,
+    pub contributions: Map<Address, i128>,
+}
+
+// A recurring bounty: `reward` (user-friendly units) is escrowed again for
+// each of the `remaining` occurrences still to be created
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Series {
+    pub owner: Address,
+    pub token: Address,
+    pub reward: i128,
+    pub period: u64,
+    pub remaining: u32,
+    pub bounties: Vec<u64>,
+}
+
+// Admin actions that move escrowed funds; they only run through the
+// `request_emergency_action` / `execute_emergency_action` timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EmergencyAction {
+    // Returns the escrowed reward of an unsettled bounty to its owner
+    RefundBounty(u64),
+    // Returns the remaining escrow of an active project to its owner
+    RefundProject(u64),
+    // Pays (token, recipient, adjusted amount) out of the insurance pool
+    PayInsurance(Address, Address, i128),
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PayoutMode {
+    // Winners are paid directly by `select_winners`
+    Push,
+    // `select_winners` records each winner's share; winners call `claim_reward`
+    Claim,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RewardKind {
+    // Reward is escrowed in tokens and paid out by the contract
+    Fungible,
+    // Prize is delivered off-chain (e.g. an NFT); only the reference is kept
+    // and settlement records winners without any transfer
+    External(String),
+}
+
+// Where leftover reward (rounding dust, unfilled ranks) goes on settlement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RemainderRecipient {
+    Owner,
+    FeeAccount,
+    Address(Address),
+}
+
+// Optional per-bounty settings for `create_bounty`, grouped in one argument
+// to stay within the contract function parameter limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyOptions {
+    pub allocation: AllocationMode,
+    pub rounding: RoundingMode,
+    pub payout: PayoutMode,
+    pub reward_kind: RewardKind,
+    pub remainder_recipient: RemainderRecipient,
+    // Hold winners' shares until they call `accept_and_claim`
+    pub require_winner_acceptance: bool,
+    // Invite-only: when non-empty, only these addresses may apply
+    pub allowed_applicants: Option<Vec<Address>>,
+    pub deadline_mode: DeadlineMode,
+    // Smallest primary-token share a selected winner receives, in
+    // user-friendly units (0 = none); see `select_winners`
+    pub min_per_winner: i128,
+    // Brief shown to applicants alongside the title
+    pub description: Option<String>,
+    // Receives the platform fee instead of the fee account; must be approved
+    // by the admin, see `set_fee_recipient_approved`
+    pub fee_recipient: Option<Address>,
+}
+
+impl Default for BountyOptions {
+    fn default() -> Self {
+        BountyOptions {
+            allocation: AllocationMode::Standard,
+            rounding: RoundingMode::Down,
+            payout: PayoutMode::Push,
+            reward_kind: RewardKind::Fungible,
+            remainder_recipient: RemainderRecipient::Owner,
+            require_winner_acceptance: false,
+            allowed_applicants: None,
+            deadline_mode: DeadlineMode::Timestamp,
+            min_per_winner: 0,
+            description: None,
+            fee_recipient: None,
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Bounty {
     pub owner: Address,
     pub title: String,
+    // Empty when the owner gave none
+    pub description: String,
     pub reward: i128,
     pub token: Address,
     pub distribution: Map<u32, u32>,
@@ -96,6 +277,144 @@ pub struct Bounty {
     pub applicants: Vec<Address>,
     pub submissions: Map<Address, String>,
     pub winners: Vec<Address>,
+    pub fee: i128,
+    pub allocation: AllocationMode,
+    pub rounding: RoundingMode,
+    // Additional (token, adjusted reward) pools beyond the primary token
+    pub extra_rewards: Vec<(Address, i128)>,
+    // (token, fee, insured part of that fee) charged on each extra reward
+    pub extra_fees: Vec<(Address, i128, i128)>,
+    pub payout: PayoutMode,
+    // Set once the reward has been handed out, so a retried settlement is a no-op
+    pub distribution_completed: bool,
+    pub reward_kind: RewardKind,
+    pub remainder_recipient: RemainderRecipient,
+    pub created_at: u64,
+    // Effective platform fee charged at creation, in basis points of the
+    // reward, after any fee cap
+    pub fee_bps_at_creation: u32,
+    // Winners' shares are held as claims until accepted, see `accept_and_claim`
+    pub require_winner_acceptance: bool,
+    // What the submission and judging deadlines are measured against
+    pub deadline_mode: DeadlineMode,
+    // Floor for each winner's primary-token share (adjusted, 0 = none)
+    pub min_per_winner: i128,
+    // Part of `fee` that went to the insurance pool rather than the fee account
+    pub insured_fee: i128,
+    // Bounty this one was created under by `create_sub_bounty`
+    pub parent_id: Option<u64>,
+    // Where the platform fee went, when not the fee account
+    pub fee_recipient: Option<Address>,
+}
+
+// Participation figures for a bounty detail page, returned by
+// `get_bounty_stats`. `total_distributed` is in primary-token units.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyStats {
+    pub applicant_count: u32,
+    pub winner_count: u32,
+    pub total_distributed: i128,
+    pub submission_deadline_passed: bool,
+    pub judging_deadline_passed: bool,
+}
+
+// Everything an indexer needs to rebuild a bounty, returned by
+// `get_bounty_snapshot`. `applicants` holds at most MAX_BATCH_SIZE entries;
+// compare with `applicant_count` and page the rest via `get_bounty_applicants`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountySnapshot {
+    pub id: u64,
+    pub owner: Address,
+    pub token: Address,
+    pub title: String,
+    pub description: String,
+    pub reward: i128,
+    pub fee: i128,
+    pub status: Status,
+    pub created_at: u64,
+    pub submission_deadline: u64,
+    pub judging_deadline: u64,
+    pub applicants: Vec<Address>,
+    pub applicant_count: u32,
+    pub submission_count: u32,
+    pub winners: Vec<Address>,
+    pub payouts: Map<Address, i128>,
+    pub distribution_completed: bool,
+}
+
+// Snapshot of the global settings, returned by `get_config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigView {
+    pub admin: Address,
+    pub fee_account: Address,
+    pub bounty_fee_percent: i128,
+    pub gig_fee_percent: i128,
+    pub job_fee_percent: i128,
+    pub hackathon_fee_percent: i128,
+    pub submission_cooldown: u64,
+    pub owner_create_cooldown: u64,
+    pub keeper_incentive_percent: u32,
+    pub milestone_release_fee_percent: u32,
+    pub fee_refund_window: u64,
+    pub max_open_per_owner: u32,
+    pub min_judging_gap: u64,
+    pub block_owner_apply: bool,
+    pub emergency_delay: u64,
+    pub insurance_bps: u32,
+    pub claim_window: u64,
+    pub default_token: Option<Address>,
+    pub event_schema_version: u32,
+}
+
+// Headline counters returned by `get_global_stats`, maintained as entities
+// are created and change status rather than computed by scanning
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalStats {
+    pub total_bounties: u32,
+    pub active_bounties: u32,
+    pub total_projects: u32,
+    pub active_projects: u32,
+    // Platform fees charged, per token (adjusted amounts): sent to the fee
+    // account or a bounty's own fee recipient, or accrued in the contract
+    // when they could not be sent. The insured share is not included.
+    pub fees_collected: Map<Address, i128>,
+}
+
+// Lifetime bounty lifecycle counts for monitoring, returned by
+// `get_operational_counters`
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Counters {
+    pub bounties_created: u32,
+    pub bounties_completed: u32,
+    pub bounties_closed: u32,
+    pub bounties_deleted: u32,
+}
+
+// Result of `health_check`; `healthy` is true when every other flag is
+// true
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthReport {
+    pub healthy: bool,
+    pub admin_set: bool,
+    pub fee_account_set: bool,
+    pub bounty_ids_ok: bool,
+    pub project_ids_ok: bool,
+    pub hackathon_ids_ok: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SelectionReceipt {
+    pub distributed: i128,
+    // Sent to the remainder recipient, which is the owner unless configured
+    pub returned_to_owner: i128,
+    pub winners_paid: u32,
 }
 
 #[contracttype]
@@ -111,6 +430,8 @@ pub struct MilestoneInfo {
     pub amount: i128,
     pub order: u32,
     pub is_paid: bool,
+    // Subcontractor this milestone must be paid to, see `set_milestone_payee`
+    pub payee: Option<Address>,
 }
 
 #[contracttype]
@@ -124,6 +445,30 @@ pub struct Project {
     pub deadline: u64,
     pub status: ProjectStatus,
     pub milestones: Vec<MilestoneInfo>,
+    // Fee percent taken from each milestone release, fixed at creation (0 = off)
+    pub release_fee_percent: u32,
+}
+
+// A user's activity across the contract, returned by `get_user_profile`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserProfile {
+    pub bounties_created: u32,
+    pub bounties_applied: u32,
+    pub bounties_won: u32,
+    pub projects_owned: u32,
+}
+
+// Compact view of a project returned by `get_projects_summary`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectSummary {
+    pub id: u64,
+    pub project_type: ProjectType,
+    pub status: ProjectStatus,
+    pub total_reward: i128,
+    pub remaining_escrow: i128,
+    pub deadline: u64,
 }
 
 #[contracttype]
@@ -154,6 +499,8 @@ pub struct Hackathon {
     pub winners: Map<u32, Address>,
 }
 
+// Namespace of every storage key; see storage.rs. Values are persisted, so
+// existing discriminants must never be reused or renumbered.
 #[derive(Clone, Copy)]
 #[repr(u32)]
 pub enum DataKey {
@@ -167,6 +514,58 @@ pub enum DataKey {
     NextHackathonId = 8,
     Hackathon = 9,
     DeploymentSeq = 10,
+    FeeCap = 11,
+    IdempotencyKey = 12,
+    SubmissionCooldown = 13,
+    LastSubmission = 14,
+    KeeperIncentive = 15,
+    MilestoneReleaseFee = 16,
+    BountyPayouts = 17,
+    PendingAdmin = 18,
+    ClaimableReward = 19,
+    TokenDecimals = 20,
+    LifetimeFunded = 21,
+    Proposal = 22,
+    OwnerCounts = 23,
+    GlobalStats = 24,
+    FeaturedBounties = 25,
+    FeeRefundWindow = 26,
+    OpenBountyCount = 27,
+    MaxOpenPerOwner = 28,
+    UsedTokens = 29,
+    EventSeq = 30,
+    MinJudgingGap = 31,
+    AltPool = 32,
+    ProjectEarnings = 33,
+    ContributorEarnings = 34,
+    AcceptanceDeadline = 35,
+    PendingFeeAccount = 36,
+    BlockOwnerApply = 37,
+    AccruedFees = 38,
+    Allowlist = 39,
+    EmergencyDelay = 40,
+    PendingEmergency = 41,
+    ApplicantCount = 42,
+    Crowdfund = 43,
+    Series = 44,
+    BountySeries = 45,
+    NextSeriesId = 46,
+    LifetimePaid = 47,
+    DeadlineExtensions = 48,
+    OperationalCounters = 49,
+    SubBounties = 50,
+    InsuranceBps = 51,
+    InsurancePool = 52,
+    OwnerCreateCooldown = 53,
+    LastCreation = 54,
+    ApprovedFeeRecipient = 55,
+    SubmissionHistory = 56,
+    DefaultToken = 57,
+    BountyPaused = 58,
+    ClaimWindow = 59,
+    ClaimsOpenedAt = 60,
+    FeeRefundable = 61,
+    ProjectContributors = 62,
 }
 
 impl TryFromVal<Env, DataKey> for Val {
@@ -1,11 +1,66 @@
 use soroban_sdk::{Address, Env, String, Vec, token};
 
+use crate::types::Error;
+
 // Constants
 pub const BOUNTY_PLATFORM_FEE_PERCENT: i128 = 5;
 pub const GIG_PLATFORM_FEE_PERCENT: i128 = 3;
 pub const JOB_PLATFORM_FEE_PERCENT: i128 = 2;
 pub const HACKATHON_PLATFORM_FEE_PERCENT: i128 = 5;
 
+// Upper bound on the number of ids accepted by batch entry points so a
+// single call stays within the per-transaction instruction budget.
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+// Most ranks a bounty distribution may define; settlement walks every rank
+pub const MAX_DISTRIBUTION_RANKS: u32 = 50;
+
+// Largest token decimals accepted; 10^decimals must stay well inside i128
+pub const MAX_DECIMALS: u32 = 18;
+
+// Upper bound on the share of a bounty's reward paid to the keeper that
+// triggers its settlement
+pub const MAX_KEEPER_INCENTIVE_PERCENT: u32 = 2;
+
+// Upper bound on the optional fee taken from each gig milestone release
+pub const MAX_MILESTONE_RELEASE_FEE_PERCENT: u32 = 10;
+
+// Longest note an owner may attach when selecting winners, in bytes
+pub const MAX_NOTE_LENGTH: u32 = 280;
+
+// Longest window after creation in which closing refunds the platform fee
+pub const MAX_FEE_REFUND_WINDOW: u64 = 86_400;
+// Time winners have to accept an acceptance-gated payout (30 days)
+pub const ACCEPTANCE_WINDOW: u64 = 2_592_000;
+// Shortest claim window after which owners may take back unclaimed shares
+pub const MIN_CLAIM_WINDOW: u64 = 86_400;
+
+// Default and smallest allowed wait between requesting and executing an
+// emergency action (3 days and 1 day)
+pub const DEFAULT_EMERGENCY_DELAY: u64 = 259_200;
+pub const MIN_EMERGENCY_DELAY: u64 = 86_400;
+
+// Largest share of each bounty fee, in basis points, that may be set aside
+// for the insurance pool
+pub const MAX_INSURANCE_BPS: u32 = 1_000;
+
+// How often, and by how many days in total, an owner may push back a
+// bounty's submission deadline
+pub const MAX_DEADLINE_EXTENSIONS: u32 = 3;
+pub const MAX_DEADLINE_EXTENSION_DAYS: u64 = 14;
+
+// Longest proposal text an applicant may attach to a submission, in bytes
+pub const MAX_PROPOSAL_LENGTH: u32 = 1_000;
+// Longest bounty description, in bytes
+pub const MAX_DESCRIPTION_LENGTH: u32 = 2_000;
+// Submission links kept per applicant; older revisions drop off
+pub const MAX_SUBMISSION_REVISIONS: u32 = 10;
+
+// TTL management, expressed in ledgers (~5 seconds each)
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
 pub enum FeeType {
     Bounty,
     Gig,
@@ -15,10 +70,10 @@ pub enum FeeType {
 
 pub fn calculate_fee(reward: i128, fee_type: FeeType) -> i128 {
     match fee_type {
-        FeeType::Bounty => reward * BOUNTY_PLATFORM_FEE_PERCENT as i128 / 100,
-        FeeType::Gig => reward * GIG_PLATFORM_FEE_PERCENT as i128 / 100,
-        FeeType::Job => reward * JOB_PLATFORM_FEE_PERCENT as i128 / 100,
-        FeeType::Hackathon => reward * HACKATHON_PLATFORM_FEE_PERCENT as i128 / 100,
+        FeeType::Bounty => reward * BOUNTY_PLATFORM_FEE_PERCENT / 100,
+        FeeType::Gig => reward * GIG_PLATFORM_FEE_PERCENT / 100,
+        FeeType::Job => reward * JOB_PLATFORM_FEE_PERCENT / 100,
+        FeeType::Hackathon => reward * HACKATHON_PLATFORM_FEE_PERCENT / 100,
     }
 }
 
@@ -26,11 +81,24 @@ pub fn get_token_client(env: &'_ Env, token_address: Address) -> token::Client<'
     token::Client::new(env, &token_address)
 }
 
+// Only the tests read decimals directly; the contract goes through
+// `try_get_token_decimals`
+#[cfg(test)]
 pub fn get_token_decimals(env: &'_ Env, token_address: &Address) -> u32 {
     let token_client = get_token_client(env, token_address.clone());
     token_client.decimals()
 }
 
+// Like `get_token_decimals`, but reports a missing contract or a missing or
+// malformed `decimals()` as `InvalidToken` instead of trapping.
+pub fn try_get_token_decimals(env: &Env, token_address: &Address) -> Result<u32, Error> {
+    let token_client = get_token_client(env, token_address.clone());
+    match token_client.try_decimals() {
+        Ok(Ok(decimals)) => Ok(decimals),
+        _ => Err(Error::InvalidToken),
+    }
+}
+
 pub fn adjust_for_decimals(amount: i128, decimals: u32) -> i128 {
     // Calculate 10^decimals to convert from user-friendly amount to token amount
     let mut multiplier: i128 = 1;
@@ -40,7 +108,12 @@ pub fn adjust_for_decimals(amount: i128, decimals: u32) -> i128 {
     amount * multiplier
 }
 
-pub fn _convert_from_token_amount(amount: i128, decimals: u32) -> i128 {
+// `adjust_for_decimals` that reports overflow instead of panicking
+pub fn checked_adjust_for_decimals(amount: i128, decimals: u32) -> Option<i128> {
+    10i128.checked_pow(decimals)?.checked_mul(amount)
+}
+
+pub fn convert_from_token_amount(amount: i128, decimals: u32) -> i128 {
     // Calculate 10^decimals to convert from token amount to user-friendly amount
     let mut divisor: i128 = 1;
     for _ in 0..decimals {
@@ -57,6 +130,46 @@ pub fn validate_distribution_sum(distribution: &Vec<(u32, u32)>) -> bool {
     total == 100
 }
 
+// Deadline ordering shared by bounty creation and updates: submissions must
+// still be open (`now < submission`, since applying is allowed while
+// `now <= submission`) and judging must end strictly after submissions, and
+// at least `min_gap` seconds after them.
+pub fn validate_deadlines(submission: u64, judging: u64, now: u64, min_gap: u64) -> Result<(), Error> {
+    if submission <= now {
+        return Err(Error::InvalidDeadlineUpdate);
+    }
+    if judging <= submission || judging - submission < min_gap {
+        return Err(Error::JudgingDeadlineMustBeAfterSubmissionDeadline);
+    }
+    Ok(())
+}
+
+// Rejects a winner list naming the same address more than once; a repeated
+// winner would collect several ranks' shares.
+pub fn assert_unique_addresses(addresses: &Vec<Address>) -> Result<(), Error> {
+    for (i, address) in addresses.iter().enumerate() {
+        if addresses.slice(i as u32 + 1..).contains(&address) {
+            return Err(Error::NotEnoughWinners);
+        }
+    }
+    Ok(())
+}
+
+// Splits 100% evenly over ranks 1..=num_winners; the rounding remainder goes
+// to rank 1 so the entries still sum to 100.
+pub fn even_distribution(env: &Env, num_winners: u32) -> Vec<(u32, u32)> {
+    let mut distribution = Vec::new(env);
+    if num_winners == 0 || num_winners > 100 {
+        return distribution;
+    }
+    let share = 100 / num_winners;
+    for rank in 1..=num_winners {
+        let percent = if rank == 1 { share + 100 % num_winners } else { share };
+        distribution.push_back((rank, percent));
+    }
+    distribution
+}
+
 pub fn is_zero_address(env: &Env, addr: &Address) -> bool {
     // The byte representation of a zero address would be all zeros
     addr.to_string()